@@ -0,0 +1,183 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+// -------------------------------------------------------------------------------------------------
+
+/// ABM - Addressed binary and safety related message given to an AIS transponder for
+/// transmission
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct AddressedBinaryMessageRequest {
+    /// AIS station type.
+    pub station: Station,
+
+    /// Sequential message identifier (0-3)
+    pub sequence_id: Option<u8>,
+
+    /// MMSI of the destination station
+    pub destination_mmsi: Option<u32>,
+
+    /// Channel to be used for the transmission
+    pub channel: Option<AisChannelSelection>,
+
+    /// Message ID of the message to be transmitted (6, 12, 25 or 26)
+    pub message_id: Option<u8>,
+
+    /// Encapsulated binary data without fill bits
+    pub data: BitVec,
+}
+
+/// AIS channel selection of a transmit request
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AisChannelSelection {
+    /// No broadcast channel preference
+    NoPreference,
+
+    /// Broadcast on AIS channel A
+    ChannelA,
+
+    /// Broadcast on AIS channel B
+    ChannelB,
+
+    /// Broadcast message on both AIS channels
+    Both,
+}
+
+impl AisChannelSelection {
+    pub fn new(val: u8) -> Result<AisChannelSelection, String> {
+        match val {
+            0 => Ok(AisChannelSelection::NoPreference),
+            1 => Ok(AisChannelSelection::ChannelA),
+            2 => Ok(AisChannelSelection::ChannelB),
+            3 => Ok(AisChannelSelection::Both),
+            _ => Err(format!("Unrecognized AIS channel selection: {}", val)),
+        }
+    }
+}
+
+impl core::fmt::Display for AisChannelSelection {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            AisChannelSelection::NoPreference => write!(f, "no preference"),
+            AisChannelSelection::ChannelA => write!(f, "channel A"),
+            AisChannelSelection::ChannelB => write!(f, "channel B"),
+            AisChannelSelection::Both => write!(f, "both channels"),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// !xxABM: Addressed binary and safety related message
+pub(crate) fn handle(
+    sentence: &str,
+    station: Station,
+    store: &mut NmeaParser,
+) -> Result<ParsedMessage, ParseError> {
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    let sequence_id = pick_number_field(&split, 3)?;
+    let channel = match pick_number_field(&split, 5)? {
+        Some(c) => Some(AisChannelSelection::new(c)?),
+        None => None,
+    };
+    let data = match pull_transmit_payload(&split, sequence_id, 7, store)? {
+        Some(data) => data,
+        None => return Ok(ParsedMessage::Incomplete),
+    };
+
+    Ok(ParsedMessage::AddressedBinaryMessageRequest(
+        AddressedBinaryMessageRequest {
+            station,
+            sequence_id,
+            destination_mmsi: pick_number_field(&split, 4)?,
+            channel,
+            message_id: pick_number_field(&split, 6)?,
+            data,
+        },
+    ))
+}
+
+/// Collect the encapsulated payload of a multi-sentence ABM or BBM message. Payload is found in
+/// field `payload_field` and the number of fill bits in the next field. Returns `None` until all
+/// the sentences of the message have been received.
+pub(crate) fn pull_transmit_payload(
+    split: &[&str],
+    sequence_id: Option<u8>,
+    payload_field: usize,
+    store: &mut NmeaParser,
+) -> Result<Option<BitVec>, ParseError> {
+    let sentence_type = split.first().unwrap_or(&"");
+    let count: u8 = pick_number_field(split, 1)?.unwrap_or(1);
+    let number: u8 = pick_number_field(split, 2)?.unwrap_or(1);
+    let seq = sequence_id.unwrap_or(0) as u64;
+    let mut payload = pick_string_field(split, payload_field).unwrap_or_default();
+    let mut fill_bits: usize = pick_number_field(split, payload_field + 1)?.unwrap_or(0);
+
+    if count > 1 {
+        let key = |i| make_fragment_key(sentence_type, seq, count, i, "");
+        match store.assemble_fragments(key, count, number, &payload, fill_bits) {
+            Some((combined, last_fill_bits)) => {
+                payload = combined;
+                fill_bits = last_fill_bits;
+            }
+            None => return Ok(None),
+        }
+    }
+
+    let mut bv = parse_payload(&payload)?;
+    if fill_bits > bv.len() {
+        return Err(format!("Invalid number of fill bits: {}", fill_bits).into());
+    }
+    bv.truncate(bv.len() - fill_bits);
+    Ok(Some(bv))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_abm() {
+        match NmeaParser::new().parse_sentence("!AIABM,1,1,0,230123450,1,6,E03Owh0,2*55") {
+            Ok(ps) => match ps {
+                ParsedMessage::AddressedBinaryMessageRequest(abm) => {
+                    assert_eq!(abm.station, Station::MobileStation);
+                    assert_eq!(abm.sequence_id, Some(0));
+                    assert_eq!(abm.destination_mmsi, Some(230123450));
+                    assert_eq!(abm.channel, Some(AisChannelSelection::ChannelA));
+                    assert_eq!(abm.message_id, Some(6));
+                    assert_eq!(abm.data.len(), 40);
+                    assert_eq!(pick_u64(&abm.data, 0, 6), 21);
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+
+        // Invalid channel selection
+        assert!(NmeaParser::new()
+            .parse_sentence("!AIABM,1,1,0,230123450,9,6,E03Owh0,2*5D")
+            .is_err());
+    }
+}
@@ -0,0 +1,222 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+// -------------------------------------------------------------------------------------------------
+
+/// ACA - AIS regional channel assignment
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct RegionalChannelAssignment {
+    /// Sequence number (0-9) used to link the region to an ACS sentence
+    pub sequence_number: Option<u8>,
+
+    /// Northeast corner latitude of the region
+    pub ne_lat: Option<f64>,
+
+    /// Northeast corner longitude of the region
+    pub ne_lon: Option<f64>,
+
+    /// Southwest corner latitude of the region
+    pub sw_lat: Option<f64>,
+
+    /// Southwest corner longitude of the region
+    pub sw_lon: Option<f64>,
+
+    /// Size of transitional zone in nautical miles (1-8)
+    pub zonesize: Option<u8>,
+
+    /// Channel A number
+    pub channel_a: Option<u16>,
+
+    /// Channel A band:
+    /// false = default,
+    /// true = 12.5 kHz
+    pub channel_a_band: Option<bool>,
+
+    /// Channel B number
+    pub channel_b: Option<u16>,
+
+    /// Channel B band:
+    /// false = default,
+    /// true = 12.5 kHz
+    pub channel_b_band: Option<bool>,
+
+    /// TxRx mode:
+    /// 0 = TxA/TxB, RxA/RxB (default)
+    /// 1 = TxA, RxA/RxB
+    /// 2 = TxB, RxA/RxB
+    pub txrx: Option<u8>,
+
+    /// Power level to be used:
+    /// false = low,
+    /// true = high
+    pub power: Option<bool>,
+
+    /// Source of the channel assignment
+    pub source: Option<ChannelAssignmentSource>,
+
+    /// True if the region is in use
+    pub in_use: Option<bool>,
+
+    /// Time when the in-use flag last changed
+    pub in_use_changed: Option<DateTime<Utc>>,
+}
+
+impl LatLon for RegionalChannelAssignment {
+    /// Latitude of the region centre
+    fn latitude(&self) -> Option<f64> {
+        Some((self.ne_lat? + self.sw_lat?) / 2.0)
+    }
+
+    /// Longitude of the region centre
+    fn longitude(&self) -> Option<f64> {
+        Some((self.ne_lon? + self.sw_lon?) / 2.0)
+    }
+}
+
+/// Source of a regional channel assignment
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ChannelAssignmentSource {
+    /// Addressed channel management message (type 22)
+    AddressedMessage,
+
+    /// Broadcast channel management message (type 22)
+    BroadcastMessage,
+
+    /// IEC 61162-1 ACA sentence
+    AcaSentence,
+
+    /// DSC channel management command
+    Dsc,
+
+    /// Manual operator input
+    Manual,
+}
+
+impl ChannelAssignmentSource {
+    pub fn new(val: &str) -> Result<ChannelAssignmentSource, String> {
+        match val {
+            "A" => Ok(ChannelAssignmentSource::AddressedMessage),
+            "B" => Ok(ChannelAssignmentSource::BroadcastMessage),
+            "C" => Ok(ChannelAssignmentSource::AcaSentence),
+            "D" => Ok(ChannelAssignmentSource::Dsc),
+            "M" => Ok(ChannelAssignmentSource::Manual),
+            _ => Err(format!("Unrecognized channel assignment source: {}", val)),
+        }
+    }
+}
+
+impl core::fmt::Display for ChannelAssignmentSource {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ChannelAssignmentSource::AddressedMessage => write!(f, "addressed message"),
+            ChannelAssignmentSource::BroadcastMessage => write!(f, "broadcast message"),
+            ChannelAssignmentSource::AcaSentence => write!(f, "ACA sentence"),
+            ChannelAssignmentSource::Dsc => write!(f, "DSC"),
+            ChannelAssignmentSource::Manual => write!(f, "manual input"),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// xxACA: AIS regional channel assignment
+pub(crate) fn handle(sentence: &str) -> Result<ParsedMessage, ParseError> {
+    let now: DateTime<Utc> = Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).single().unwrap();
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    Ok(ParsedMessage::RegionalChannelAssignment(
+        RegionalChannelAssignment {
+            sequence_number: pick_number_field(&split, 1)?,
+            ne_lat: parse_latitude_ddmm_mmm(
+                split.get(2).unwrap_or(&""),
+                split.get(3).unwrap_or(&""),
+            )?,
+            ne_lon: parse_longitude_dddmm_mmm(
+                split.get(4).unwrap_or(&""),
+                split.get(5).unwrap_or(&""),
+            )?,
+            sw_lat: parse_latitude_ddmm_mmm(
+                split.get(6).unwrap_or(&""),
+                split.get(7).unwrap_or(&""),
+            )?,
+            sw_lon: parse_longitude_dddmm_mmm(
+                split.get(8).unwrap_or(&""),
+                split.get(9).unwrap_or(&""),
+            )?,
+            zonesize: pick_number_field(&split, 10)?,
+            channel_a: pick_number_field(&split, 11)?,
+            channel_a_band: pick_number_field::<u8>(&split, 12)?.map(|b| b != 0),
+            channel_b: pick_number_field(&split, 13)?,
+            channel_b_band: pick_number_field::<u8>(&split, 14)?.map(|b| b != 0),
+            txrx: pick_number_field(&split, 15)?,
+            // 0 = high, 1 = low
+            power: pick_number_field::<u8>(&split, 16)?.map(|p| p == 0),
+            source: match pick_string_field(&split, 17) {
+                Some(s) => Some(ChannelAssignmentSource::new(&s)?),
+                None => None,
+            },
+            in_use: pick_number_field::<u8>(&split, 18)?.map(|u| u != 0),
+            in_use_changed: parse_hhmmss_ss(split.get(19).unwrap_or(&""), now).ok(),
+        },
+    ))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_aca() {
+        match NmeaParser::new().parse_sentence(
+            "$AIACA,1,6000.00,N,02600.00,E,5900.00,N,02400.00,E,4,2087,0,2088,1,0,1,M,1,120000.00*34",
+        ) {
+            Ok(ps) => match ps {
+                ParsedMessage::RegionalChannelAssignment(aca) => {
+                    assert_eq!(aca.sequence_number, Some(1));
+                    assert_eq!(aca.ne_lat, Some(60.0));
+                    assert_eq!(aca.ne_lon, Some(26.0));
+                    assert_eq!(aca.sw_lat, Some(59.0));
+                    assert_eq!(aca.sw_lon, Some(24.0));
+                    assert_eq!(aca.latitude(), Some(59.5));
+                    assert_eq!(aca.longitude(), Some(25.0));
+                    assert_eq!(aca.zonesize, Some(4));
+                    assert_eq!(aca.channel_a, Some(2087));
+                    assert_eq!(aca.channel_a_band, Some(false));
+                    assert_eq!(aca.channel_b, Some(2088));
+                    assert_eq!(aca.channel_b_band, Some(true));
+                    assert_eq!(aca.txrx, Some(0));
+                    assert_eq!(aca.power, Some(false));
+                    assert_eq!(aca.source, Some(ChannelAssignmentSource::Manual));
+                    assert_eq!(aca.in_use, Some(true));
+                    assert_eq!(
+                        aca.in_use_changed,
+                        Utc.with_ymd_and_hms(2000, 1, 1, 12, 0, 0).single()
+                    );
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+}
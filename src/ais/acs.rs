@@ -0,0 +1,79 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+// -------------------------------------------------------------------------------------------------
+
+/// ACS - Channel management information source
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct ChannelManagementSource {
+    /// Sequence number (0-9) of the ACA sentence this source relates to
+    pub sequence_number: Option<u8>,
+
+    /// MMSI of the station that originated the channel management information
+    pub mmsi: Option<u32>,
+
+    /// Time when the channel management information was received
+    pub timestamp: Option<DateTime<Utc>>,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// xxACS: Channel management information source
+pub(crate) fn handle(sentence: &str) -> Result<ParsedMessage, ParseError> {
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    Ok(ParsedMessage::ChannelManagementSource(
+        ChannelManagementSource {
+            sequence_number: pick_number_field(&split, 1)?,
+            mmsi: pick_number_field(&split, 2)?,
+            timestamp: match pick_date_with_fields(&split, 6, 5, 4, 0, 0, 0, 0) {
+                Ok(date) => parse_hhmmss_ss(split.get(3).unwrap_or(&""), date).ok(),
+                Err(_) => None,
+            },
+        },
+    ))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_acs() {
+        match NmeaParser::new().parse_sentence("$AIACS,1,230123450,120501.00,16,10,2026*71") {
+            Ok(ps) => match ps {
+                ParsedMessage::ChannelManagementSource(acs) => {
+                    assert_eq!(acs.sequence_number, Some(1));
+                    assert_eq!(acs.mmsi, Some(230123450));
+                    assert_eq!(
+                        acs.timestamp,
+                        Utc.with_ymd_and_hms(2026, 10, 16, 12, 5, 1).single()
+                    );
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+}
@@ -0,0 +1,359 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+// -------------------------------------------------------------------------------------------------
+
+/// DAC 1, FI 22: Area notice (IMO 289)
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct AreaNotice {
+    /// Message linkage ID connecting the notice to related messages (10 bits)
+    pub message_linkage_id: u16,
+
+    /// Area type, i.e. the notice description code (7 bits)
+    pub notice_type: u8,
+
+    /// UTC month of the start time
+    pub start_month: Option<u8>,
+
+    /// UTC day of the start time
+    pub start_day: Option<u8>,
+
+    /// UTC hour of the start time
+    pub start_hour: Option<u8>,
+
+    /// UTC minute of the start time
+    pub start_minute: Option<u8>,
+
+    /// Duration of the notice in minutes, `None` = undefined
+    pub duration_minutes: Option<u32>,
+
+    /// Sub-areas which together define the area
+    pub sub_areas: Vec<SubArea>,
+}
+
+impl AreaNotice {
+    /// Return the category of the notice.
+    pub fn category(&self) -> AreaNoticeCategory {
+        AreaNoticeCategory::new(self.notice_type)
+    }
+
+    /// Return true if the message cancels the area with the same message linkage ID.
+    pub fn is_cancellation(&self) -> bool {
+        self.notice_type == 126
+    }
+
+    /// Return the associated text of all text sub-areas joined together.
+    pub fn text(&self) -> String {
+        self.sub_areas
+            .iter()
+            .filter_map(|sa| match sa {
+                SubArea::Text(s) => Some(s.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+/// Category of an area notice
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AreaNoticeCategory {
+    /// Caution area (0-22)
+    Caution,
+
+    /// Environmental caution area (23-31)
+    EnvironmentalCaution,
+
+    /// Restricted area (32-39)
+    Restricted,
+
+    /// Anchorage area (40-55)
+    Anchorage,
+
+    /// Security alert (56-63)
+    SecurityAlert,
+
+    /// Distress area (64-79)
+    Distress,
+
+    /// Instruction (80-87)
+    Instruction,
+
+    /// Information (88-95)
+    Information,
+
+    /// Chart feature (96-111)
+    ChartFeature,
+
+    /// Report from ship (112-119)
+    ShipReport,
+
+    /// Route (120-124)
+    Route,
+
+    /// Other, defined in the associated text (125)
+    Other,
+
+    /// Cancellation of an earlier notice (126)
+    Cancellation,
+
+    /// Undefined (127)
+    Undefined,
+}
+
+impl AreaNoticeCategory {
+    fn new(val: u8) -> AreaNoticeCategory {
+        match val {
+            0..=22 => AreaNoticeCategory::Caution,
+            23..=31 => AreaNoticeCategory::EnvironmentalCaution,
+            32..=39 => AreaNoticeCategory::Restricted,
+            40..=55 => AreaNoticeCategory::Anchorage,
+            56..=63 => AreaNoticeCategory::SecurityAlert,
+            64..=79 => AreaNoticeCategory::Distress,
+            80..=87 => AreaNoticeCategory::Instruction,
+            88..=95 => AreaNoticeCategory::Information,
+            96..=111 => AreaNoticeCategory::ChartFeature,
+            112..=119 => AreaNoticeCategory::ShipReport,
+            120..=124 => AreaNoticeCategory::Route,
+            125 => AreaNoticeCategory::Other,
+            126 => AreaNoticeCategory::Cancellation,
+            _ => AreaNoticeCategory::Undefined,
+        }
+    }
+}
+
+impl core::fmt::Display for AreaNoticeCategory {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            AreaNoticeCategory::Caution => write!(f, "caution area"),
+            AreaNoticeCategory::EnvironmentalCaution => write!(f, "environmental caution area"),
+            AreaNoticeCategory::Restricted => write!(f, "restricted area"),
+            AreaNoticeCategory::Anchorage => write!(f, "anchorage area"),
+            AreaNoticeCategory::SecurityAlert => write!(f, "security alert"),
+            AreaNoticeCategory::Distress => write!(f, "distress area"),
+            AreaNoticeCategory::Instruction => write!(f, "instruction"),
+            AreaNoticeCategory::Information => write!(f, "information"),
+            AreaNoticeCategory::ChartFeature => write!(f, "chart feature"),
+            AreaNoticeCategory::ShipReport => write!(f, "report from ship"),
+            AreaNoticeCategory::Route => write!(f, "route"),
+            AreaNoticeCategory::Other => write!(f, "other"),
+            AreaNoticeCategory::Cancellation => write!(f, "cancellation"),
+            AreaNoticeCategory::Undefined => write!(f, "undefined"),
+        }
+    }
+}
+
+/// Sub-area of an area notice. Distances are in metres and angles in degrees.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SubArea {
+    /// Circle, or a point when the radius is zero
+    Circle {
+        latitude: Option<f64>,
+        longitude: Option<f64>,
+        radius: u32,
+    },
+
+    /// Rectangle whose south-west corner is at the given position
+    Rectangle {
+        latitude: Option<f64>,
+        longitude: Option<f64>,
+        east_dimension: u32,
+        north_dimension: u32,
+        orientation: u16,
+    },
+
+    /// Sector of a circle, bounds measured clockwise from true north
+    Sector {
+        latitude: Option<f64>,
+        longitude: Option<f64>,
+        radius: u32,
+        left_bound: u16,
+        right_bound: u16,
+    },
+
+    /// Polyline continuing from the position of the preceding sub-area
+    Polyline(Vec<PolarPoint>),
+
+    /// Polygon continuing from the position of the preceding sub-area
+    Polygon(Vec<PolarPoint>),
+
+    /// Associated text
+    Text(String),
+
+    /// Reserved shape
+    Reserved,
+}
+
+/// Point given as a bearing and distance from the previous point
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PolarPoint {
+    /// Bearing from the previous point in degrees
+    pub angle: f64,
+
+    /// Distance from the previous point in metres
+    pub distance: u32,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Number of bits in each sub-area
+const SUB_AREA_BITS: usize = 87;
+
+/// Parse polyline or polygon points. Points with an angle of 360 degrees or more are unused.
+fn pick_points(data: &BitVec, index: usize, scale: u32) -> Vec<PolarPoint> {
+    (0..4)
+        .map(|i| {
+            (
+                pick_u64(data, index + i * 20, 10),
+                pick_u64(data, index + i * 20 + 10, 10),
+            )
+        })
+        .take_while(|(angle, _)| *angle < 720)
+        .map(|(angle, distance)| PolarPoint {
+            angle: angle as f64 * 0.5,
+            distance: distance as u32 * scale,
+        })
+        .collect()
+}
+
+/// Parse sub-area starting from the given index.
+fn pick_sub_area(data: &BitVec, index: usize) -> SubArea {
+    let scale = 10u32.pow(pick_u64(data, index + 3, 2) as u32);
+    match pick_u64(data, index, 3) {
+        0 => SubArea::Circle {
+            longitude: pick_longitude(data, index + 5),
+            latitude: pick_latitude(data, index + 30),
+            radius: pick_u64(data, index + 57, 12) as u32 * scale,
+        },
+        1 => SubArea::Rectangle {
+            longitude: pick_longitude(data, index + 5),
+            latitude: pick_latitude(data, index + 30),
+            east_dimension: pick_u64(data, index + 57, 8) as u32 * scale,
+            north_dimension: pick_u64(data, index + 65, 8) as u32 * scale,
+            orientation: pick_u64(data, index + 73, 9) as u16,
+        },
+        2 => SubArea::Sector {
+            longitude: pick_longitude(data, index + 5),
+            latitude: pick_latitude(data, index + 30),
+            radius: pick_u64(data, index + 57, 12) as u32 * scale,
+            left_bound: pick_u64(data, index + 69, 9) as u16,
+            right_bound: pick_u64(data, index + 78, 9) as u16,
+        },
+        3 => SubArea::Polyline(pick_points(data, index + 5, scale)),
+        4 => SubArea::Polygon(pick_points(data, index + 5, scale)),
+        5 => SubArea::Text(pick_string(data, index + 3, 14)),
+        _ => SubArea::Reserved,
+    }
+}
+
+/// DAC 1, FI 22: Area notice
+pub(crate) fn handle(data: &BitVec) -> Result<ApplicationData, ParseError> {
+    let sub_area_count = data.len().saturating_sub(55) / SUB_AREA_BITS;
+    Ok(ApplicationData::AreaNotice(AreaNotice {
+        message_linkage_id: { pick_u64(data, 0, 10) as u16 },
+        notice_type: { pick_u64(data, 10, 7) as u8 },
+        start_month: { Some(pick_u64(data, 17, 4) as u8).filter(|m| (1..=12).contains(m)) },
+        start_day: { Some(pick_u64(data, 21, 5) as u8).filter(|d| *d > 0) },
+        start_hour: { Some(pick_u64(data, 26, 5) as u8).filter(|h| *h < 24) },
+        start_minute: { Some(pick_u64(data, 31, 6) as u8).filter(|m| *m < 60) },
+        duration_minutes: { Some(pick_u64(data, 37, 18) as u32).filter(|d| *d != 262143) },
+        sub_areas: {
+            (0..sub_area_count)
+                .map(|i| pick_sub_area(data, 55 + i * SUB_AREA_BITS))
+                .collect()
+        },
+    }))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_area_notice() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("!AIVDM,2,1,1,A,85M:Ih00E`1Afi3h1J0MvrB1=G;20j000TFP7c@2,0*42") {
+            Ok(ps) => match ps {
+                ParsedMessage::Incomplete => {}
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+
+        match p.parse_sentence("!AIVDM,2,2,1,A,U`01J00549F5BCP4?G>000,0*31") {
+            Ok(ps) => match ps {
+                ParsedMessage::BinaryBroadcastMessage(bbm) => match bbm.application_data {
+                    Some(ApplicationData::AreaNotice(an)) => {
+                        assert_eq!(an.message_linkage_id, 513);
+                        assert_eq!(an.notice_type, 35);
+                        assert_eq!(an.category(), AreaNoticeCategory::Restricted);
+                        assert!(!an.is_cancellation());
+                        assert_eq!(an.start_month, Some(7));
+                        assert_eq!(an.start_day, Some(12));
+                        assert_eq!(an.start_hour, Some(8));
+                        assert_eq!(an.start_minute, Some(30));
+                        assert_eq!(an.duration_minutes, Some(720));
+                        assert_eq!(an.sub_areas.len(), 3);
+                        match &an.sub_areas[0] {
+                            SubArea::Circle {
+                                latitude,
+                                longitude,
+                                radius,
+                            } => {
+                                assert::close(latitude.unwrap_or(0.0), 42.25, 0.0001);
+                                assert::close(longitude.unwrap_or(0.0), -70.5, 0.0001);
+                                assert_eq!(*radius, 500);
+                            }
+                            sa => {
+                                panic!("Unexpected sub-area: {:?}", sa);
+                            }
+                        }
+                        assert_eq!(
+                            an.sub_areas[1],
+                            SubArea::Polygon(vec![
+                                PolarPoint {
+                                    angle: 90.0,
+                                    distance: 1500
+                                },
+                                PolarPoint {
+                                    angle: 180.0,
+                                    distance: 2000
+                                },
+                            ])
+                        );
+                        assert_eq!(an.text(), "DIVERS DOWN");
+                    }
+                    _ => {
+                        panic!("Unexpected application data: {:?}", bbm.application_data);
+                    }
+                },
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+}
@@ -0,0 +1,186 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+// -------------------------------------------------------------------------------------------------
+
+/// DAC 235/250, FI 10: Aids-to-navigation monitoring data (UK and ROI)
+#[derive(Clone, Debug, PartialEq)]
+pub struct AtonMonitoringData {
+    /// Internal supply voltage in volts (0.05 V resolution)
+    pub internal_voltage: f64,
+
+    /// External analogue input 1 in volts (0.05 V resolution)
+    pub external_voltage1: f64,
+
+    /// External analogue input 2 in volts (0.05 V resolution)
+    pub external_voltage2: f64,
+
+    /// Status of the RACON
+    pub racon_status: RaconStatus,
+
+    /// Status of the light
+    pub light_status: LightStatus,
+
+    /// Health alarm: false = good health, true = alarm
+    pub alarm: bool,
+
+    /// Extended status bits of the digital inputs (8 bits)
+    pub digital_inputs: u8,
+
+    /// Off-position status: true = off position, false = on position
+    pub off_position: bool,
+}
+
+impl AtonMonitoringData {
+    /// Return true if the aid to navigation reports good health and is on position.
+    pub fn is_healthy(&self) -> bool {
+        !self.alarm && !self.off_position
+    }
+}
+
+/// RACON status of an aid to navigation
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RaconStatus {
+    /// No RACON installed
+    NotFitted,
+
+    /// RACON installed but not monitored
+    NotMonitored,
+
+    /// RACON operational
+    Operational,
+
+    /// RACON error
+    Error,
+}
+
+impl RaconStatus {
+    fn new(val: u8) -> RaconStatus {
+        match val {
+            0 => RaconStatus::NotFitted,
+            1 => RaconStatus::NotMonitored,
+            2 => RaconStatus::Operational,
+            _ => RaconStatus::Error,
+        }
+    }
+}
+
+impl core::fmt::Display for RaconStatus {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            RaconStatus::NotFitted => write!(f, "not fitted"),
+            RaconStatus::NotMonitored => write!(f, "not monitored"),
+            RaconStatus::Operational => write!(f, "operational"),
+            RaconStatus::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// Light status of an aid to navigation
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LightStatus {
+    /// No light or no monitoring
+    NotMonitored,
+
+    /// Light on
+    On,
+
+    /// Light off
+    Off,
+
+    /// Light failure or reduced range
+    Error,
+}
+
+impl LightStatus {
+    fn new(val: u8) -> LightStatus {
+        match val {
+            0 => LightStatus::NotMonitored,
+            1 => LightStatus::On,
+            2 => LightStatus::Off,
+            _ => LightStatus::Error,
+        }
+    }
+}
+
+impl core::fmt::Display for LightStatus {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            LightStatus::NotMonitored => write!(f, "not monitored"),
+            LightStatus::On => write!(f, "on"),
+            LightStatus::Off => write!(f, "off"),
+            LightStatus::Error => write!(f, "error"),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// DAC 235/250, FI 10: Aids-to-navigation monitoring data
+pub(crate) fn handle(data: &BitVec) -> Result<ApplicationData, ParseError> {
+    Ok(ApplicationData::AtonMonitoring(AtonMonitoringData {
+        internal_voltage: { pick_u64(data, 0, 10) as f64 * 0.05 },
+        external_voltage1: { pick_u64(data, 10, 10) as f64 * 0.05 },
+        external_voltage2: { pick_u64(data, 20, 10) as f64 * 0.05 },
+        racon_status: { RaconStatus::new(pick_u64(data, 30, 2) as u8) },
+        light_status: { LightStatus::new(pick_u64(data, 32, 2) as u8) },
+        alarm: { pick_u64(data, 34, 1) != 0 },
+        digital_inputs: { pick_u64(data, 35, 8) as u8 },
+        off_position: { pick_u64(data, 43, 1) != 0 },
+    }))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_aton_monitoring() {
+        match NmeaParser::new().parse_sentence("!AIVDM,1,1,,A,8>jHD0PrjSr7P090d0,4*11") {
+            Ok(ps) => match ps {
+                ParsedMessage::BinaryBroadcastMessage(bbm) => {
+                    assert_eq!(bbm.mmsi, 992351234);
+                    match bbm.application_data {
+                        Some(ApplicationData::AtonMonitoring(am)) => {
+                            assert::close(am.internal_voltage, 12.5, 0.001);
+                            assert::close(am.external_voltage1, 6.0, 0.001);
+                            assert::close(am.external_voltage2, 0.0, 0.001);
+                            assert_eq!(am.racon_status, RaconStatus::Operational);
+                            assert_eq!(am.light_status, LightStatus::On);
+                            assert!(!am.alarm);
+                            assert_eq!(am.digital_inputs, 0b00000101);
+                            assert!(am.off_position);
+                            assert!(!am.is_healthy());
+                        }
+                        _ => {
+                            panic!("Unexpected application data: {:?}", bbm.application_data);
+                        }
+                    }
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+}
@@ -0,0 +1,290 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+// -------------------------------------------------------------------------------------------------
+
+/// DAC 367, FI 33: Environmental message of the US Coast Guard. The message carries up to eight
+/// sensor reports of a monitoring site.
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct EnvironmentalMessage {
+    /// Sensor reports
+    pub reports: Vec<SensorReport>,
+}
+
+impl EnvironmentalMessage {
+    /// Return the station name given by a station ID report.
+    pub fn station_name(&self) -> Option<&str> {
+        self.reports.iter().find_map(|r| match &r.data {
+            SensorReportData::StationId(name) => Some(name.as_str()),
+            _ => None,
+        })
+    }
+}
+
+/// Single sensor report of an environmental message
+#[derive(Clone, Debug, PartialEq)]
+pub struct SensorReport {
+    /// UTC day of month of the report
+    pub day: Option<u8>,
+
+    /// UTC hour of the report
+    pub hour: Option<u8>,
+
+    /// UTC minute of the report
+    pub minute: Option<u8>,
+
+    /// Site ID connecting the reports of the same monitoring site (7 bits)
+    pub site_id: u8,
+
+    /// Report type specific data
+    pub data: SensorReportData,
+}
+
+/// Report type specific data of a sensor report
+#[derive(Clone, Debug, PartialEq)]
+pub enum SensorReportData {
+    /// Report type 0: Location of the monitoring site
+    SiteLocation {
+        latitude: Option<f64>,
+        longitude: Option<f64>,
+        altitude: Option<f64>,
+        owner: u8,
+        timeout: u8,
+    },
+
+    /// Report type 1: Name of the monitoring station
+    StationId(String),
+
+    /// Report type 2: Wind observation and forecast, speeds in knots and directions in degrees
+    Wind {
+        speed_knots: Option<u8>,
+        gust_knots: Option<u8>,
+        direction: Option<u16>,
+        gust_direction: Option<u16>,
+        forecast_speed_knots: Option<u8>,
+        forecast_gust_knots: Option<u8>,
+        forecast_direction: Option<u16>,
+    },
+
+    /// Report type 3: Water level observation and forecast in metres
+    WaterLevel {
+        is_depth: bool,
+        level: Option<f64>,
+        trend: Option<Tendency>,
+        vertical_datum: u8,
+        forecast_level: Option<f64>,
+    },
+
+    /// Report types not decoded by the crate
+    Other { report_type: u8, bits: BitVec },
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Number of bits in each sensor report
+const SENSOR_REPORT_BITS: usize = 112;
+
+/// Pick a 7-bit speed in knots, 127 means not available.
+fn pick_speed(data: &BitVec, index: usize) -> Option<u8> {
+    Some(pick_u64(data, index, 7) as u8).filter(|s| *s < 127)
+}
+
+/// Pick a 9-bit direction in degrees, values above 359 mean not available.
+fn pick_direction(data: &BitVec, index: usize) -> Option<u16> {
+    Some(pick_u64(data, index, 9) as u16).filter(|d| *d < 360)
+}
+
+/// Pick a 16-bit signed water level in centimetres.
+fn pick_level(data: &BitVec, index: usize) -> Option<f64> {
+    Some(pick_i64(data, index, 16))
+        .filter(|l| *l != -32768)
+        .map(|l| l as f64 * 0.01)
+}
+
+/// Parse the sensor report starting from the given index.
+fn pick_report(data: &BitVec, index: usize) -> SensorReport {
+    let report_type = pick_u64(data, index, 4) as u8;
+    let i = index + 27;
+    SensorReport {
+        day: { Some(pick_u64(data, index + 4, 5) as u8).filter(|d| *d > 0) },
+        hour: { Some(pick_u64(data, index + 9, 5) as u8).filter(|h| *h < 24) },
+        minute: { Some(pick_u64(data, index + 14, 6) as u8).filter(|m| *m < 60) },
+        site_id: { pick_u64(data, index + 20, 7) as u8 },
+        data: match report_type {
+            0 => SensorReportData::SiteLocation {
+                longitude: {
+                    let lon_raw = pick_i64(data, i, 28);
+                    if lon_raw.abs() <= 180 * 600000 {
+                        Some(lon_raw as f64 / 600000.0)
+                    } else {
+                        None
+                    }
+                },
+                latitude: {
+                    let lat_raw = pick_i64(data, i + 28, 27);
+                    if lat_raw.abs() <= 90 * 600000 {
+                        Some(lat_raw as f64 / 600000.0)
+                    } else {
+                        None
+                    }
+                },
+                altitude: {
+                    Some(pick_u64(data, i + 58, 12))
+                        .filter(|a| *a != 4095)
+                        .map(|a| a as f64 * 0.1)
+                },
+                owner: pick_u64(data, i + 70, 4) as u8,
+                timeout: pick_u64(data, i + 74, 3) as u8,
+            },
+            1 => SensorReportData::StationId(pick_string(data, i, 14)),
+            2 => SensorReportData::Wind {
+                speed_knots: pick_speed(data, i),
+                gust_knots: pick_speed(data, i + 7),
+                direction: pick_direction(data, i + 14),
+                gust_direction: pick_direction(data, i + 23),
+                forecast_speed_knots: pick_speed(data, i + 35),
+                forecast_gust_knots: pick_speed(data, i + 42),
+                forecast_direction: pick_direction(data, i + 49),
+            },
+            3 => SensorReportData::WaterLevel {
+                is_depth: pick_u64(data, i, 1) != 0,
+                level: pick_level(data, i + 1),
+                trend: Tendency::new(pick_u64(data, i + 17, 2) as u8),
+                vertical_datum: pick_u64(data, i + 19, 5) as u8,
+                forecast_level: pick_level(data, i + 28),
+            },
+            _ => SensorReportData::Other {
+                report_type,
+                bits: BitVec::from_bitslice(&data[i..min(i + 85, data.len())]),
+            },
+        },
+    }
+}
+
+/// DAC 367, FI 33: Environmental message
+pub(crate) fn handle(data: &BitVec) -> Result<ApplicationData, ParseError> {
+    let count = data.len() / SENSOR_REPORT_BITS;
+    Ok(ApplicationData::Environmental(EnvironmentalMessage {
+        reports: {
+            (0..count)
+                .map(|i| pick_report(data, i * SENSOR_REPORT_BITS))
+                .collect()
+        },
+    }))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_environmental_message() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence(
+            "!AIVDM,2,1,4,B,803OwliKp@M1@;V>i>94S=@@?`d05l50`H:BAqQRQqh0000W@D2RQs8JAwws,0*19",
+        ) {
+            Ok(ps) => match ps {
+                ParsedMessage::Incomplete => {}
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+        match p.parse_sentence("!AIVDM,2,2,4,B,@37Wv3M1@:wum4D000iqwP00,0*51") {
+            Ok(ps) => match ps {
+                ParsedMessage::BinaryBroadcastMessage(bbm) => {
+                    assert_eq!(bbm.dac, 367);
+                    assert_eq!(bbm.fid, 33);
+                    match bbm.application_data {
+                        Some(ApplicationData::Environmental(em)) => {
+                            assert_eq!(em.reports.len(), 4);
+                            assert_eq!(em.station_name(), Some("CARROLLTON"));
+                            let r = &em.reports[0];
+                            assert_eq!(r.day, Some(14));
+                            assert_eq!(r.hour, Some(16));
+                            assert_eq!(r.minute, Some(20));
+                            assert_eq!(r.site_id, 5);
+                            match &r.data {
+                                SensorReportData::SiteLocation {
+                                    latitude,
+                                    longitude,
+                                    altitude,
+                                    owner,
+                                    timeout,
+                                } => {
+                                    assert::close(latitude.unwrap_or(0.0), 29.95, 0.0001);
+                                    assert::close(longitude.unwrap_or(0.0), -90.07, 0.0001);
+                                    assert::close(altitude.unwrap_or(0.0), 12.5, 0.001);
+                                    assert_eq!(*owner, 1);
+                                    assert_eq!(*timeout, 3);
+                                }
+                                _ => {
+                                    panic!("Unexpected report: {:?}", r);
+                                }
+                            }
+                            assert_eq!(
+                                em.reports[2].data,
+                                SensorReportData::Wind {
+                                    speed_knots: Some(10),
+                                    gust_knots: Some(15),
+                                    direction: Some(200),
+                                    gust_direction: Some(210),
+                                    forecast_speed_knots: None,
+                                    forecast_gust_knots: None,
+                                    forecast_direction: None,
+                                }
+                            );
+                            match &em.reports[3].data {
+                                SensorReportData::WaterLevel {
+                                    is_depth,
+                                    level,
+                                    trend,
+                                    vertical_datum,
+                                    forecast_level,
+                                } => {
+                                    assert!(!is_depth);
+                                    assert::close(level.unwrap_or(0.0), -0.35, 0.0001);
+                                    assert_eq!(*trend, Some(Tendency::Decreasing));
+                                    assert_eq!(*vertical_datum, 2);
+                                    assert_eq!(*forecast_level, None);
+                                }
+                                _ => {
+                                    panic!("Unexpected report: {:?}", em.reports[3]);
+                                }
+                            }
+                        }
+                        _ => {
+                            panic!("Unexpected application data: {:?}", bbm.application_data);
+                        }
+                    }
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+}
@@ -0,0 +1,314 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+/// DAC 200, FI 21: Inland ETA report at lock, bridge or terminal
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct InlandEta {
+    /// UN country code (2 characters)
+    pub country: String,
+
+    /// UN/LOCODE location code (3 characters)
+    pub location: String,
+
+    /// Fairway section number (5 characters)
+    pub fairway_section: String,
+
+    /// Terminal code (5 characters)
+    pub terminal: String,
+
+    /// Fairway hectometre (5 characters)
+    pub hectometre: String,
+
+    /// Estimated time of arrival at the lock, bridge or terminal
+    pub eta: Option<DateTime<Utc>>,
+
+    /// Number of assisting tugboats, `None` = unknown
+    pub tugboats: Option<u8>,
+
+    /// Air draught in metres (0.01 m resolution)
+    pub air_draught: Option<f64>,
+}
+
+/// DAC 200, FI 22: Inland RTA response at lock, bridge or terminal
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct InlandRta {
+    /// UN country code (2 characters)
+    pub country: String,
+
+    /// UN/LOCODE location code (3 characters)
+    pub location: String,
+
+    /// Fairway section number (5 characters)
+    pub fairway_section: String,
+
+    /// Terminal code (5 characters)
+    pub terminal: String,
+
+    /// Fairway hectometre (5 characters)
+    pub hectometre: String,
+
+    /// Recommended time of arrival at the lock, bridge or terminal
+    pub rta: Option<DateTime<Utc>>,
+
+    /// Status of the lock, bridge or terminal
+    pub status: Option<LockStatus>,
+}
+
+/// Status of a lock, bridge or terminal
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LockStatus {
+    /// Operational
+    Operational,
+
+    /// Limited operation (e.g. obstructed by ice)
+    LimitedOperation,
+
+    /// Out of order
+    OutOfOrder,
+}
+
+impl core::fmt::Display for LockStatus {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            LockStatus::Operational => write!(f, "operational"),
+            LockStatus::LimitedOperation => write!(f, "limited operation"),
+            LockStatus::OutOfOrder => write!(f, "out of order"),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// DAC 200, FI 10: Inland ship static and voyage related data
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct InlandStaticVoyageData {
+    /// European vessel identification number (ENI)
+    pub eni: String,
+
+    /// Length of the ship or convoy in metres (0.1 m resolution)
+    pub length: Option<f64>,
+
+    /// Beam of the ship or convoy in metres (0.1 m resolution)
+    pub beam: Option<f64>,
+
+    /// Ship or combination type according to the ERI classification (14 bits)
+    pub ship_type: u16,
+
+    /// Hazardous cargo code: 0-3 = number of blue cones/lights, 4 = B-flag, 5 = unknown
+    pub hazardous_cargo: u8,
+
+    /// Static draught in metres (0.01 m resolution)
+    pub draught: Option<f64>,
+
+    /// Loaded state: true = loaded, false = unloaded
+    pub loaded: Option<bool>,
+
+    /// True if the speed information comes from a certified sensor
+    pub speed_quality: bool,
+
+    /// True if the course information comes from a certified sensor
+    pub course_quality: bool,
+
+    /// True if the heading information comes from a certified sensor
+    pub heading_quality: bool,
+}
+
+impl InlandStaticVoyageData {
+    /// Return the number of blue cones or lights, or `None` if the cargo is unknown or marked
+    /// with a B-flag.
+    pub fn blue_cones(&self) -> Option<u8> {
+        if self.hazardous_cargo <= 3 {
+            Some(self.hazardous_cargo)
+        } else {
+            None
+        }
+    }
+
+    /// Return true if the ship carries a B-flag.
+    pub fn has_b_flag(&self) -> bool {
+        self.hazardous_cargo == 4
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// DAC 200, FI 10: Inland ship static and voyage related data
+pub(crate) fn handle_fi10(data: &BitVec) -> Result<ApplicationData, ParseError> {
+    Ok(ApplicationData::InlandStaticVoyage(
+        InlandStaticVoyageData {
+            eni: { pick_string(data, 0, 8) },
+            length: {
+                Some(pick_u64(data, 48, 13))
+                    .filter(|l| *l > 0)
+                    .map(|l| l as f64 * 0.1)
+            },
+            beam: {
+                Some(pick_u64(data, 61, 10))
+                    .filter(|b| *b > 0)
+                    .map(|b| b as f64 * 0.1)
+            },
+            ship_type: { pick_u64(data, 71, 14) as u16 },
+            hazardous_cargo: { pick_u64(data, 85, 3) as u8 },
+            draught: {
+                Some(pick_u64(data, 88, 11))
+                    .filter(|d| *d > 0)
+                    .map(|d| d as f64 * 0.01)
+            },
+            loaded: {
+                match pick_u64(data, 99, 2) {
+                    1 => Some(true),
+                    2 => Some(false),
+                    _ => None,
+                }
+            },
+            speed_quality: { pick_u64(data, 101, 1) != 0 },
+            course_quality: { pick_u64(data, 102, 1) != 0 },
+            heading_quality: { pick_u64(data, 103, 1) != 0 },
+        },
+    ))
+}
+
+/// DAC 200, FI 21: Inland ETA report
+pub(crate) fn handle_fi21(data: &BitVec) -> Result<ApplicationData, ParseError> {
+    Ok(ApplicationData::InlandEta(InlandEta {
+        country: { pick_string(data, 0, 2) },
+        location: { pick_string(data, 12, 3) },
+        fairway_section: { pick_string(data, 30, 5) },
+        terminal: { pick_string(data, 60, 5) },
+        hectometre: { pick_string(data, 90, 5) },
+        eta: { pick_eta(data, 120)? },
+        tugboats: { Some(pick_u64(data, 140, 3) as u8).filter(|t| *t < 7) },
+        air_draught: {
+            Some(pick_u64(data, 143, 12))
+                .filter(|d| *d > 0)
+                .map(|d| d as f64 * 0.01)
+        },
+    }))
+}
+
+/// DAC 200, FI 22: Inland RTA response
+pub(crate) fn handle_fi22(data: &BitVec) -> Result<ApplicationData, ParseError> {
+    Ok(ApplicationData::InlandRta(InlandRta {
+        country: { pick_string(data, 0, 2) },
+        location: { pick_string(data, 12, 3) },
+        fairway_section: { pick_string(data, 30, 5) },
+        terminal: { pick_string(data, 60, 5) },
+        hectometre: { pick_string(data, 90, 5) },
+        rta: { pick_eta(data, 120)? },
+        status: {
+            match pick_u64(data, 140, 2) {
+                0 => Some(LockStatus::Operational),
+                1 => Some(LockStatus::LimitedOperation),
+                2 => Some(LockStatus::OutOfOrder),
+                _ => None,
+            }
+        },
+    }))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_inland_static_voyage() {
+        match NmeaParser::new().parse_sentence("!AIVDM,1,1,,A,839ed50j2d=><<uMd29Pq?aB8hp0,0*55") {
+            Ok(ps) => match ps {
+                ParsedMessage::BinaryBroadcastMessage(bbm) => match bbm.application_data {
+                    Some(ApplicationData::InlandStaticVoyage(isv)) => {
+                        assert_eq!(isv.eni, "04803560");
+                        assert::close(isv.length.unwrap_or(0.0), 110.0, 0.001);
+                        assert::close(isv.beam.unwrap_or(0.0), 11.4, 0.001);
+                        assert_eq!(isv.ship_type, 8010);
+                        assert_eq!(isv.blue_cones(), Some(2));
+                        assert!(!isv.has_b_flag());
+                        assert::close(isv.draught.unwrap_or(0.0), 2.8, 0.001);
+                        assert_eq!(isv.loaded, Some(true));
+                        assert!(isv.speed_quality);
+                        assert!(isv.course_quality);
+                        assert!(!isv.heading_quality);
+                    }
+                    _ => {
+                        panic!("Unexpected application data: {:?}", bbm.application_data);
+                    }
+                },
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_inland_eta_rta() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("!AIVDM,1,1,,A,639ed500P@M0<QD@DADW37;?AC333733OO=WeeB3@0,4*55") {
+            Ok(ps) => match ps {
+                ParsedMessage::BinaryAddressedMessage(bam) => match bam.application_data {
+                    Some(ApplicationData::InlandEta(eta)) => {
+                        assert_eq!(eta.country, "DE");
+                        assert_eq!(eta.location, "DUI");
+                        assert_eq!(eta.fairway_section, "01234");
+                        assert_eq!(eta.terminal, "T0001");
+                        assert_eq!(eta.hectometre, "00773");
+                        let t = eta.eta.unwrap();
+                        assert_eq!((t.month(), t.day(), t.hour(), t.minute()), (6, 15, 13, 45));
+                        assert_eq!(eta.tugboats, Some(2));
+                        assert::close(eta.air_draught.unwrap_or(0.0), 10.5, 0.001);
+                    }
+                    _ => {
+                        panic!("Unexpected application data: {:?}", bam.application_data);
+                    }
+                },
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+
+        match p.parse_sentence("!AIVDM,1,1,,A,639ed500P@M0<QH@DADW37;?AC333733OO=Wee@,2*1E") {
+            Ok(ps) => match ps {
+                ParsedMessage::BinaryAddressedMessage(bam) => match bam.application_data {
+                    Some(ApplicationData::InlandRta(rta)) => {
+                        assert_eq!(rta.location, "DUI");
+                        let t = rta.rta.unwrap();
+                        assert_eq!((t.month(), t.day(), t.hour(), t.minute()), (6, 15, 13, 45));
+                        assert_eq!(rta.status, Some(LockStatus::LimitedOperation));
+                    }
+                    _ => {
+                        panic!("Unexpected application data: {:?}", bam.application_data);
+                    }
+                },
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+}
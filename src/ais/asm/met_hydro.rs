@@ -0,0 +1,510 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+// -------------------------------------------------------------------------------------------------
+
+/// DAC 1, FI 31: Meteorological and hydrological data (IMO 289). The legacy FI 11 message
+/// (IMO 236) is decoded into the same structure.
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct MeteorologicalHydrologicalData {
+    /// Latitude of the measuring station
+    pub latitude: Option<f64>,
+
+    /// Longitude of the measuring station
+    pub longitude: Option<f64>,
+
+    /// Position accuracy: true = high (<= 10 m), false = low (> 10 m)
+    pub high_position_accuracy: bool,
+
+    /// UTC day of month of the observation
+    pub day: Option<u8>,
+
+    /// UTC hour of the observation
+    pub hour: Option<u8>,
+
+    /// UTC minute of the observation
+    pub minute: Option<u8>,
+
+    /// Average wind speed in knots over the last 10 minutes
+    pub wind_speed_knots: Option<f64>,
+
+    /// Wind gust speed in knots over the last 10 minutes
+    pub wind_gust_knots: Option<f64>,
+
+    /// Wind direction in degrees
+    pub wind_direction: Option<u16>,
+
+    /// Wind gust direction in degrees
+    pub wind_gust_direction: Option<u16>,
+
+    /// Dry bulb air temperature in degrees Celsius
+    pub air_temperature: Option<f64>,
+
+    /// Relative humidity in percents
+    pub relative_humidity: Option<u8>,
+
+    /// Dew point in degrees Celsius
+    pub dew_point: Option<f64>,
+
+    /// Air pressure in hectopascals
+    pub air_pressure: Option<u16>,
+
+    /// Air pressure tendency
+    pub air_pressure_tendency: Option<Tendency>,
+
+    /// Horizontal visibility in nautical miles
+    pub visibility_nm: Option<f64>,
+
+    /// True if the visibility is greater than the given value
+    pub visibility_greater_than: bool,
+
+    /// Water level in metres, including tide, deviation from the local chart datum
+    pub water_level: Option<f64>,
+
+    /// Water level trend
+    pub water_level_trend: Option<Tendency>,
+
+    /// Surface current speed in knots
+    pub surface_current_knots: Option<f64>,
+
+    /// Surface current direction in degrees
+    pub surface_current_direction: Option<u16>,
+
+    /// Current speed in knots at the second measuring level
+    pub current2_knots: Option<f64>,
+
+    /// Current direction in degrees at the second measuring level
+    pub current2_direction: Option<u16>,
+
+    /// Depth of the second current measuring level in metres
+    pub current2_depth: Option<u8>,
+
+    /// Current speed in knots at the third measuring level
+    pub current3_knots: Option<f64>,
+
+    /// Current direction in degrees at the third measuring level
+    pub current3_direction: Option<u16>,
+
+    /// Depth of the third current measuring level in metres
+    pub current3_depth: Option<u8>,
+
+    /// Significant wave height in metres
+    pub wave_height: Option<f64>,
+
+    /// Wave period in seconds
+    pub wave_period: Option<u8>,
+
+    /// Wave direction in degrees
+    pub wave_direction: Option<u16>,
+
+    /// Swell height in metres
+    pub swell_height: Option<f64>,
+
+    /// Swell period in seconds
+    pub swell_period: Option<u8>,
+
+    /// Swell direction in degrees
+    pub swell_direction: Option<u16>,
+
+    /// Sea state according to the Beaufort scale (0-12)
+    pub sea_state: Option<u8>,
+
+    /// Water temperature in degrees Celsius
+    pub water_temperature: Option<f64>,
+
+    /// Type of precipitation
+    pub precipitation_type: Option<PrecipitationType>,
+
+    /// Salinity in parts per thousand
+    pub salinity: Option<f64>,
+
+    /// True if there is ice
+    pub ice: Option<bool>,
+}
+
+impl LatLon for MeteorologicalHydrologicalData {
+    fn latitude(&self) -> Option<f64> {
+        self.latitude
+    }
+
+    fn longitude(&self) -> Option<f64> {
+        self.longitude
+    }
+}
+
+/// Tendency of air pressure or water level
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Tendency {
+    /// Steady
+    Steady,
+
+    /// Decreasing
+    Decreasing,
+
+    /// Increasing
+    Increasing,
+}
+
+impl Tendency {
+    pub(crate) fn new(val: u8) -> Option<Tendency> {
+        match val {
+            0 => Some(Tendency::Steady),
+            1 => Some(Tendency::Decreasing),
+            2 => Some(Tendency::Increasing),
+            _ => None,
+        }
+    }
+}
+
+impl core::fmt::Display for Tendency {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Tendency::Steady => write!(f, "steady"),
+            Tendency::Decreasing => write!(f, "decreasing"),
+            Tendency::Increasing => write!(f, "increasing"),
+        }
+    }
+}
+
+/// Type of precipitation (WMO)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PrecipitationType {
+    /// Rain
+    Rain,
+
+    /// Thunderstorm
+    Thunderstorm,
+
+    /// Freezing rain
+    FreezingRain,
+
+    /// Mixed or ice
+    MixedOrIce,
+
+    /// Snow
+    Snow,
+
+    /// Reserved for future use
+    Reserved,
+}
+
+impl PrecipitationType {
+    fn new(val: u8) -> Option<PrecipitationType> {
+        match val {
+            1 => Some(PrecipitationType::Rain),
+            2 => Some(PrecipitationType::Thunderstorm),
+            3 => Some(PrecipitationType::FreezingRain),
+            4 => Some(PrecipitationType::MixedOrIce),
+            5 => Some(PrecipitationType::Snow),
+            7 => None,
+            _ => Some(PrecipitationType::Reserved),
+        }
+    }
+}
+
+impl core::fmt::Display for PrecipitationType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PrecipitationType::Rain => write!(f, "rain"),
+            PrecipitationType::Thunderstorm => write!(f, "thunderstorm"),
+            PrecipitationType::FreezingRain => write!(f, "freezing rain"),
+            PrecipitationType::MixedOrIce => write!(f, "mixed/ice"),
+            PrecipitationType::Snow => write!(f, "snow"),
+            PrecipitationType::Reserved => write!(f, "reserved"),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Pick an unsigned field which is available only when it is less than `limit`.
+fn pick_below(data: &BitVec, index: usize, len: usize, limit: u64) -> Option<u64> {
+    let val = pick_u64(data, index, len);
+    if val < limit {
+        Some(val)
+    } else {
+        None
+    }
+}
+
+/// Pick a 9-bit direction field in degrees. Values above 359 mean not available.
+fn pick_direction(data: &BitVec, index: usize) -> Option<u16> {
+    pick_below(data, index, 9, 360).map(|val| val as u16)
+}
+
+/// DAC 1, FI 31: Meteorological and hydrological data
+pub(crate) fn handle_fi31(data: &BitVec) -> Result<ApplicationData, ParseError> {
+    Ok(ApplicationData::MeteorologicalHydrological(
+        MeteorologicalHydrologicalData {
+            longitude: { pick_longitude(data, 0) },
+            latitude: { pick_latitude(data, 25) },
+            high_position_accuracy: { pick_u64(data, 49, 1) != 0 },
+            day: {
+                pick_below(data, 50, 5, 32)
+                    .filter(|d| *d > 0)
+                    .map(|d| d as u8)
+            },
+            hour: { pick_below(data, 55, 5, 24).map(|h| h as u8) },
+            minute: { pick_below(data, 60, 6, 60).map(|m| m as u8) },
+            wind_speed_knots: { pick_below(data, 66, 7, 127).map(|s| s as f64) },
+            wind_gust_knots: { pick_below(data, 73, 7, 127).map(|s| s as f64) },
+            wind_direction: { pick_direction(data, 80) },
+            wind_gust_direction: { pick_direction(data, 89) },
+            air_temperature: {
+                let raw = pick_i64(data, 98, 11);
+                if raw != -1024 {
+                    Some(raw as f64 * 0.1)
+                } else {
+                    None
+                }
+            },
+            relative_humidity: { pick_below(data, 109, 7, 101).map(|h| h as u8) },
+            dew_point: {
+                let raw = pick_i64(data, 116, 10);
+                if raw != 501 {
+                    Some(raw as f64 * 0.1)
+                } else {
+                    None
+                }
+            },
+            air_pressure: { pick_below(data, 126, 9, 403).map(|p| (p + 799) as u16) },
+            air_pressure_tendency: { Tendency::new(pick_u64(data, 135, 2) as u8) },
+            visibility_greater_than: { pick_u64(data, 137, 1) != 0 },
+            visibility_nm: { pick_below(data, 138, 7, 127).map(|v| v as f64 * 0.1) },
+            water_level: { pick_below(data, 145, 12, 4001).map(|l| l as f64 * 0.01 - 10.0) },
+            water_level_trend: { Tendency::new(pick_u64(data, 157, 2) as u8) },
+            surface_current_knots: { pick_below(data, 159, 8, 252).map(|s| s as f64 * 0.1) },
+            surface_current_direction: { pick_direction(data, 167) },
+            current2_knots: { pick_below(data, 176, 8, 252).map(|s| s as f64 * 0.1) },
+            current2_direction: { pick_direction(data, 184) },
+            current2_depth: { pick_below(data, 193, 5, 31).map(|d| d as u8) },
+            current3_knots: { pick_below(data, 198, 8, 252).map(|s| s as f64 * 0.1) },
+            current3_direction: { pick_direction(data, 206) },
+            current3_depth: { pick_below(data, 215, 5, 31).map(|d| d as u8) },
+            wave_height: { pick_below(data, 220, 8, 252).map(|h| h as f64 * 0.1) },
+            wave_period: { pick_below(data, 228, 6, 61).map(|p| p as u8) },
+            wave_direction: { pick_direction(data, 234) },
+            swell_height: { pick_below(data, 243, 8, 252).map(|h| h as f64 * 0.1) },
+            swell_period: { pick_below(data, 251, 6, 61).map(|p| p as u8) },
+            swell_direction: { pick_direction(data, 257) },
+            sea_state: { pick_below(data, 266, 4, 13).map(|s| s as u8) },
+            water_temperature: {
+                let raw = pick_i64(data, 270, 10);
+                if raw != 501 && raw != -512 {
+                    Some(raw as f64 * 0.1)
+                } else {
+                    None
+                }
+            },
+            precipitation_type: { PrecipitationType::new(pick_u64(data, 280, 3) as u8) },
+            salinity: { pick_below(data, 283, 9, 501).map(|s| s as f64 * 0.1) },
+            ice: {
+                match pick_u64(data, 292, 2) {
+                    0 => Some(false),
+                    1 => Some(true),
+                    _ => None,
+                }
+            },
+        },
+    ))
+}
+
+/// Pick a field with an offset and return `None` if it is the not-available value.
+fn pick_offset(data: &BitVec, index: usize, len: usize, scale: f64, offset: f64) -> Option<f64> {
+    let val = pick_u64(data, index, len);
+    if val != (1 << len) - 1 {
+        Some(val as f64 * scale + offset)
+    } else {
+        None
+    }
+}
+
+/// DAC 1, FI 11: Meteorological and hydrological data (IMO 236)
+pub(crate) fn handle_fi11(data: &BitVec) -> Result<ApplicationData, ParseError> {
+    Ok(ApplicationData::MeteorologicalHydrological(
+        MeteorologicalHydrologicalData {
+            latitude: { pick_latitude(data, 0) },
+            longitude: { pick_longitude(data, 24) },
+            high_position_accuracy: false,
+            day: {
+                pick_below(data, 49, 5, 32)
+                    .filter(|d| *d > 0)
+                    .map(|d| d as u8)
+            },
+            hour: { pick_below(data, 54, 5, 24).map(|h| h as u8) },
+            minute: { pick_below(data, 59, 6, 60).map(|m| m as u8) },
+            wind_speed_knots: { pick_below(data, 65, 7, 127).map(|s| s as f64) },
+            wind_gust_knots: { pick_below(data, 72, 7, 127).map(|s| s as f64) },
+            wind_direction: { pick_direction(data, 79) },
+            wind_gust_direction: { pick_direction(data, 88) },
+            air_temperature: { pick_offset(data, 97, 11, 0.1, -60.0) },
+            relative_humidity: { pick_below(data, 108, 7, 101).map(|h| h as u8) },
+            dew_point: { pick_offset(data, 115, 10, 0.1, -20.0) },
+            air_pressure: { pick_below(data, 125, 9, 511).map(|p| (p + 800) as u16) },
+            air_pressure_tendency: { Tendency::new(pick_u64(data, 134, 2) as u8) },
+            visibility_greater_than: false,
+            visibility_nm: { pick_offset(data, 136, 8, 0.1, 0.0) },
+            water_level: { pick_offset(data, 144, 9, 0.1, -10.0) },
+            water_level_trend: { Tendency::new(pick_u64(data, 153, 2) as u8) },
+            surface_current_knots: { pick_offset(data, 155, 8, 0.1, 0.0) },
+            surface_current_direction: { pick_direction(data, 163) },
+            current2_knots: { pick_offset(data, 172, 8, 0.1, 0.0) },
+            current2_direction: { pick_direction(data, 180) },
+            current2_depth: { pick_below(data, 189, 5, 31).map(|d| d as u8) },
+            current3_knots: { pick_offset(data, 194, 8, 0.1, 0.0) },
+            current3_direction: { pick_direction(data, 202) },
+            current3_depth: { pick_below(data, 211, 5, 31).map(|d| d as u8) },
+            wave_height: { pick_offset(data, 216, 8, 0.1, 0.0) },
+            wave_period: { pick_below(data, 224, 6, 63).map(|p| p as u8) },
+            wave_direction: { pick_direction(data, 230) },
+            swell_height: { pick_offset(data, 239, 8, 0.1, 0.0) },
+            swell_period: { pick_below(data, 247, 6, 63).map(|p| p as u8) },
+            swell_direction: { pick_direction(data, 253) },
+            sea_state: { pick_below(data, 262, 4, 13).map(|s| s as u8) },
+            water_temperature: { pick_offset(data, 266, 10, 0.1, -10.0) },
+            precipitation_type: { PrecipitationType::new(pick_u64(data, 276, 3) as u8) },
+            salinity: { pick_offset(data, 279, 9, 0.1, 0.0) },
+            ice: {
+                match pick_u64(data, 288, 2) {
+                    0 => Some(false),
+                    1 => Some(true),
+                    _ => None,
+                }
+            },
+        },
+    ))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_met_hydro_fi31() {
+        match NmeaParser::new().parse_sentence(
+            "!AIVDM,1,1,,A,802<HVh0GhWGR1fDS63WQQ9hfKseOk6dbb0l2Ucwe7wvlO31FqwwnQ0E8v00,0*6B",
+        ) {
+            Ok(ps) => match ps {
+                ParsedMessage::BinaryBroadcastMessage(bbm) => {
+                    assert_eq!(bbm.mmsi, 2300059);
+                    assert_eq!(bbm.dac, 1);
+                    assert_eq!(bbm.fid, 31);
+                    match bbm.application_data {
+                        Some(ApplicationData::MeteorologicalHydrological(mh)) => {
+                            assert::close(mh.latitude.unwrap_or(0.0), 60.25, 0.0001);
+                            assert::close(mh.longitude.unwrap_or(0.0), 21.5, 0.0001);
+                            assert!(mh.high_position_accuracy);
+                            assert_eq!(mh.day, Some(16));
+                            assert_eq!(mh.hour, Some(14));
+                            assert_eq!(mh.minute, Some(30));
+                            assert_eq!(mh.wind_speed_knots, Some(12.0));
+                            assert_eq!(mh.wind_gust_knots, Some(18.0));
+                            assert_eq!(mh.wind_direction, Some(225));
+                            assert_eq!(mh.wind_gust_direction, Some(230));
+                            assert::close(mh.air_temperature.unwrap_or(0.0), -3.5, 0.001);
+                            assert_eq!(mh.relative_humidity, Some(87));
+                            assert::close(mh.dew_point.unwrap_or(0.0), -5.2, 0.001);
+                            assert_eq!(mh.air_pressure, Some(1013));
+                            assert_eq!(mh.air_pressure_tendency, Some(Tendency::Decreasing));
+                            assert!(!mh.visibility_greater_than);
+                            assert::close(mh.visibility_nm.unwrap_or(0.0), 8.5, 0.001);
+                            assert::close(mh.water_level.unwrap_or(0.0), 0.3, 0.001);
+                            assert_eq!(mh.water_level_trend, Some(Tendency::Increasing));
+                            assert::close(mh.surface_current_knots.unwrap_or(0.0), 0.5, 0.001);
+                            assert_eq!(mh.surface_current_direction, Some(90));
+                            assert_eq!(mh.current2_knots, None);
+                            assert_eq!(mh.current2_direction, None);
+                            assert_eq!(mh.current2_depth, None);
+                            assert::close(mh.wave_height.unwrap_or(0.0), 1.2, 0.001);
+                            assert_eq!(mh.wave_period, Some(5));
+                            assert_eq!(mh.wave_direction, Some(220));
+                            assert_eq!(mh.swell_height, None);
+                            assert_eq!(mh.swell_period, None);
+                            assert_eq!(mh.sea_state, Some(4));
+                            assert::close(mh.water_temperature.unwrap_or(0.0), 2.1, 0.001);
+                            assert_eq!(mh.precipitation_type, Some(PrecipitationType::Rain));
+                            assert::close(mh.salinity.unwrap_or(0.0), 6.2, 0.001);
+                            assert_eq!(mh.ice, Some(false));
+                        }
+                        _ => {
+                            panic!("Unexpected application data: {:?}", bbm.application_data);
+                        }
+                    }
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_met_hydro_fi11() {
+        match NmeaParser::new().parse_sentence(
+            "!AIVDM,1,1,,A,83tfD@@0BkCCL0EjR0k02?rlwrmOr7<HI31wwwwwwwwwwhSwwwwww<ggwt0,2*70",
+        ) {
+            Ok(ps) => match ps {
+                ParsedMessage::BinaryBroadcastMessage(bbm) => {
+                    assert_eq!(bbm.dac, 1);
+                    assert_eq!(bbm.fid, 11);
+                    match bbm.application_data {
+                        Some(ApplicationData::MeteorologicalHydrological(mh)) => {
+                            assert::close(mh.latitude.unwrap_or(0.0), 57.7, 0.0001);
+                            assert::close(mh.longitude.unwrap_or(0.0), 11.9, 0.0001);
+                            assert_eq!(mh.day, Some(3));
+                            assert_eq!(mh.hour, Some(6));
+                            assert_eq!(mh.minute, Some(0));
+                            assert_eq!(mh.wind_speed_knots, Some(8.0));
+                            assert_eq!(mh.wind_gust_knots, None);
+                            assert_eq!(mh.wind_direction, Some(180));
+                            assert::close(mh.air_temperature.unwrap_or(0.0), 12.5, 0.001);
+                            assert_eq!(mh.relative_humidity, None);
+                            assert::close(mh.dew_point.unwrap_or(0.0), 7.0, 0.001);
+                            assert_eq!(mh.air_pressure, Some(998));
+                            assert_eq!(mh.air_pressure_tendency, Some(Tendency::Steady));
+                            assert::close(mh.visibility_nm.unwrap_or(0.0), 10.0, 0.001);
+                            assert::close(mh.water_level.unwrap_or(0.0), -0.4, 0.001);
+                            assert_eq!(mh.water_level_trend, None);
+                            assert_eq!(mh.surface_current_knots, None);
+                            assert::close(mh.wave_height.unwrap_or(0.0), 0.8, 0.001);
+                            assert_eq!(mh.sea_state, Some(3));
+                            assert::close(mh.water_temperature.unwrap_or(0.0), 9.0, 0.001);
+                            assert_eq!(mh.precipitation_type, None);
+                            assert_eq!(mh.salinity, None);
+                            assert_eq!(mh.ice, None);
+                        }
+                        _ => {
+                            panic!("Unexpected application data: {:?}", bbm.application_data);
+                        }
+                    }
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+}
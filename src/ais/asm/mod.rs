@@ -0,0 +1,162 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+//! AIS application specific messages (ASM) carried by binary message types 6 and 8
+
+pub(crate) mod area_notice;
+pub(crate) mod aton_monitoring;
+pub(crate) mod environmental;
+pub(crate) mod inland;
+pub(crate) mod met_hydro;
+pub(crate) mod text_telegram;
+pub(crate) mod tidal_window;
+
+use super::*;
+pub use area_notice::{AreaNotice, AreaNoticeCategory, PolarPoint, SubArea};
+pub use aton_monitoring::{AtonMonitoringData, LightStatus, RaconStatus};
+pub use environmental::{EnvironmentalMessage, SensorReport, SensorReportData};
+pub use inland::{InlandEta, InlandRta, InlandStaticVoyageData, LockStatus};
+pub use met_hydro::{MeteorologicalHydrologicalData, PrecipitationType, Tendency};
+pub use text_telegram::TextTelegram;
+pub use tidal_window::{TidalWindow, TidalWindowPoint};
+
+// -------------------------------------------------------------------------------------------------
+
+/// Application specific payload of an AIS binary message, identified by the designated area code
+/// (DAC) and function identifier (FI).
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum ApplicationData {
+    /// DAC 1, FI 0: Text telegram
+    TextTelegram(TextTelegram),
+
+    /// DAC 1, FI 31 and legacy FI 11: Meteorological and hydrological data
+    MeteorologicalHydrological(MeteorologicalHydrologicalData),
+
+    /// DAC 1, FI 22: Area notice
+    AreaNotice(AreaNotice),
+
+    /// DAC 1, FI 14 and FI 32: Tidal window
+    TidalWindow(TidalWindow),
+
+    /// DAC 200, FI 10: Inland ship static and voyage related data
+    InlandStaticVoyage(InlandStaticVoyageData),
+
+    /// DAC 200, FI 21: Inland ETA report
+    InlandEta(InlandEta),
+
+    /// DAC 200, FI 22: Inland RTA response
+    InlandRta(InlandRta),
+
+    /// DAC 235 and 250, FI 10: Aids-to-navigation monitoring data
+    AtonMonitoring(AtonMonitoringData),
+
+    /// DAC 367, FI 33: Environmental message (USCG)
+    Environmental(EnvironmentalMessage),
+
+    /// Result of a decoder registered with `NmeaParser::register_asm_decoder()`
+    Custom(CustomMessage),
+}
+
+/// User supplied decoder of application specific messages. Decoders are registered for a DAC and
+/// FI combination with `NmeaParser::register_asm_decoder()`.
+pub trait AsmDecoder: Send + Sync {
+    /// Decode the application data. `data` contains the bits following the FI field. Decoders
+    /// typically return `ApplicationData::Custom` but any other variant can be returned as well.
+    fn decode(&self, data: &BitVec) -> Result<ApplicationData, ParseError>;
+}
+
+impl<F> AsmDecoder for F
+where
+    F: Fn(&BitVec) -> Result<ApplicationData, ParseError> + Send + Sync,
+{
+    fn decode(&self, data: &BitVec) -> Result<ApplicationData, ParseError> {
+        self(data)
+    }
+}
+
+/// Registered decoders keyed by DAC and FI
+pub(crate) type AsmDecoders = HashMap<(u16, u8), Arc<dyn AsmDecoder>>;
+
+/// Decoder of application specific message data
+type Decoder = fn(&BitVec) -> Result<ApplicationData, ParseError>;
+
+/// Built-in decoders of addressed (type 6) messages keyed by DAC and FI
+const ADDRESSED_DECODERS: &[(u16, u8, Decoder)] = &[
+    (1, 0, text_telegram::handle),      // IMO 289 text telegram
+    (1, 14, tidal_window::handle_fi14), // IMO 236 tidal window
+    (1, 32, tidal_window::handle_fi32), // IMO 289 tidal window
+    (200, 21, inland::handle_fi21),     // Inland ETA report
+    (200, 22, inland::handle_fi22),     // Inland RTA response
+];
+
+/// Built-in decoders of broadcast (type 8) messages keyed by DAC and FI
+const BROADCAST_DECODERS: &[(u16, u8, Decoder)] = &[
+    (1, 11, met_hydro::handle_fi11), // IMO 236 meteorological and hydrological data
+    (1, 22, area_notice::handle),    // IMO 289 area notice
+    (1, 31, met_hydro::handle_fi31), // IMO 289 meteorological and hydrological data
+    (200, 10, inland::handle_fi10),  // Inland ship static and voyage related data
+    (235, 10, aton_monitoring::handle), // UK aids-to-navigation monitoring data
+    (250, 10, aton_monitoring::handle), // ROI aids-to-navigation monitoring data
+    (366, 33, environmental::handle), // USCG environmental message (test DAC)
+    (367, 33, environmental::handle), // USCG environmental message
+];
+
+/// Decode the application data of a binary message. `data` contains the bits following the FI
+/// field. Registered decoders take precedence over the built-in ones. Unknown DAC/FI
+/// combinations return `None`.
+pub(crate) fn decode(
+    dac: u16,
+    fi: u8,
+    addressed: bool,
+    data: &BitVec,
+    registered: &AsmDecoders,
+) -> Result<Option<ApplicationData>, ParseError> {
+    if let Some(decoder) = registered.get(&(dac, fi)) {
+        return Ok(Some(decoder.decode(data)?));
+    }
+    let decoders = if addressed {
+        ADDRESSED_DECODERS
+    } else {
+        BROADCAST_DECODERS
+    };
+    match decoders.iter().find(|(d, f, _)| *d == dac && *f == fi) {
+        Some((_, _, decoder)) => Ok(Some(decoder(data)?)),
+        None => Ok(None),
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Pick a 24-bit latitude in 1/1000 minutes.
+pub(crate) fn pick_latitude(data: &BitVec, index: usize) -> Option<f64> {
+    let lat_raw = pick_i64(data, index, 24);
+    if lat_raw.abs() <= 90 * 60000 {
+        Some(lat_raw as f64 / 60000.0)
+    } else {
+        None
+    }
+}
+
+/// Pick a 25-bit longitude in 1/1000 minutes.
+pub(crate) fn pick_longitude(data: &BitVec, index: usize) -> Option<f64> {
+    let lon_raw = pick_i64(data, index, 25);
+    if lon_raw.abs() <= 180 * 60000 {
+        Some(lon_raw as f64 / 60000.0)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,43 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+// -------------------------------------------------------------------------------------------------
+
+/// DAC 1, FI 0: Text telegram (IMO 289)
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct TextTelegram {
+    /// True if the receiver is requested to acknowledge the message with FI 5.
+    pub acknowledge_required: bool,
+
+    /// Text sequence number (11 bits)
+    pub sequence_number: u16,
+
+    /// Text
+    pub text: String,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// DAC 1, FI 0: Text telegram
+pub(crate) fn handle(data: &BitVec) -> Result<ApplicationData, ParseError> {
+    Ok(ApplicationData::TextTelegram(TextTelegram {
+        acknowledge_required: { pick_u64(data, 0, 1) != 0 },
+        sequence_number: { pick_u64(data, 1, 11) as u16 },
+        text: { pick_string(data, 12, data.len().saturating_sub(12) / 6) },
+    }))
+}
@@ -0,0 +1,204 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+// -------------------------------------------------------------------------------------------------
+
+/// DAC 1, FI 14: Tidal window (IMO 236). The FI 32 message of IMO 289 is decoded into the same
+/// structure.
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct TidalWindow {
+    /// UTC month the windows apply to
+    pub month: Option<u8>,
+
+    /// UTC day the windows apply to
+    pub day: Option<u8>,
+
+    /// Tidal windows of up to three positions
+    pub windows: Vec<TidalWindowPoint>,
+}
+
+/// Tidal window at a single position
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct TidalWindowPoint {
+    /// Latitude of the position
+    pub latitude: Option<f64>,
+
+    /// Longitude of the position
+    pub longitude: Option<f64>,
+
+    /// UTC hour the window opens
+    pub from_hour: Option<u8>,
+
+    /// UTC minute the window opens
+    pub from_minute: Option<u8>,
+
+    /// UTC hour the window closes
+    pub to_hour: Option<u8>,
+
+    /// UTC minute the window closes
+    pub to_minute: Option<u8>,
+
+    /// Direction of the current in degrees
+    pub current_direction: Option<u16>,
+
+    /// Speed of the current in knots
+    pub current_speed_knots: Option<f64>,
+}
+
+impl LatLon for TidalWindowPoint {
+    fn latitude(&self) -> Option<f64> {
+        self.latitude
+    }
+
+    fn longitude(&self) -> Option<f64> {
+        self.longitude
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Parse the fields common to all window layouts starting from the given index.
+fn pick_window_times(data: &BitVec, index: usize, point: TidalWindowPoint) -> TidalWindowPoint {
+    TidalWindowPoint {
+        from_hour: { Some(pick_u64(data, index, 5) as u8).filter(|h| *h < 24) },
+        from_minute: { Some(pick_u64(data, index + 5, 6) as u8).filter(|m| *m < 60) },
+        to_hour: { Some(pick_u64(data, index + 11, 5) as u8).filter(|h| *h < 24) },
+        to_minute: { Some(pick_u64(data, index + 16, 6) as u8).filter(|m| *m < 60) },
+        current_direction: { Some(pick_u64(data, index + 22, 9) as u16).filter(|d| *d < 360) },
+        ..point
+    }
+}
+
+/// DAC 1, FI 14: Tidal window
+pub(crate) fn handle_fi14(data: &BitVec) -> Result<ApplicationData, ParseError> {
+    let count = min(3, data.len().saturating_sub(9) / 93);
+    Ok(ApplicationData::TidalWindow(TidalWindow {
+        month: { Some(pick_u64(data, 0, 4) as u8).filter(|m| (1..=12).contains(m)) },
+        day: { Some(pick_u64(data, 4, 5) as u8).filter(|d| *d > 0) },
+        windows: {
+            (0..count)
+                .map(|i| {
+                    let index = 9 + i * 93;
+                    let point = TidalWindowPoint {
+                        latitude: {
+                            let lat_raw = pick_i64(data, index, 27);
+                            if lat_raw.abs() <= 90 * 600000 {
+                                Some(lat_raw as f64 / 600000.0)
+                            } else {
+                                None
+                            }
+                        },
+                        longitude: {
+                            let lon_raw = pick_i64(data, index + 27, 28);
+                            if lon_raw.abs() <= 180 * 600000 {
+                                Some(lon_raw as f64 / 600000.0)
+                            } else {
+                                None
+                            }
+                        },
+                        current_speed_knots: {
+                            Some(pick_u64(data, index + 86, 7))
+                                .filter(|s| *s < 127)
+                                .map(|s| s as f64 * 0.1)
+                        },
+                        ..Default::default()
+                    };
+                    pick_window_times(data, index + 55, point)
+                })
+                .collect()
+        },
+    }))
+}
+
+/// DAC 1, FI 32: Tidal window
+pub(crate) fn handle_fi32(data: &BitVec) -> Result<ApplicationData, ParseError> {
+    let count = min(3, data.len().saturating_sub(9) / 88);
+    Ok(ApplicationData::TidalWindow(TidalWindow {
+        month: { Some(pick_u64(data, 0, 4) as u8).filter(|m| (1..=12).contains(m)) },
+        day: { Some(pick_u64(data, 4, 5) as u8).filter(|d| *d > 0) },
+        windows: {
+            (0..count)
+                .map(|i| {
+                    let index = 9 + i * 88;
+                    let point = TidalWindowPoint {
+                        longitude: { pick_longitude(data, index) },
+                        latitude: { pick_latitude(data, index + 25) },
+                        current_speed_knots: {
+                            Some(pick_u64(data, index + 80, 8))
+                                .filter(|s| *s < 252)
+                                .map(|s| s as f64 * 0.1)
+                        },
+                        ..Default::default()
+                    };
+                    pick_window_times(data, index + 49, point)
+                })
+                .collect()
+        },
+    }))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_tidal_window_fi14() {
+        match NmeaParser::new()
+            .parse_sentence("!AIVDM,1,1,,B,602R360w=M`004qJ`@:g06iuL37ae5aM224h0nNE1Sk7U`wP,5*0C")
+        {
+            Ok(ps) => match ps {
+                ParsedMessage::BinaryAddressedMessage(bam) => {
+                    assert_eq!(bam.destination_mmsi, 265123456);
+                    match bam.application_data {
+                        Some(ApplicationData::TidalWindow(tw)) => {
+                            assert_eq!(tw.month, Some(5));
+                            assert_eq!(tw.day, Some(21));
+                            assert_eq!(tw.windows.len(), 2);
+                            let w = &tw.windows[0];
+                            assert::close(w.latitude.unwrap_or(0.0), 57.69, 0.00001);
+                            assert::close(w.longitude.unwrap_or(0.0), 11.85, 0.00001);
+                            assert_eq!(w.from_hour, Some(6));
+                            assert_eq!(w.from_minute, Some(15));
+                            assert_eq!(w.to_hour, Some(9));
+                            assert_eq!(w.to_minute, Some(45));
+                            assert_eq!(w.current_direction, Some(45));
+                            assert::close(w.current_speed_knots.unwrap_or(0.0), 2.3, 0.001);
+                            let w = &tw.windows[1];
+                            assert::close(w.latitude.unwrap_or(0.0), 57.7, 0.00001);
+                            assert_eq!(w.from_hour, None);
+                            assert_eq!(w.to_minute, None);
+                            assert_eq!(w.current_direction, None);
+                            assert_eq!(w.current_speed_knots, None);
+                        }
+                        _ => {
+                            panic!("Unexpected application data: {:?}", bam.application_data);
+                        }
+                    }
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+}
@@ -0,0 +1,126 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::abm::pull_transmit_payload;
+use super::*;
+
+// -------------------------------------------------------------------------------------------------
+
+/// BBM - Broadcast binary message given to an AIS transponder for transmission
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct BroadcastBinaryMessageRequest {
+    /// AIS station type.
+    pub station: Station,
+
+    /// Sequential message identifier (0-9)
+    pub sequence_id: Option<u8>,
+
+    /// Channel to be used for the transmission
+    pub channel: Option<AisChannelSelection>,
+
+    /// Message ID of the message to be transmitted (8, 14, 25 or 26)
+    pub message_id: Option<u8>,
+
+    /// Encapsulated binary data without fill bits
+    pub data: BitVec,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// !xxBBM: Broadcast binary message
+pub(crate) fn handle(
+    sentence: &str,
+    station: Station,
+    store: &mut NmeaParser,
+) -> Result<ParsedMessage, ParseError> {
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    let sequence_id = pick_number_field(&split, 3)?;
+    let channel = match pick_number_field(&split, 4)? {
+        Some(c) => Some(AisChannelSelection::new(c)?),
+        None => None,
+    };
+    let data = match pull_transmit_payload(&split, sequence_id, 6, store)? {
+        Some(data) => data,
+        None => return Ok(ParsedMessage::Incomplete),
+    };
+
+    Ok(ParsedMessage::BroadcastBinaryMessageRequest(
+        BroadcastBinaryMessageRequest {
+            station,
+            sequence_id,
+            channel,
+            message_id: pick_number_field(&split, 5)?,
+            data,
+        },
+    ))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_bbm() {
+        let mut p = NmeaParser::new();
+        assert_eq!(
+            p.parse_sentence("!AIBBM,2,1,3,2,8,>9OWS7Q,0*49"),
+            Ok(ParsedMessage::Incomplete)
+        );
+        assert_eq!(p.strings_count(), 1);
+        match p.parse_sentence("!AIBBM,2,2,3,2,8,0P1,2*33") {
+            Ok(ps) => match ps {
+                ParsedMessage::BroadcastBinaryMessageRequest(bbm) => {
+                    assert_eq!(bbm.station, Station::MobileStation);
+                    assert_eq!(bbm.sequence_id, Some(3));
+                    assert_eq!(bbm.channel, Some(AisChannelSelection::ChannelB));
+                    assert_eq!(bbm.message_id, Some(8));
+                    assert_eq!(bbm.data.len(), 58);
+                    assert_eq!(pick_u64(&bbm.data, 0, 6), 14);
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+        assert_eq!(p.strings_count(), 0);
+
+        // Fill bits are taken from the last fragment also when it arrives first
+        assert_eq!(
+            p.parse_sentence("!AIBBM,2,2,3,2,8,0P1,2*33"),
+            Ok(ParsedMessage::Incomplete)
+        );
+        match p.parse_sentence("!AIBBM,2,1,3,2,8,>9OWS7Q,0*49") {
+            Ok(ParsedMessage::BroadcastBinaryMessageRequest(bbm)) => {
+                assert_eq!(bbm.data.len(), 58);
+            }
+            ps => {
+                panic!("Unexpected result: {:?}", ps);
+            }
+        }
+
+        // Maximum sentence count
+        assert_eq!(
+            p.parse_sentence("!AIBBM,255,1,3,2,8,>9OWS7Q,0"),
+            Ok(ParsedMessage::Incomplete)
+        );
+    }
+}
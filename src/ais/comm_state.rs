@@ -0,0 +1,196 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+// -------------------------------------------------------------------------------------------------
+
+/// Communication state of a SOTDMA or ITDMA transmission. The state tells how the station is
+/// synchronized and how it is going to use the time slots.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CommunicationState {
+    /// Self-organized time division multiple access
+    Sotdma(SotdmaState),
+
+    /// Incremental time division multiple access
+    Itdma(ItdmaState),
+}
+
+impl CommunicationState {
+    /// Decode 19-bit SOTDMA communication state.
+    pub(crate) fn sotdma(raw: u32) -> CommunicationState {
+        let timeout = ((raw >> 14) & 0x7) as u8;
+        let sub = (raw & 0x3fff) as u16;
+        CommunicationState::Sotdma(SotdmaState {
+            sync_state: SyncState::new((raw >> 17) as u8),
+            slot_timeout: timeout,
+            sub_message: match timeout {
+                0 => SotdmaSubMessage::SlotOffset(sub),
+                1 => SotdmaSubMessage::UtcTime {
+                    hour: (sub >> 9) as u8,
+                    minute: ((sub >> 2) & 0x7f) as u8,
+                },
+                2 | 4 | 6 => SotdmaSubMessage::SlotNumber(sub),
+                _ => SotdmaSubMessage::ReceivedStations(sub),
+            },
+        })
+    }
+
+    /// Decode 19-bit ITDMA communication state.
+    pub(crate) fn itdma(raw: u32) -> CommunicationState {
+        CommunicationState::Itdma(ItdmaState {
+            sync_state: SyncState::new((raw >> 17) as u8),
+            slot_increment: ((raw >> 4) & 0x1fff) as u16,
+            number_of_slots: ((raw >> 1) & 0x7) as u8,
+            keep_flag: raw & 1 != 0,
+        })
+    }
+
+    /// Decode 19-bit communication state selected by the communication state selector flag
+    /// (false = SOTDMA, true = ITDMA).
+    pub(crate) fn new(itdma: bool, raw: u32) -> CommunicationState {
+        if itdma {
+            CommunicationState::itdma(raw)
+        } else {
+            CommunicationState::sotdma(raw)
+        }
+    }
+
+    /// Return the synchronization state of the station.
+    pub fn sync_state(&self) -> SyncState {
+        match self {
+            CommunicationState::Sotdma(s) => s.sync_state,
+            CommunicationState::Itdma(s) => s.sync_state,
+        }
+    }
+}
+
+/// SOTDMA communication state
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SotdmaState {
+    /// Synchronization state
+    pub sync_state: SyncState,
+
+    /// Number of frames remaining until a new slot is selected (0-7)
+    pub slot_timeout: u8,
+
+    /// Sub message whose meaning depends on the slot timeout
+    pub sub_message: SotdmaSubMessage,
+}
+
+/// SOTDMA sub message
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SotdmaSubMessage {
+    /// Slot offset to the next transmission (slot timeout 0)
+    SlotOffset(u16),
+
+    /// UTC hour and minute (slot timeout 1)
+    UtcTime { hour: u8, minute: u8 },
+
+    /// Slot number used for this transmission (slot timeout 2, 4 and 6)
+    SlotNumber(u16),
+
+    /// Number of other stations received (slot timeout 3, 5 and 7)
+    ReceivedStations(u16),
+}
+
+/// ITDMA communication state
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ItdmaState {
+    /// Synchronization state
+    pub sync_state: SyncState,
+
+    /// Offset to the next slot to be used, or zero if no more transmissions
+    pub slot_increment: u16,
+
+    /// Number of consecutive slots to allocate (0-4 = 1-5 slots, 5-7 = 1-3 slots with the
+    /// offset increased by 8192)
+    pub number_of_slots: u8,
+
+    /// True if the slot remains allocated for one additional frame
+    pub keep_flag: bool,
+}
+
+/// Synchronization state of the transmitting station
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SyncState {
+    /// UTC direct
+    UtcDirect,
+
+    /// UTC indirect
+    UtcIndirect,
+
+    /// Synchronized to a base station
+    BaseStation,
+
+    /// Synchronized to another station with the highest number of received stations
+    OtherStation,
+}
+
+impl SyncState {
+    fn new(val: u8) -> SyncState {
+        match val & 0x3 {
+            0 => SyncState::UtcDirect,
+            1 => SyncState::UtcIndirect,
+            2 => SyncState::BaseStation,
+            _ => SyncState::OtherStation,
+        }
+    }
+}
+
+impl core::fmt::Display for SyncState {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SyncState::UtcDirect => write!(f, "UTC direct"),
+            SyncState::UtcIndirect => write!(f, "UTC indirect"),
+            SyncState::BaseStation => write!(f, "base station"),
+            SyncState::OtherStation => write!(f, "other station"),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_communication_state() {
+        assert_eq!(
+            CommunicationState::sotdma((1 << 17) | (1 << 14) | (10 << 9) | (40 << 2)),
+            CommunicationState::Sotdma(SotdmaState {
+                sync_state: SyncState::UtcIndirect,
+                slot_timeout: 1,
+                sub_message: SotdmaSubMessage::UtcTime {
+                    hour: 10,
+                    minute: 40
+                },
+            })
+        );
+        assert_eq!(
+            CommunicationState::new(true, 393222),
+            CommunicationState::Itdma(ItdmaState {
+                sync_state: SyncState::OtherStation,
+                slot_increment: 0,
+                number_of_slots: 3,
+                keep_flag: false,
+            })
+        );
+        assert_eq!(
+            CommunicationState::sotdma((2 << 17) | (7 << 14) | 12).sync_state(),
+            SyncState::BaseStation
+        );
+    }
+}
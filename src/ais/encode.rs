@@ -0,0 +1,491 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+//! AIS sentence encoder generating `!AIVDM`/`!AIVDO` sentences from parsed data structures
+
+use super::*;
+
+/// Maximum number of payload characters placed in a single sentence
+const MAX_PAYLOAD_CHARS: usize = 60;
+
+/// Encoder turning `VesselDynamicData` and `VesselStaticData` back into AIS sentences. The
+/// encoder keeps track of the sequential message ID used for multi-sentence messages.
+#[derive(Clone, Debug)]
+pub struct AisEncoder {
+    /// Radio channel code written to the sentences
+    channel: char,
+
+    /// Sequential message ID of the next multi-sentence message (0-9)
+    message_id: u8,
+}
+
+impl Default for AisEncoder {
+    fn default() -> AisEncoder {
+        AisEncoder::new()
+    }
+}
+
+impl AisEncoder {
+    /// Construct an encoder writing to radio channel A.
+    pub fn new() -> AisEncoder {
+        AisEncoder {
+            channel: 'A',
+            message_id: 0,
+        }
+    }
+
+    /// Set the radio channel code (usually 'A' or 'B') written to the sentences.
+    pub fn set_channel(&mut self, channel: char) {
+        self.channel = channel;
+    }
+
+    /// Encode vessel dynamic data as type 1 (class A) or type 18 (class B) position report.
+    /// Class A reports with an ITDMA communication state are encoded as type 3.
+    pub fn encode_vessel_dynamic_data(&mut self, vdd: &VesselDynamicData) -> Vec<String> {
+        let bv = match vdd.ais_type {
+            AisClass::ClassB => encode_type18(vdd),
+            _ => encode_type1(vdd),
+        };
+        self.encode_payload(&bv, vdd.own_vessel, station_talker(vdd.station))
+    }
+
+    /// Encode vessel static data as type 5 (class A) or type 24 parts A and B (class B) static
+    /// data report. Type 24 parts are returned as two separate sentences.
+    pub fn encode_vessel_static_data(&mut self, vsd: &VesselStaticData) -> Vec<String> {
+        match vsd.ais_type {
+            AisClass::ClassB => {
+                let mut res = self.encode_payload(&encode_type24(vsd, false), vsd.own_vessel, "AI");
+                res.append(&mut self.encode_payload(
+                    &encode_type24(vsd, true),
+                    vsd.own_vessel,
+                    "AI",
+                ));
+                res
+            }
+            _ => self.encode_payload(&encode_type5(vsd), vsd.own_vessel, "AI"),
+        }
+    }
+
+    /// Armor the given message bits and split them into as many sentences as needed.
+    fn encode_payload(&mut self, bv: &BitVec, own_vessel: bool, talker: &str) -> Vec<String> {
+        let (payload, fill_bits) = armor_payload(bv);
+        let sentence_type = if own_vessel { "VDO" } else { "VDM" };
+        let fragment_count = payload.len().div_ceil(MAX_PAYLOAD_CHARS);
+        let fragment_count = fragment_count.max(1);
+
+        let message_id = if fragment_count > 1 {
+            let id = self.message_id;
+            self.message_id = (self.message_id + 1) % 10;
+            id.to_string()
+        } else {
+            "".to_string()
+        };
+
+        let mut res = Vec::with_capacity(fragment_count);
+        for i in 0..fragment_count {
+            let start = i * MAX_PAYLOAD_CHARS;
+            let end = min(start + MAX_PAYLOAD_CHARS, payload.len());
+            let fill = if i + 1 == fragment_count {
+                fill_bits
+            } else {
+                0
+            };
+            let body = format!(
+                "{}{},{},{},{},{},{},{}",
+                talker,
+                sentence_type,
+                fragment_count,
+                i + 1,
+                message_id,
+                self.channel,
+                &payload[start..end],
+                fill
+            );
+            let checksum = body.bytes().fold(0u8, |acc, b| acc ^ b);
+            res.push(format!("!{}*{:02X}", body, checksum));
+        }
+        res
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Talker ID matching the given AIS station.
+fn station_talker(station: Station) -> &'static str {
+    match station {
+        Station::BaseStation => "AB",
+        Station::DependentAisBaseStation => "AD",
+        Station::AidToNavigationStation => "AN",
+        Station::AisReceivingStation => "AR",
+        Station::LimitedBaseStation => "AS",
+        Station::AisTransmittingStation => "AT",
+        Station::RepeaterStation => "AX",
+        Station::MobileStation | Station::Other => "AI",
+    }
+}
+
+/// Convert message bits to 6-bit armored payload characters. Returns the payload and the number
+/// of fill bits added to the last character.
+fn armor_payload(bv: &BitVec) -> (String, u8) {
+    let fill_bits = (6 - bv.len() % 6) % 6;
+    let mut payload = String::with_capacity((bv.len() + fill_bits) / 6);
+    for i in (0..bv.len()).step_by(6) {
+        let v = pick_u64(bv, i, 6) as u8;
+        payload.push(if v < 40 { v + 48 } else { v + 56 } as char);
+    }
+    (payload, fill_bits as u8)
+}
+
+/// Append an unsigned field of `len` bits.
+fn push_u64(bv: &mut BitVec, value: u64, len: usize) {
+    for i in (0..len).rev() {
+        bv.push((value >> i) & 1 != 0);
+    }
+}
+
+/// Append a two's complement signed field of `len` bits.
+fn push_i64(bv: &mut BitVec, value: i64, len: usize) {
+    push_u64(bv, value as u64, len);
+}
+
+/// Append a 6-bit text field of `char_count` characters padded with '@'. Characters outside of
+/// the 6-bit character set are replaced with '?'.
+fn push_string(bv: &mut BitVec, s: &str, char_count: usize) {
+    let mut chars = s.chars();
+    for _ in 0..char_count {
+        let v = match chars.next().map(|c| c.to_ascii_uppercase()) {
+            Some(c @ '@'..='_') => c as u64 - 64,
+            Some(c @ ' '..='?') => c as u64,
+            Some(_) => '?' as u64,
+            None => 0,
+        };
+        push_u64(bv, v, AIS_CHAR_BITS);
+    }
+}
+
+/// Append a flag field.
+fn push_bool(bv: &mut BitVec, value: bool) {
+    bv.push(value);
+}
+
+/// Scale an optional value and round it to an integer, or use `na` if the value is unavailable.
+fn scaled(value: Option<f64>, scale: f64, na: i64) -> i64 {
+    match value {
+        Some(v) => (v * scale).round() as i64,
+        None => na,
+    }
+}
+
+/// Encode rate of turn to its 8-bit raw value, the inverse of the decoding in type 1-3 handler.
+fn encode_rot(rot: Option<f64>, rot_direction: Option<RotDirection>) -> i64 {
+    match rot {
+        Some(rot) => {
+            let target = rot.abs();
+            let mut best = 0;
+            let mut best_err = f64::MAX;
+            for raw in 0..=126 {
+                let err = (((raw as f64 * 708.0 / 126.0) / 4.733).powi(2) - target).abs();
+                if err < best_err {
+                    best = raw;
+                    best_err = err;
+                }
+            }
+            if rot < 0.0 {
+                -best
+            } else {
+                best
+            }
+        }
+        None => match rot_direction {
+            Some(RotDirection::Port) => -127,
+            Some(RotDirection::Center) => 0,
+            Some(RotDirection::Starboard) => 127,
+            None => -128,
+        },
+    }
+}
+
+/// Append the position fields shared by types 1-3 and 18.
+fn push_position(bv: &mut BitVec, vdd: &VesselDynamicData) {
+    push_bool(bv, vdd.high_position_accuracy);
+    push_i64(bv, scaled(vdd.longitude, 600000.0, 0x6791AC0), 28);
+    push_i64(bv, scaled(vdd.latitude, 600000.0, 0x3412140), 27);
+    push_u64(bv, scaled(vdd.cog, 10.0, 0xE10) as u64, 12);
+    push_u64(bv, scaled(vdd.heading_true, 1.0, 511) as u64, 9);
+    push_u64(bv, vdd.timestamp_seconds as u64, 6);
+}
+
+/// Types 1 and 3: Position Report Class A
+fn encode_type1(vdd: &VesselDynamicData) -> BitVec {
+    let message_type = match vdd.communication_state {
+        Some(CommunicationState::Itdma(_)) => 3,
+        _ => 1,
+    };
+    let mut bv = BitVec::with_capacity(168);
+    push_u64(&mut bv, message_type, 6);
+    push_u64(&mut bv, 0, 2);
+    push_u64(&mut bv, vdd.mmsi as u64, 30);
+    push_u64(&mut bv, vdd.nav_status.to_value() as u64, 4);
+    push_i64(&mut bv, encode_rot(vdd.rot, vdd.rot_direction), 8);
+    push_u64(&mut bv, scaled(vdd.sog_knots, 10.0, 1023) as u64, 10);
+    push_position(&mut bv, vdd);
+    push_u64(
+        &mut bv,
+        match vdd.special_manoeuvre {
+            None => 0,
+            Some(false) => 1,
+            Some(true) => 2,
+        },
+        2,
+    );
+    push_u64(&mut bv, vdd.spare.unwrap_or(0) as u64, 3);
+    push_bool(&mut bv, vdd.raim_flag);
+    push_u64(&mut bv, vdd.radio_status.unwrap_or(0) as u64, 19);
+    bv
+}
+
+/// Type 18: Standard Class B CS Position Report
+fn encode_type18(vdd: &VesselDynamicData) -> BitVec {
+    let mut bv = BitVec::with_capacity(168);
+    push_u64(&mut bv, 18, 6);
+    push_u64(&mut bv, 0, 2);
+    push_u64(&mut bv, vdd.mmsi as u64, 30);
+    push_u64(&mut bv, vdd.regional.unwrap_or(0) as u64, 8);
+    push_u64(&mut bv, scaled(vdd.sog_knots, 10.0, 1023) as u64, 10);
+    push_position(&mut bv, vdd);
+    push_u64(&mut bv, vdd.regional2.unwrap_or(0) as u64, 2);
+    push_bool(&mut bv, vdd.class_b_unit_flag.unwrap_or(false));
+    push_bool(&mut bv, vdd.class_b_display.unwrap_or(false));
+    push_bool(&mut bv, vdd.class_b_dsc.unwrap_or(false));
+    push_bool(&mut bv, vdd.class_b_band_flag.unwrap_or(false));
+    push_bool(&mut bv, vdd.class_b_msg22_flag.unwrap_or(false));
+    push_bool(&mut bv, vdd.class_b_mode_flag.unwrap_or(false));
+    push_bool(&mut bv, vdd.raim_flag);
+    push_bool(&mut bv, vdd.class_b_css_flag.unwrap_or(false));
+    push_u64(&mut bv, vdd.radio_status.unwrap_or(0) as u64, 19);
+    bv
+}
+
+/// Append the dimension fields shared by types 5 and 24.
+fn push_dimensions(bv: &mut BitVec, vsd: &VesselStaticData) {
+    push_u64(bv, vsd.dimension_to_bow.unwrap_or(0) as u64, 9);
+    push_u64(bv, vsd.dimension_to_stern.unwrap_or(0) as u64, 9);
+    push_u64(bv, vsd.dimension_to_port.unwrap_or(0) as u64, 6);
+    push_u64(bv, vsd.dimension_to_starboard.unwrap_or(0) as u64, 6);
+}
+
+/// Type 5: Ship static and voyage related data
+fn encode_type5(vsd: &VesselStaticData) -> BitVec {
+    let mut bv = BitVec::with_capacity(424);
+    push_u64(&mut bv, 5, 6);
+    push_u64(&mut bv, 0, 2);
+    push_u64(&mut bv, vsd.mmsi as u64, 30);
+    push_u64(&mut bv, vsd.ais_version_indicator as u64, 2);
+    push_u64(&mut bv, vsd.imo_number.unwrap_or(0) as u64, 30);
+    push_string(&mut bv, vsd.call_sign.as_deref().unwrap_or(""), 7);
+    push_string(&mut bv, vsd.name.as_deref().unwrap_or(""), 20);
+    push_u64(&mut bv, vsd.ship_and_cargo_type().0 as u64, 8);
+    push_dimensions(&mut bv, vsd);
+    push_u64(
+        &mut bv,
+        vsd.position_fix_type.map(|t| t.to_value()).unwrap_or(0) as u64,
+        4,
+    );
+    match vsd.eta {
+        Some(eta) => {
+            push_u64(&mut bv, eta.month() as u64, 4);
+            push_u64(&mut bv, eta.day() as u64, 5);
+            push_u64(&mut bv, eta.hour() as u64, 5);
+            push_u64(&mut bv, eta.minute() as u64, 6);
+        }
+        None => {
+            push_u64(&mut bv, 0, 4);
+            push_u64(&mut bv, 0, 5);
+            push_u64(&mut bv, 24, 5);
+            push_u64(&mut bv, 60, 6);
+        }
+    }
+    push_u64(&mut bv, vsd.draught10.unwrap_or(0) as u64, 8);
+    push_string(&mut bv, vsd.destination.as_deref().unwrap_or(""), 20);
+    push_u64(&mut bv, 0, 2); // DTE and spare
+    bv
+}
+
+/// Type 24: Static data report, part A or B
+fn encode_type24(vsd: &VesselStaticData, part_b: bool) -> BitVec {
+    let mut bv = BitVec::with_capacity(168);
+    push_u64(&mut bv, 24, 6);
+    push_u64(&mut bv, 0, 2);
+    push_u64(&mut bv, vsd.mmsi as u64, 30);
+    if !part_b {
+        push_u64(&mut bv, 0, 2);
+        push_string(&mut bv, vsd.name.as_deref().unwrap_or(""), 20);
+    } else {
+        push_u64(&mut bv, 1, 2);
+        push_u64(&mut bv, vsd.ship_and_cargo_type().0 as u64, 8);
+        push_string(&mut bv, vsd.equipment_vendor_id.as_deref().unwrap_or(""), 3);
+        push_u64(&mut bv, vsd.equipment_model.unwrap_or(0) as u64, 4);
+        push_u64(&mut bv, vsd.equipment_serial_number.unwrap_or(0) as u64, 20);
+        push_string(&mut bv, vsd.call_sign.as_deref().unwrap_or(""), 7);
+        match vsd.mothership_mmsi {
+            Some(mmsi) => push_u64(&mut bv, mmsi as u64, 30),
+            None => push_dimensions(&mut bv, vsd),
+        }
+        push_u64(&mut bv, 0, 6);
+    }
+    bv
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_encode_type1() {
+        let s = "!AIVDM,1,1,,A,15RTgt0PAso;90TKcjM8h6g208CQ,0*4A";
+        let mut p = NmeaParser::new();
+        match p.parse_sentence(s) {
+            Ok(ps) => match ps {
+                ParsedMessage::VesselDynamicData(vdd) => {
+                    let sentences = AisEncoder::new().encode_vessel_dynamic_data(&vdd);
+                    assert_eq!(sentences, vec![s.to_string()]);
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_encode_type18() {
+        let s = "!AIVDM,1,1,,A,B3KMWh:D=hLOfh8V>n1=;wbEkP06,0*2C";
+        let mut p = NmeaParser::new();
+        match p.parse_sentence(s) {
+            Ok(ps) => match ps {
+                ParsedMessage::VesselDynamicData(vdd) => {
+                    let sentences = AisEncoder::new().encode_vessel_dynamic_data(&vdd);
+                    assert_eq!(sentences, vec![s.to_string()]);
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_encode_type5() {
+        let mut p = NmeaParser::new();
+        p.parse_sentence(
+            "!AIVDM,2,1,1,A,55?MbV02;H;s<HtKR20EHE:0@T4@Dn2222222216L961O5Gf0NSQEp6ClRp8,0*1C",
+        )
+        .ok();
+        let vsd = match p.parse_sentence("!AIVDM,2,2,1,A,88888888880,2*25") {
+            Ok(ParsedMessage::VesselStaticData(vsd)) => vsd,
+            other => {
+                panic!("Unexpected result: {:?}", other);
+            }
+        };
+
+        let sentences = AisEncoder::new().encode_vessel_static_data(&vsd);
+        assert_eq!(sentences.len(), 2);
+        assert!(sentences[0].starts_with("!AIVDM,2,1,0,A,55?MbV02;H;s<HtK"));
+        assert!(sentences[1].ends_with(",2*24"));
+
+        // Parse the generated sentences back
+        let mut p = NmeaParser::new();
+        assert_eq!(
+            p.parse_sentence(&sentences[0]).ok(),
+            Some(ParsedMessage::Incomplete)
+        );
+        match p.parse_sentence(&sentences[1]) {
+            Ok(ps) => match ps {
+                ParsedMessage::VesselStaticData(vsd2) => {
+                    assert_eq!(vsd2, vsd);
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_encode_type24() {
+        let mut p = NmeaParser::new();
+        p.parse_sentence("!AIVDM,1,1,,A,H42O55i18tMET00000000000000,2*6D")
+            .ok();
+        let vsd = match p.parse_sentence("!AIVDM,1,1,,A,H42O55lti4hhhilD3nink000?050,0*40") {
+            Ok(ParsedMessage::VesselStaticData(vsd)) => vsd,
+            other => {
+                panic!("Unexpected result: {:?}", other);
+            }
+        };
+
+        let sentences = AisEncoder::new().encode_vessel_static_data(&vsd);
+        assert_eq!(sentences.len(), 2);
+
+        let mut p = NmeaParser::new();
+        assert_eq!(
+            p.parse_sentence(&sentences[0]).ok(),
+            Some(ParsedMessage::Incomplete)
+        );
+        match p.parse_sentence(&sentences[1]) {
+            Ok(ps) => match ps {
+                ParsedMessage::VesselStaticData(vsd2) => {
+                    assert_eq!(vsd2, vsd);
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_encode_own_vessel_and_string() {
+        let vdd = VesselDynamicData {
+            own_vessel: true,
+            ais_type: AisClass::ClassA,
+            mmsi: 230012345,
+            ..Default::default()
+        };
+        let mut e = AisEncoder::new();
+        e.set_channel('B');
+        let sentences = e.encode_vessel_dynamic_data(&vdd);
+        assert_eq!(sentences.len(), 1);
+        assert!(sentences[0].starts_with("!AIVDO,1,1,,B,"));
+
+        let mut bv = BitVec::new();
+        push_string(&mut bv, "ab~", 4);
+        assert_eq!(pick_string(&bv, 0, 4), "AB?");
+    }
+}
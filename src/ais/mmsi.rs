@@ -0,0 +1,292 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+// -------------------------------------------------------------------------------------------------
+
+/// Access to the MMSI of a parsed AIS message and the information derived from it.
+pub trait HasMmsi {
+    /// Return the MMSI (Maritime Mobile Service Identity) of the station.
+    fn mmsi(&self) -> u32;
+
+    /// Return the class of the station derived from the MMSI format.
+    fn mmsi_class(&self) -> MmsiClass {
+        MmsiClass::new(self.mmsi())
+    }
+
+    /// Return the Maritime Identification Digits (MID) embedded in the MMSI, if any.
+    fn mid(&self) -> Option<u16> {
+        mid::mmsi_to_mid(self.mmsi())
+    }
+
+    /// Return the ISO 3166-1 alpha-2 country code of the flag state derived from the MID.
+    fn flag_state(&self) -> Option<&'static str> {
+        self.mid().and_then(mid::mid_to_country)
+    }
+}
+
+/// Class of a station derived from the MMSI format as defined in ITU-R M.585
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MmsiClass {
+    /// Ship station (MIDXXXXXX)
+    Ship,
+
+    /// Group of ship stations (0MIDXXXXX)
+    GroupOfShips,
+
+    /// Coast station (00MIDXXXX)
+    CoastStation,
+
+    /// SAR aircraft (111MIDXXX)
+    SarAircraft,
+
+    /// Handheld VHF transceiver with DSC and GNSS (8MIDXXXXX)
+    HandheldVhf,
+
+    /// Craft associated with a parent ship (98MIDXXXX)
+    AuxiliaryCraft,
+
+    /// Aid to navigation (99MIDXXXX)
+    AidToNavigation,
+
+    /// AIS search and rescue transmitter (970XXYYYY)
+    Sart,
+
+    /// Man overboard device (972XXYYYY)
+    ManOverboard,
+
+    /// EPIRB with AIS locating signal (974XXYYYY)
+    Epirb,
+
+    /// Autonomous maritime radio device (979XXYYYY)
+    AutonomousDevice,
+
+    /// MMSI not following any of the known formats
+    Unknown,
+}
+
+impl MmsiClass {
+    /// Classify the given MMSI.
+    pub fn new(mmsi: u32) -> MmsiClass {
+        match mmsi {
+            1_000_000..=9_999_999 => MmsiClass::CoastStation,
+            10_000_000..=99_999_999 => MmsiClass::GroupOfShips,
+            111_000_000..=111_999_999 => MmsiClass::SarAircraft,
+            200_000_000..=799_999_999 => MmsiClass::Ship,
+            800_000_000..=899_999_999 => MmsiClass::HandheldVhf,
+            970_000_000..=970_999_999 => MmsiClass::Sart,
+            972_000_000..=972_999_999 => MmsiClass::ManOverboard,
+            974_000_000..=974_999_999 => MmsiClass::Epirb,
+            979_000_000..=979_999_999 => MmsiClass::AutonomousDevice,
+            980_000_000..=989_999_999 => MmsiClass::AuxiliaryCraft,
+            990_000_000..=999_999_999 => MmsiClass::AidToNavigation,
+            _ => MmsiClass::Unknown,
+        }
+    }
+
+    /// Return true for distress and locating devices (SART, MOB and EPIRB) whose transmission
+    /// indicates an emergency.
+    pub fn is_emergency_device(&self) -> bool {
+        matches!(
+            self,
+            MmsiClass::Sart | MmsiClass::ManOverboard | MmsiClass::Epirb
+        )
+    }
+}
+
+impl core::fmt::Display for MmsiClass {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            MmsiClass::Ship => write!(f, "ship"),
+            MmsiClass::GroupOfShips => write!(f, "group of ships"),
+            MmsiClass::CoastStation => write!(f, "coast station"),
+            MmsiClass::SarAircraft => write!(f, "SAR aircraft"),
+            MmsiClass::HandheldVhf => write!(f, "handheld VHF"),
+            MmsiClass::AuxiliaryCraft => write!(f, "auxiliary craft"),
+            MmsiClass::AidToNavigation => write!(f, "aid to navigation"),
+            MmsiClass::Sart => write!(f, "SART"),
+            MmsiClass::ManOverboard => write!(f, "man overboard device"),
+            MmsiClass::Epirb => write!(f, "EPIRB"),
+            MmsiClass::AutonomousDevice => write!(f, "autonomous maritime radio device"),
+            MmsiClass::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+impl HasMmsi for VesselDynamicData {
+    fn mmsi(&self) -> u32 {
+        self.mmsi
+    }
+}
+
+impl HasMmsi for VesselStaticData {
+    fn mmsi(&self) -> u32 {
+        self.mmsi
+    }
+}
+
+impl HasMmsi for BaseStationReport {
+    fn mmsi(&self) -> u32 {
+        self.mmsi
+    }
+}
+
+impl HasMmsi for StandardSarAircraftPositionReport {
+    fn mmsi(&self) -> u32 {
+        self.mmsi
+    }
+}
+
+impl HasMmsi for AidToNavigationReport {
+    fn mmsi(&self) -> u32 {
+        self.mmsi
+    }
+}
+
+impl HasMmsi for UnsupportedAisMessage {
+    fn mmsi(&self) -> u32 {
+        self.mmsi
+    }
+}
+
+impl HasMmsi for BinaryAddressedMessage {
+    fn mmsi(&self) -> u32 {
+        self.mmsi
+    }
+}
+
+impl HasMmsi for BinaryBroadcastMessage {
+    fn mmsi(&self) -> u32 {
+        self.mmsi
+    }
+}
+
+impl HasMmsi for UtcDateInquiry {
+    fn mmsi(&self) -> u32 {
+        self.source_mmsi
+    }
+}
+
+impl HasMmsi for AddressedSafetyRelatedMessage {
+    fn mmsi(&self) -> u32 {
+        self.source_mmsi
+    }
+}
+
+impl HasMmsi for SafetyRelatedAcknowledgement {
+    fn mmsi(&self) -> u32 {
+        self.mmsi
+    }
+}
+
+impl HasMmsi for SafetyRelatedBroadcastMessage {
+    fn mmsi(&self) -> u32 {
+        self.mmsi
+    }
+}
+
+impl HasMmsi for Interrogation {
+    fn mmsi(&self) -> u32 {
+        self.mmsi
+    }
+}
+
+impl HasMmsi for AssignmentModeCommand {
+    fn mmsi(&self) -> u32 {
+        self.mmsi
+    }
+}
+
+impl HasMmsi for DgnssBroadcastBinaryMessage {
+    fn mmsi(&self) -> u32 {
+        self.mmsi
+    }
+}
+
+impl HasMmsi for DataLinkManagementMessage {
+    fn mmsi(&self) -> u32 {
+        self.mmsi
+    }
+}
+
+impl HasMmsi for ChannelManagement {
+    fn mmsi(&self) -> u32 {
+        self.mmsi
+    }
+}
+
+impl HasMmsi for GroupAssignmentCommand {
+    fn mmsi(&self) -> u32 {
+        self.mmsi
+    }
+}
+
+impl HasMmsi for SingleSlotBinaryMessage {
+    fn mmsi(&self) -> u32 {
+        self.mmsi
+    }
+}
+
+impl HasMmsi for MultipleSlotBinaryMessage {
+    fn mmsi(&self) -> u32 {
+        self.mmsi
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_mmsi_class() {
+        assert_eq!(MmsiClass::new(230123456), MmsiClass::Ship);
+        assert_eq!(MmsiClass::new(23012345), MmsiClass::GroupOfShips);
+        assert_eq!(MmsiClass::new(2301234), MmsiClass::CoastStation);
+        assert_eq!(MmsiClass::new(111230123), MmsiClass::SarAircraft);
+        assert_eq!(MmsiClass::new(823012345), MmsiClass::HandheldVhf);
+        assert_eq!(MmsiClass::new(982301234), MmsiClass::AuxiliaryCraft);
+        assert_eq!(MmsiClass::new(992301234), MmsiClass::AidToNavigation);
+        assert_eq!(MmsiClass::new(970011234), MmsiClass::Sart);
+        assert_eq!(MmsiClass::new(972011234), MmsiClass::ManOverboard);
+        assert_eq!(MmsiClass::new(974011234), MmsiClass::Epirb);
+        assert_eq!(MmsiClass::new(0), MmsiClass::Unknown);
+        assert!(MmsiClass::new(970011234).is_emergency_device());
+        assert!(!MmsiClass::new(230123456).is_emergency_device());
+
+        // SART activation reported as a position report
+        match NmeaParser::new().parse_sentence("!AIVDM,1,1,,A,1>M;`h>P001ivs0RHsH>4?vt0000,0*42") {
+            Ok(ps) => match ps {
+                ParsedMessage::VesselDynamicData(vdd) => {
+                    assert_eq!(vdd.mmsi, 970123456);
+                    assert_eq!(vdd.mmsi_class(), MmsiClass::Sart);
+                    assert!(vdd.mmsi_class().is_emergency_device());
+                    assert_eq!(vdd.flag_state(), None);
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+}
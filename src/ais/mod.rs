@@ -20,6 +20,8 @@ pub(crate) mod vdm_t1t2t3;
 pub(crate) mod vdm_t4;
 pub(crate) mod vdm_t5;
 pub(crate) mod vdm_t6;
+pub(crate) mod vdm_t7;
+pub(crate) mod vdm_t8;
 pub(crate) mod vdm_t9;
 pub(crate) mod vdm_t10;
 pub(crate) mod vdm_t11;
@@ -39,10 +41,23 @@ pub(crate) mod vdm_t24;
 pub(crate) mod vdm_t25;
 pub(crate) mod vdm_t26;
 pub(crate) mod vdm_t27;
+pub(crate) mod abm;
+pub(crate) mod bbm;
+pub(crate) mod aca;
+pub(crate) mod acs;
+pub mod asm;
+pub(crate) mod encode;
+pub(crate) mod comm_state;
+pub(crate) mod mid;
+pub(crate) mod mmsi;
+pub(crate) mod own_ship;
+pub(crate) mod vessel_index;
 
 use super::*;
 pub use vdm_t4::BaseStationReport;
 pub use vdm_t6::BinaryAddressedMessage;
+pub use vdm_t7::BinaryAcknowledge;
+pub use vdm_t8::BinaryBroadcastMessage;
 pub use vdm_t9::StandardSarAircraftPositionReport;
 pub use vdm_t10::UtcDateInquiry;
 pub use vdm_t12::AddressedSafetyRelatedMessage;
@@ -53,10 +68,21 @@ pub use vdm_t16::AssignmentModeCommand;
 pub use vdm_t17::DgnssBroadcastBinaryMessage;
 pub use vdm_t20::{DataLinkManagementMessage};
 pub use vdm_t21::{AidToNavigationReport, NavAidType};
-pub use vdm_t22::{ChannelManagement};
+pub use vdm_t22::{ChannelManagement, TxRxMode};
 pub use vdm_t23::{GroupAssignmentCommand};
 pub use vdm_t25::{SingleSlotBinaryMessage};
 pub use vdm_t26::{MultipleSlotBinaryMessage};
+pub use abm::{AddressedBinaryMessageRequest, AisChannelSelection};
+pub use bbm::BroadcastBinaryMessageRequest;
+pub use aca::{ChannelAssignmentSource, RegionalChannelAssignment};
+pub use acs::ChannelManagementSource;
+pub use asm::{ApplicationData, AsmDecoder};
+pub use encode::AisEncoder;
+pub use mid::mid_to_country;
+pub use mmsi::{HasMmsi, MmsiClass};
+pub use own_ship::OwnShip;
+pub use vessel_index::{VesselIndex, VesselState};
+pub use comm_state::{CommunicationState, ItdmaState, SotdmaState, SotdmaSubMessage, SyncState};
 
 // -------------------------------------------------------------------------------------------------
 
@@ -121,6 +147,28 @@ impl core::str::FromStr for Station {
 
 // -------------------------------------------------------------------------------------------------
 
+/// AIS message of a type not supported by the crate. The raw bits are kept so that the message
+/// can be logged or forwarded.
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct UnsupportedAisMessage {
+    /// True if the data is about own vessel, false if about other.
+    pub own_vessel: bool,
+
+    /// AIS station type.
+    pub station: Station,
+
+    /// Message type (6 bits)
+    pub message_type: u8,
+
+    /// User ID (30 bits), if the message follows the common header layout
+    pub mmsi: u32,
+
+    /// Message bits without fill bits
+    pub bits: BitVec,
+}
+
+// -------------------------------------------------------------------------------------------------
+
 /// Types 1, 2, 3 and 18: Position Report Class A, and Long Range AIS Broadcast message
 #[derive(Default, Clone, Debug, PartialEq)]
 pub struct VesselDynamicData {
@@ -222,6 +270,27 @@ pub struct VesselDynamicData {
     /// Diagnostic information for the radio system.
     /// <https://www.itu.int/dms_pubrec/itu-r/rec/m/R-REC-M.1371-1-200108-S!!PDF-E.pdf>
     pub radio_status: Option<u32>,
+
+    /// Decoded communication state, `None` if the message doesn't carry one.
+    pub communication_state: Option<CommunicationState>,
+
+    /// Overall dimension / reference for position A (9 bits, type 19)
+    pub dimension_to_bow: Option<u16>,
+    /// Overall dimension / reference for position B (9 bits, type 19)
+    pub dimension_to_stern: Option<u16>,
+    /// Overall dimension / reference for position C (6 bits, type 19)
+    pub dimension_to_port: Option<u16>,
+    /// Overall dimension / reference for position D (6 bits, type 19)
+    pub dimension_to_starboard: Option<u16>,
+
+    /// Reserved for regional applications, uninterpreted (8 bits, types 18 and 19)
+    pub regional: Option<u8>,
+
+    /// Second regional reserved field, uninterpreted (2 bits in type 18, 4 bits in type 19)
+    pub regional2: Option<u8>,
+
+    /// Spare bits, uninterpreted (3 bits in types 1-3, 4 bits in type 19)
+    pub spare: Option<u8>,
 }
 
 /// AIS class which is either Class A or Class B
@@ -253,6 +322,29 @@ impl core::fmt::Display for AisClass {
     }
 }
 
+impl VesselDynamicData {
+    /// Return the overall length of the vessel in metres, if known.
+    pub fn length(&self) -> Option<u16> {
+        overall_dimension(self.dimension_to_bow, self.dimension_to_stern)
+    }
+
+    /// Return the overall breadth of the vessel in metres, if known.
+    pub fn breadth(&self) -> Option<u16> {
+        overall_dimension(self.dimension_to_port, self.dimension_to_starboard)
+    }
+
+    /// Return the position of the reference point (e.g. GNSS antenna) relative to the centre of
+    /// the vessel as metres forward and metres to starboard.
+    pub fn reference_point(&self) -> Option<(f64, f64)> {
+        reference_point(
+            self.dimension_to_bow,
+            self.dimension_to_stern,
+            self.dimension_to_port,
+            self.dimension_to_starboard,
+        )
+    }
+}
+
 impl LatLon for VesselDynamicData {
     fn latitude(&self) -> Option<f64> {
         self.latitude
@@ -277,8 +369,8 @@ pub enum NavigationStatus {
     UnderWaySailing = 8,            // 8
     Reserved9 = 9,                  // 9, may be renamed in the future
     Reserved10 = 10,                // 10, may be renamed in the future
-    Reserved11 = 11,                // 11, may be renamed in the future
-    Reserved12 = 12,                // 12, may be renamed in the future
+    PowerDrivenTowingAstern = 11,   // 11, regional use
+    PowerDrivenPushingAhead = 12,   // 12, pushing ahead or towing alongside, regional use
     Reserved13 = 13,                // 13, may be renamed in the future
     AisSartIsActive = 14,           // 14
     NotDefined = 15,                // 15
@@ -297,8 +389,8 @@ impl NavigationStatus {
             8 => NavigationStatus::UnderWaySailing,
             9 => NavigationStatus::Reserved9,
             10 => NavigationStatus::Reserved10,
-            11 => NavigationStatus::Reserved11,
-            12 => NavigationStatus::Reserved12,
+            11 => NavigationStatus::PowerDrivenTowingAstern,
+            12 => NavigationStatus::PowerDrivenPushingAhead,
             13 => NavigationStatus::Reserved13,
             14 => NavigationStatus::AisSartIsActive,
             15 => NavigationStatus::NotDefined,
@@ -309,6 +401,48 @@ impl NavigationStatus {
     pub fn to_value(&self) -> u8 {
         *self as u8
     }
+
+    /// Return true if the vessel is under way by engine or sail, including towing and pushing.
+    pub fn is_underway(&self) -> bool {
+        matches!(
+            self,
+            NavigationStatus::UnderWayUsingEngine
+                | NavigationStatus::UnderWaySailing
+                | NavigationStatus::PowerDrivenTowingAstern
+                | NavigationStatus::PowerDrivenPushingAhead
+        )
+    }
+
+    /// Return true if the vessel is moored.
+    pub fn is_moored(&self) -> bool {
+        *self == NavigationStatus::Moored
+    }
+
+    /// Return true if the vessel is at anchor.
+    pub fn is_at_anchor(&self) -> bool {
+        *self == NavigationStatus::AtAnchor
+    }
+
+    /// Return true if the vessel is not expected to move, i.e. it is at anchor, moored or
+    /// aground.
+    pub fn is_stationary(&self) -> bool {
+        matches!(
+            self,
+            NavigationStatus::AtAnchor | NavigationStatus::Moored | NavigationStatus::Aground
+        )
+    }
+
+    /// Return true if the status restricts the ability of the vessel to give way.
+    pub fn is_restricted(&self) -> bool {
+        matches!(
+            self,
+            NavigationStatus::NotUnderCommand
+                | NavigationStatus::RestrictedManoeuverability
+                | NavigationStatus::ConstrainedByDraught
+                | NavigationStatus::Aground
+                | NavigationStatus::EngagedInFishing
+        )
+    }
 }
 
 impl core::fmt::Display for NavigationStatus {
@@ -327,8 +461,12 @@ impl core::fmt::Display for NavigationStatus {
             NavigationStatus::UnderWaySailing => write!(f, "under way sailing"),
             NavigationStatus::Reserved9 => write!(f, "(reserved9)"),
             NavigationStatus::Reserved10 => write!(f, "(reserved10)"),
-            NavigationStatus::Reserved11 => write!(f, "(reserved11)"),
-            NavigationStatus::Reserved12 => write!(f, "(reserved12)"),
+            NavigationStatus::PowerDrivenTowingAstern => {
+                write!(f, "power-driven vessel towing astern")
+            }
+            NavigationStatus::PowerDrivenPushingAhead => {
+                write!(f, "power-driven vessel pushing ahead or towing alongside")
+            }
             NavigationStatus::Reserved13 => write!(f, "(reserved13)"),
             NavigationStatus::AisSartIsActive => write!(f, "ais sart is active"),
             NavigationStatus::NotDefined => write!(f, "(notDefined)"),
@@ -653,6 +791,120 @@ impl Default for CargoType {
 
 // -------------------------------------------------------------------------------------------------
 
+/// Combined ship and cargo type code (0-99) as transmitted in AIS messages 5, 19 and 24
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ShipAndCargoType(pub u8);
+
+impl ShipAndCargoType {
+    /// Return the description of the code according to ITU-R M.1371.
+    pub fn description(&self) -> &'static str {
+        let hazard = |kind: &'static [&'static str; 10]| kind[(self.0 % 10) as usize];
+        match self.0 {
+            0 => "Not available",
+            1..=19 => "Reserved for future use",
+            20..=29 => hazard(&[
+                "Wing in ground (WIG), all ships of this type",
+                "Wing in ground (WIG), hazardous category A",
+                "Wing in ground (WIG), hazardous category B",
+                "Wing in ground (WIG), hazardous category C",
+                "Wing in ground (WIG), hazardous category D",
+                "Wing in ground (WIG), reserved for future use",
+                "Wing in ground (WIG), reserved for future use",
+                "Wing in ground (WIG), reserved for future use",
+                "Wing in ground (WIG), reserved for future use",
+                "Wing in ground (WIG), no additional information",
+            ]),
+            30 => "Fishing",
+            31 => "Towing",
+            32 => "Towing: length exceeds 200 m or breadth exceeds 25 m",
+            33 => "Dredging or underwater operations",
+            34 => "Diving operations",
+            35 => "Military operations",
+            36 => "Sailing",
+            37 => "Pleasure craft",
+            38 | 39 => "Reserved",
+            40..=49 => hazard(&[
+                "High speed craft (HSC), all ships of this type",
+                "High speed craft (HSC), hazardous category A",
+                "High speed craft (HSC), hazardous category B",
+                "High speed craft (HSC), hazardous category C",
+                "High speed craft (HSC), hazardous category D",
+                "High speed craft (HSC), reserved for future use",
+                "High speed craft (HSC), reserved for future use",
+                "High speed craft (HSC), reserved for future use",
+                "High speed craft (HSC), reserved for future use",
+                "High speed craft (HSC), no additional information",
+            ]),
+            50 => "Pilot vessel",
+            51 => "Search and rescue vessel",
+            52 => "Tug",
+            53 => "Port tender",
+            54 => "Anti-pollution equipment",
+            55 => "Law enforcement",
+            56 | 57 => "Spare, local vessel",
+            58 => "Medical transport",
+            59 => "Noncombatant ship according to RR Resolution No. 18",
+            60..=69 => hazard(&[
+                "Passenger, all ships of this type",
+                "Passenger, hazardous category A",
+                "Passenger, hazardous category B",
+                "Passenger, hazardous category C",
+                "Passenger, hazardous category D",
+                "Passenger, reserved for future use",
+                "Passenger, reserved for future use",
+                "Passenger, reserved for future use",
+                "Passenger, reserved for future use",
+                "Passenger, no additional information",
+            ]),
+            70..=79 => hazard(&[
+                "Cargo, all ships of this type",
+                "Cargo, hazardous category A",
+                "Cargo, hazardous category B",
+                "Cargo, hazardous category C",
+                "Cargo, hazardous category D",
+                "Cargo, reserved for future use",
+                "Cargo, reserved for future use",
+                "Cargo, reserved for future use",
+                "Cargo, reserved for future use",
+                "Cargo, no additional information",
+            ]),
+            80..=89 => hazard(&[
+                "Tanker, all ships of this type",
+                "Tanker, hazardous category A",
+                "Tanker, hazardous category B",
+                "Tanker, hazardous category C",
+                "Tanker, hazardous category D",
+                "Tanker, reserved for future use",
+                "Tanker, reserved for future use",
+                "Tanker, reserved for future use",
+                "Tanker, reserved for future use",
+                "Tanker, no additional information",
+            ]),
+            90..=99 => hazard(&[
+                "Other type, all ships of this type",
+                "Other type, hazardous category A",
+                "Other type, hazardous category B",
+                "Other type, hazardous category C",
+                "Other type, hazardous category D",
+                "Other type, reserved for future use",
+                "Other type, reserved for future use",
+                "Other type, reserved for future use",
+                "Other type, reserved for future use",
+                "Other type, no additional information",
+            ]),
+            _ => "Invalid",
+        }
+    }
+}
+
+impl core::fmt::Display for ShipAndCargoType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
 /// EPFD position fix types
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum PositionFixType {
@@ -710,305 +962,101 @@ impl core::fmt::Display for PositionFixType {
 }
 
 impl VesselStaticData {
-    /// Decode ISO 3166 country code from MID part of MMSI.
+    /// Return the overall length of the vessel in metres, if known.
+    pub fn length(&self) -> Option<u16> {
+        overall_dimension(self.dimension_to_bow, self.dimension_to_stern)
+    }
+
+    /// Return the overall breadth of the vessel in metres, if known.
+    pub fn breadth(&self) -> Option<u16> {
+        overall_dimension(self.dimension_to_port, self.dimension_to_starboard)
+    }
+
+    /// Return the position of the reference point (e.g. GNSS antenna) relative to the centre of
+    /// the vessel as metres forward and metres to starboard.
+    pub fn reference_point(&self) -> Option<(f64, f64)> {
+        reference_point(
+            self.dimension_to_bow,
+            self.dimension_to_stern,
+            self.dimension_to_port,
+            self.dimension_to_starboard,
+        )
+    }
+
+    /// Resolve the ETA into a date time relative to the given reference time (e.g. current
+    /// time). AIS transmits only the month, day, hour and minute of the ETA, so the year is
+    /// chosen so that the ETA is at most 180 days in the past.
+    pub fn resolve_eta(&self, reference: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let eta = self.eta?;
+        resolve_eta(eta.month(), eta.day(), eta.hour(), eta.minute(), reference)
+            .ok()
+            .flatten()
+    }
+
+    /// Return the combined ship and cargo type code.
+    pub fn ship_and_cargo_type(&self) -> ShipAndCargoType {
+        let code = match self.ship_type {
+            ShipType::NotAvailable => 0,
+            ShipType::Reserved1
+            | ShipType::WingInGround
+            | ShipType::HighSpeedCraft
+            | ShipType::Passenger
+            | ShipType::Cargo
+            | ShipType::Tanker
+            | ShipType::Other => self.ship_type.to_value() + self.cargo_type.to_value() - 10,
+            _ => self.ship_type.to_value(),
+        };
+        ShipAndCargoType(code)
+    }
+
+    /// Decode ISO 3166 country code from MID part of MMSI. Only ship station MMSIs are
+    /// recognized; see `HasMmsi::flag_state()` for other station types.
     pub fn country(&self) -> Option<&'static str> {
-        match self.mmsi / 1000000 {
-            // Mapping generated with mid-to-iso3166.py
-            201 => Some("AL"), // Albania
-            202 => Some("AD"), // Andorra
-            203 => Some("AT"), // Austria
-            204 => Some("PT"), // Portugal
-            205 => Some("BE"), // Belgium
-            206 => Some("BY"), // Belarus
-            207 => Some("BG"), // Bulgaria
-            208 => Some("VA"), // Vatican City State
-            209 => Some("CY"), // Cyprus
-            210 => Some("CY"), // Cyprus
-            211 => Some("DE"), // Germany
-            212 => Some("CY"), // Cyprus
-            213 => Some("GE"), // Georgia
-            214 => Some("MD"), // Moldova
-            215 => Some("MT"), // Malta
-            216 => Some("AM"), // Armenia
-            218 => Some("DE"), // Germany
-            219 => Some("DK"), // Denmark
-            220 => Some("DK"), // Denmark
-            224 => Some("ES"), // Spain
-            225 => Some("ES"), // Spain
-            226 => Some("FR"), // France
-            227 => Some("FR"), // France
-            228 => Some("FR"), // France
-            229 => Some("MT"), // Malta
-            230 => Some("FI"), // Finland
-            231 => Some("FO"), // Faroe Islands
-            232 => Some("GB"), // United Kingdom of Great Britain and Northern Ireland
-            233 => Some("GB"), // United Kingdom of Great Britain and Northern Ireland
-            234 => Some("GB"), // United Kingdom of Great Britain and Northern Ireland
-            235 => Some("GB"), // United Kingdom of Great Britain and Northern Ireland
-            236 => Some("GI"), // Gibraltar
-            237 => Some("GR"), // Greece
-            238 => Some("HR"), // Croatia
-            239 => Some("GR"), // Greece
-            240 => Some("GR"), // Greece
-            241 => Some("GR"), // Greece
-            242 => Some("MA"), // Morocco
-            243 => Some("HU"), // Hungary
-            244 => Some("NL"), // Netherlands
-            245 => Some("NL"), // Netherlands
-            246 => Some("NL"), // Netherlands
-            247 => Some("IT"), // Italy
-            248 => Some("MT"), // Malta
-            249 => Some("MT"), // Malta
-            250 => Some("IE"), // Ireland
-            251 => Some("IS"), // Iceland
-            252 => Some("LI"), // Liechtenstein
-            253 => Some("LU"), // Luxembourg
-            254 => Some("MC"), // Monaco
-            255 => Some("PT"), // Portugal
-            256 => Some("MT"), // Malta
-            257 => Some("NO"), // Norway
-            258 => Some("NO"), // Norway
-            259 => Some("NO"), // Norway
-            261 => Some("PL"), // Poland
-            262 => Some("ME"), // Montenegro
-            263 => Some("PT"), // Portugal
-            264 => Some("RO"), // Romania
-            265 => Some("SE"), // Sweden
-            266 => Some("SE"), // Sweden
-            267 => Some("SK"), // Slovakia
-            268 => Some("SM"), // San Marino
-            269 => Some("CH"), // Switzerland
-            270 => Some("CZ"), // Czechia
-            271 => Some("TR"), // Turkey
-            272 => Some("UA"), // Ukraine
-            273 => Some("RU"), // Russian Federation
-            274 => Some("MK"), // Republic of North Macedonia
-            275 => Some("LV"), // Latvia
-            276 => Some("EE"), // Estonia
-            277 => Some("LT"), // Lithuania
-            278 => Some("SI"), // Slovenia
-            279 => Some("RS"), // Serbia
-            301 => Some("AI"), // Anguilla
-            303 => Some("US"), // United States of America
-            304 => Some("AG"), // Antigua and Barbuda
-            305 => Some("AG"), // Antigua and Barbuda
-            306 => Some("BQ"), // Bonaire, Sint Eustatius and Saba
-            //            306 => Some("CW"), // Curaçao
-            //            306 => Some("SX"), // Sint Maarten
-            307 => Some("AW"), // Aruba
-            308 => Some("BS"), // Bahamas
-            309 => Some("BS"), // Bahamas
-            310 => Some("BM"), // Bermuda
-            311 => Some("BS"), // Bahamas
-            312 => Some("BZ"), // Belize
-            314 => Some("BB"), // Barbados
-            316 => Some("CA"), // Canada
-            319 => Some("KY"), // Cayman Islands
-            321 => Some("CR"), // Costa Rica
-            323 => Some("CU"), // Cuba
-            325 => Some("DM"), // Dominica
-            327 => Some("DO"), // Dominican Republic
-            329 => Some("GP"), // Guadeloupe
-            330 => Some("GD"), // Grenada
-            331 => Some("GL"), // Greenland
-            332 => Some("GT"), // Guatemala
-            334 => Some("HN"), // Honduras
-            336 => Some("HT"), // Haiti
-            338 => Some("US"), // United States of America
-            339 => Some("JM"), // Jamaica
-            341 => Some("KN"), // Saint Kitts and Nevis
-            343 => Some("LC"), // Saint Lucia
-            345 => Some("MX"), // Mexico
-            347 => Some("MQ"), // Martinique
-            348 => Some("MS"), // Montserrat
-            350 => Some("NI"), // Nicaragua
-            351 => Some("PA"), // Panama
-            352 => Some("PA"), // Panama
-            353 => Some("PA"), // Panama
-            354 => Some("PA"), // Panama
-            355 => Some("PA"), // Panama
-            356 => Some("PA"), // Panama
-            357 => Some("PA"), // Panama
-            358 => Some("PR"), // Puerto Rico
-            359 => Some("SV"), // El Salvador
-            361 => Some("PM"), // Saint Pierre and Miquelon
-            362 => Some("TT"), // Trinidad and Tobago
-            364 => Some("TC"), // Turks and Caicos Islands
-            366 => Some("US"), // United States of America
-            367 => Some("US"), // United States of America
-            368 => Some("US"), // United States of America
-            369 => Some("US"), // United States of America
-            370 => Some("PA"), // Panama
-            371 => Some("PA"), // Panama
-            372 => Some("PA"), // Panama
-            373 => Some("PA"), // Panama
-            374 => Some("PA"), // Panama
-            375 => Some("VC"), // Saint Vincent and the Grenadines
-            376 => Some("VC"), // Saint Vincent and the Grenadines
-            377 => Some("VC"), // Saint Vincent and the Grenadines
-            378 => Some("VG"), // British Virgin Islands
-            379 => Some("VI"), // United States Virgin Islands
-            401 => Some("AF"), // Afghanistan
-            403 => Some("SA"), // Saudi Arabia
-            405 => Some("BD"), // Bangladesh
-            408 => Some("BH"), // Bahrain
-            410 => Some("BT"), // Bhutan
-            412 => Some("CN"), // China
-            413 => Some("CN"), // China
-            414 => Some("CN"), // China
-            416 => Some("TW"), // Taiwan
-            417 => Some("LK"), // Sri Lanka
-            419 => Some("IN"), // India
-            422 => Some("IR"), // Iran
-            423 => Some("AZ"), // Azerbaijan
-            425 => Some("IQ"), // Iraq
-            428 => Some("IL"), // Israel
-            431 => Some("JP"), // Japan
-            432 => Some("JP"), // Japan
-            434 => Some("TM"), // Turkmenistan
-            436 => Some("KZ"), // Kazakhstan
-            437 => Some("UZ"), // Uzbekistan
-            438 => Some("JO"), // Jordan
-            440 => Some("KR"), // Korea
-            441 => Some("KR"), // Korea
-            443 => Some("PS"), // Palestine, State of
-            445 => Some("KR"), // Korea
-            447 => Some("KW"), // Kuwait
-            450 => Some("LB"), // Lebanon
-            451 => Some("KG"), // Kyrgyzstan
-            453 => Some("MO"), // Macao
-            455 => Some("MV"), // Maldives
-            457 => Some("MN"), // Mongolia
-            459 => Some("NP"), // Nepal
-            461 => Some("OM"), // Oman
-            463 => Some("PK"), // Pakistan
-            466 => Some("QA"), // Qatar
-            468 => Some("SY"), // Syrian Arab Republic
-            470 => Some("AE"), // United Arab Emirates
-            471 => Some("AE"), // United Arab Emirates
-            472 => Some("TJ"), // Tajikistan
-            473 => Some("YE"), // Yemen
-            475 => Some("YE"), // Yemen
-            477 => Some("HK"), // Hong Kong
-            478 => Some("BA"), // Bosnia and Herzegovina
-            501 => Some("TF"), // French Southern Territories
-            503 => Some("AU"), // Australia
-            506 => Some("MM"), // Myanmar
-            508 => Some("BN"), // Brunei Darussalam
-            510 => Some("FM"), // Micronesia
-            511 => Some("PW"), // Palau
-            512 => Some("NZ"), // New Zealand
-            514 => Some("KH"), // Cambodia
-            515 => Some("KH"), // Cambodia
-            516 => Some("CX"), // Christmas Island
-            518 => Some("CK"), // Cook Islands
-            520 => Some("FJ"), // Fiji
-            523 => Some("CC"), // Cocos Islands
-            525 => Some("ID"), // Indonesia
-            529 => Some("KI"), // Kiribati
-            531 => Some("LA"), // Lao People's Democratic Republic
-            533 => Some("MY"), // Malaysia
-            536 => Some("MP"), // Northern Mariana Islands
-            538 => Some("MH"), // Marshall Islands
-            540 => Some("NC"), // New Caledonia
-            542 => Some("NU"), // Niue
-            544 => Some("NR"), // Nauru
-            546 => Some("PF"), // French Polynesia
-            548 => Some("PH"), // Philippines
-            550 => Some("TL"), // Timor-Leste
-            553 => Some("PG"), // Papua New Guinea
-            555 => Some("PN"), // Pitcairn
-            557 => Some("SB"), // Solomon Islands
-            559 => Some("AS"), // American Samoa
-            561 => Some("WS"), // Samoa
-            563 => Some("SG"), // Singapore
-            564 => Some("SG"), // Singapore
-            565 => Some("SG"), // Singapore
-            566 => Some("SG"), // Singapore
-            567 => Some("TH"), // Thailand
-            570 => Some("TO"), // Tonga
-            572 => Some("TV"), // Tuvalu
-            574 => Some("VN"), // Viet Nam
-            576 => Some("VU"), // Vanuatu
-            577 => Some("VU"), // Vanuatu
-            578 => Some("WF"), // Wallis and Futuna
-            601 => Some("ZA"), // South Africa
-            603 => Some("AO"), // Angola
-            605 => Some("DZ"), // Algeria
-            607 => Some("TF"), // French Southern Territories
-            608 => Some("SH"), // Saint Helena, Ascension and Tristan da Cunha
-            609 => Some("BI"), // Burundi
-            610 => Some("BJ"), // Benin
-            611 => Some("BW"), // Botswana
-            612 => Some("CF"), // Central African Republic
-            613 => Some("CM"), // Cameroon
-            615 => Some("CG"), // Congo
-            616 => Some("KM"), // Comoros
-            617 => Some("CV"), // Cabo Verde
-            618 => Some("TF"), // French Southern Territories
-            619 => Some("CI"), // Côte d'Ivoire
-            620 => Some("KM"), // Comoros
-            621 => Some("DJ"), // Djibouti
-            622 => Some("EG"), // Egypt
-            624 => Some("ET"), // Ethiopia
-            625 => Some("ER"), // Eritrea
-            626 => Some("GA"), // Gabon
-            627 => Some("GH"), // Ghana
-            629 => Some("GM"), // Gambia
-            630 => Some("GW"), // Guinea-Bissau
-            631 => Some("GQ"), // Equatorial Guinea
-            632 => Some("GN"), // Guinea
-            633 => Some("BF"), // Burkina Faso
-            634 => Some("KE"), // Kenya
-            635 => Some("TF"), // French Southern Territories
-            636 => Some("LR"), // Liberia
-            637 => Some("LR"), // Liberia
-            638 => Some("SS"), // South Sudan
-            642 => Some("LY"), // Libya
-            644 => Some("LS"), // Lesotho
-            645 => Some("MU"), // Mauritius
-            647 => Some("MG"), // Madagascar
-            649 => Some("ML"), // Mali
-            650 => Some("MZ"), // Mozambique
-            654 => Some("MR"), // Mauritania
-            655 => Some("MW"), // Malawi
-            656 => Some("NE"), // Niger
-            657 => Some("NG"), // Nigeria
-            659 => Some("NA"), // Namibia
-            660 => Some("TF"), // French Southern Territories
-            661 => Some("RW"), // Rwanda
-            662 => Some("SD"), // Sudan
-            663 => Some("SN"), // Senegal
-            664 => Some("SC"), // Seychelles
-            665 => Some("SH"), // Saint Helena, Ascension and Tristan da Cunha
-            666 => Some("SO"), // Somalia
-            667 => Some("SL"), // Sierra Leone
-            668 => Some("ST"), // Sao Tome and Principe
-            669 => Some("SZ"), // Eswatini
-            670 => Some("TD"), // Chad
-            671 => Some("TG"), // Togo
-            672 => Some("TN"), // Tunisia
-            674 => Some("TZ"), // Tanzania, United Republic of
-            675 => Some("UG"), // Uganda
-            676 => Some("CG"), // Congo
-            677 => Some("TZ"), // Tanzania, United Republic of
-            678 => Some("ZM"), // Zambia
-            679 => Some("ZW"), // Zimbabwe
-            701 => Some("AR"), // Argentina
-            710 => Some("BR"), // Brazil
-            720 => Some("BO"), // Bolivia
-            725 => Some("CL"), // Chile
-            730 => Some("CO"), // Colombia
-            735 => Some("EC"), // Ecuador
-            740 => Some("FK"), // Falkland Islands [Malvinas]
-            745 => Some("GF"), // French Guiana
-            750 => Some("GY"), // Guyana
-            755 => Some("PY"), // Paraguay
-            760 => Some("PE"), // Peru
-            765 => Some("SR"), // Suriname
-            770 => Some("UY"), // Uruguay
-            775 => Some("VE"), // Venezuela
-            _ => None,
-        }
+        mid::mid_to_country((self.mmsi / 1000000) as u16)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Minimum payload length in bits for each message type. Trailing spare bits are not required
+/// because many transponders omit them.
+const MIN_PAYLOAD_BITS: [usize; 28] = [
+    0, 168, 168, 168, 168, 420, 88, 72, 56, 168, 72, 168, 72, 72, 40, 88, 96, 80, 168, 312, 72,
+    272, 168, 160, 160, 40, 60, 96,
+];
+
+/// Check that the payload without fill bits is long enough for the message type.
+pub(crate) fn check_payload_length(message_type: u64, len: usize) -> Result<(), ParseError> {
+    match MIN_PAYLOAD_BITS.get(message_type as usize) {
+        Some(min_len) if len < *min_len => Err(ParseError::TruncatedPayload(format!(
+            "message type {} has {} bits, at least {} expected",
+            message_type, len, min_len
+        ))),
+        _ => Ok(()),
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Sum two dimensions towards the reference point. Zero sum means not available.
+fn overall_dimension(a: Option<u16>, b: Option<u16>) -> Option<u16> {
+    Some(a? + b?).filter(|d| *d > 0)
+}
+
+/// Compute reference point offset from the centre of the vessel. When the reference point is
+/// not available the dimensions towards bow and port are zero.
+fn reference_point(
+    bow: Option<u16>,
+    stern: Option<u16>,
+    port: Option<u16>,
+    starboard: Option<u16>,
+) -> Option<(f64, f64)> {
+    let (bow, stern, port, starboard) = (bow?, stern?, port?, starboard?);
+    if (bow == 0 && port == 0) || bow + stern == 0 || port + starboard == 0 {
+        return None;
     }
+    Some((
+        (stern as f64 - bow as f64) / 2.0,
+        (port as f64 - starboard as f64) / 2.0,
+    ))
 }
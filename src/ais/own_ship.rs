@@ -0,0 +1,156 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+/// Own vessel state aggregated from `!AIVDO` messages by `OwnShip::push`
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct OwnShip {
+    /// The latest position report of own vessel
+    pub dynamic_data: Option<VesselDynamicData>,
+
+    /// Static data combined from the static data reports of own vessel. Type 24 parts A and B
+    /// received separately are merged together.
+    pub static_data: Option<VesselStaticData>,
+}
+
+impl OwnShip {
+    /// Create a new tracker without any own vessel state
+    pub fn new() -> OwnShip {
+        OwnShip::default()
+    }
+
+    /// Push a parsed message to the tracker. Returns true if the own vessel state was updated.
+    /// Messages about other vessels and messages without vessel data are ignored.
+    pub fn push(&mut self, message: &ParsedMessage) -> bool {
+        match message {
+            ParsedMessage::VesselDynamicData(vdd) if vdd.own_vessel => {
+                self.dynamic_data = Some(vdd.clone());
+                true
+            }
+            ParsedMessage::VesselStaticData(vsd) if vsd.own_vessel => {
+                // Keep the fields missing from the new report, unless the vessel has changed
+                let merged = self
+                    .static_data
+                    .as_ref()
+                    .and_then(|old| vsd.merge(old).ok());
+                self.static_data = Some(merged.unwrap_or_else(|| vsd.clone()));
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Return MMSI of own vessel, if known
+    pub fn mmsi(&self) -> Option<u32> {
+        self.dynamic_data
+            .as_ref()
+            .map(|vdd| vdd.mmsi)
+            .or_else(|| self.static_data.as_ref().map(|vsd| vsd.mmsi))
+    }
+
+    /// Return name of own vessel, if known
+    pub fn name(&self) -> Option<&str> {
+        self.static_data
+            .as_ref()
+            .and_then(|vsd| vsd.name.as_deref())
+    }
+
+    /// Return call sign of own vessel, if known
+    pub fn call_sign(&self) -> Option<&str> {
+        self.static_data
+            .as_ref()
+            .and_then(|vsd| vsd.call_sign.as_deref())
+    }
+
+    /// Return speed over ground of own vessel in knots, if known
+    pub fn sog_knots(&self) -> Option<f64> {
+        self.dynamic_data.as_ref().and_then(|vdd| vdd.sog_knots)
+    }
+
+    /// Return course over ground of own vessel in degrees, if known
+    pub fn cog(&self) -> Option<f64> {
+        self.dynamic_data.as_ref().and_then(|vdd| vdd.cog)
+    }
+
+    /// Return true heading of own vessel in degrees, if known
+    pub fn heading_true(&self) -> Option<f64> {
+        self.dynamic_data.as_ref().and_then(|vdd| vdd.heading_true)
+    }
+}
+
+impl LatLon for OwnShip {
+    fn latitude(&self) -> Option<f64> {
+        self.dynamic_data.as_ref().and_then(|vdd| vdd.latitude)
+    }
+
+    fn longitude(&self) -> Option<f64> {
+        self.dynamic_data.as_ref().and_then(|vdd| vdd.longitude)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_own_ship() {
+        let mut p = NmeaParser::new();
+        p.set_type24_merging(false);
+        let mut own = OwnShip::new();
+
+        // Other vessels are ignored
+        let msg = p
+            .parse_sentence("!AIVDM,1,1,,A,15RTgt0PAso;90TKcjM8h6g208CQ,0*4A")
+            .unwrap();
+        assert!(!own.push(&msg));
+        assert_eq!(own, OwnShip::new());
+
+        let msg = p
+            .parse_sentence("!AIVDO,1,1,,A,15RTgt0PAso;90TKcjM8h6g208CQ,0*48")
+            .unwrap();
+        assert!(own.push(&msg));
+        assert_eq!(own.mmsi(), Some(371798000));
+        assert_eq!(own.sog_knots(), Some(12.3));
+        assert_eq!(own.cog(), Some(224.0));
+        assert_eq!(own.heading_true(), Some(215.0));
+        assert::close(own.latitude().unwrap_or(0.0), 48.4, 0.1);
+        assert::close(own.longitude().unwrap_or(0.0), -123.4, 0.1);
+        assert_eq!(own.name(), None);
+
+        // Type 24 parts are merged
+        for s in [
+            "!AIVDO,1,1,,A,H42O55i18tMET00000000000000,2*6F",
+            "!AIVDO,1,1,,A,H42O55lti4hhhilD3nink000?050,0*42",
+        ] {
+            let msg = p.parse_sentence(s).unwrap();
+            assert!(own.push(&msg));
+        }
+        match &own.static_data {
+            Some(vsd) => {
+                assert_eq!(vsd.mmsi, 271041815);
+                assert_eq!(vsd.dimension_to_stern, Some(15));
+            }
+            None => {
+                panic!("No static data");
+            }
+        }
+        assert_eq!(own.name(), Some("PROGUY"));
+        assert_eq!(own.call_sign(), Some("TC6163"));
+    }
+}
@@ -22,47 +22,9 @@ pub(crate) fn handle(
     station: Station,
     own_vessel: bool,
 ) -> Result<ParsedMessage, ParseError> {
-    Ok(ParsedMessage::UtcDateResponse(BaseStationReport {
-        own_vessel: { own_vessel },
-        station: { station },
-        mmsi: { pick_u64(bv, 8, 30) as u32 },
-        timestamp: {
-            Some(parse_ymdhs(
-                pick_u64(bv, 38, 14) as i32,
-                pick_u64(bv, 52, 4) as u32,
-                pick_u64(bv, 56, 5) as u32,
-                pick_u64(bv, 61, 5) as u32,
-                pick_u64(bv, 66, 6) as u32,
-                pick_u64(bv, 72, 6) as u32,
-            )?)
-        },
-        high_position_accuracy: { pick_u64(bv, 78, 1) != 0 },
-        latitude: {
-            let lat_raw = pick_i64(bv, 107, 27) as i32;
-            if lat_raw != 0x3412140 {
-                Some((lat_raw as f64) / 600000.0)
-            } else {
-                None
-            }
-        },
-        longitude: {
-            let lon_raw = pick_i64(bv, 79, 28) as i32;
-            if lon_raw != 0x6791AC0 {
-                Some((lon_raw as f64) / 600000.0)
-            } else {
-                None
-            }
-        },
-        position_fix_type: {
-            let raw = pick_u64(bv, 134, 4) as u8;
-            match raw {
-                0 => None,
-                _ => Some(PositionFixType::new(raw)),
-            }
-        },
-        raim_flag: { pick_u64(bv, 148, 1) != 0 },
-        radio_status: { pick_u64(bv, 149, 19) as u32 },
-    }))
+    Ok(ParsedMessage::UtcDateResponse(
+        vdm_t4::parse_base_station_report(bv, station, own_vessel)?,
+    ))
 }
 
 // -------------------------------------------------------------------------------------------------
@@ -101,4 +63,23 @@ mod test {
             }
         }
     }
+    #[test]
+    fn test_parse_vdm_type11_not_available() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("!AIVDM,1,1,,A,;3`l5N0000Htt<tSF0l4Q@000000,0*35") {
+            Ok(ps) => match ps {
+                ParsedMessage::UtcDateResponse(bsr) => {
+                    assert_eq!(bsr.mmsi, 244123000);
+                    assert_eq!(bsr.timestamp, None);
+                    assert_eq!(bsr.latitude, None);
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
 }
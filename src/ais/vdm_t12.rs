@@ -30,16 +30,17 @@ pub struct AddressedSafetyRelatedMessage {
     /// Source MMSI (30 bits)
     pub source_mmsi: u32,
 
-    /// Sequence number (2 bits)
+    /// Sequence number (2 bits), used with the source MMSI to match the type 13
+    /// acknowledgement
     pub sequence_number: u8,
 
     /// Destination MMSI (30 bits)
     pub destination_mmsi: u32,
 
-    /// Retransmit flag (1 bit)
+    /// Retransmit flag (1 bit): true if the message is a retransmission
     pub retransmit_flag: bool,
 
-    /// Text (936 bits; 1-156 chars)
+    /// Text (936 bits; 1-156 chars). Long messages span several VDM sentences.
     pub text: String,
 }
 
@@ -127,4 +128,35 @@ mod test {
             }
         }
     }
+    #[test]
+    fn test_parse_vdm_type12_multipart() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence(
+            "!AIVDM,2,1,1,A,<02<Hnlo4U?PC53EB9D5PC53EB9D5P>1F971D9?>1<PG1B>9>7P2E?IP>BPl,0*11",
+        ) {
+            Ok(ParsedMessage::Incomplete) => {}
+            ps => {
+                panic!("Unexpected result: {:?}", ps);
+            }
+        }
+        match p.parse_sentence("!AIVDM,2,2,1,A,P?66P@?C9D9?>P9>P619BG1I,0*15") {
+            Ok(ps) => match ps {
+                ParsedMessage::AddressedSafetyRelatedMessage(asrm) => {
+                    assert_eq!(asrm.source_mmsi, 2300123);
+                    assert_eq!(asrm.sequence_number, 1);
+                    assert_eq!(asrm.destination_mmsi, 230987000);
+                    assert_eq!(
+                        asrm.text,
+                        "SECURITE SECURITE NAVIGATIONAL WARNING BUOY NR 4 OFF POSITION IN FAIRWAY"
+                    );
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
 }
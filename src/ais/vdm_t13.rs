@@ -55,6 +55,29 @@ pub struct SafetyRelatedAcknowledgement {
     pub mmsi4_seq: u8,
 }
 
+impl SafetyRelatedAcknowledgement {
+    /// Return the acknowledged (destination MMSI, sequence number) pairs. Unused pairs are left
+    /// out.
+    pub fn acknowledgements(&self) -> Vec<(u32, u8)> {
+        [
+            (self.mmsi1, self.mmsi1_seq),
+            (self.mmsi2, self.mmsi2_seq),
+            (self.mmsi3, self.mmsi3_seq),
+            (self.mmsi4, self.mmsi4_seq),
+        ]
+        .iter()
+        .filter(|(mmsi, _)| *mmsi != 0)
+        .copied()
+        .collect()
+    }
+
+    /// Return true if the message acknowledges the addressed message (type 6 or 12) sent to the
+    /// given MMSI with the given sequence number.
+    pub fn acknowledges(&self, mmsi: u32, sequence_number: u8) -> bool {
+        self.acknowledgements().contains(&(mmsi, sequence_number))
+    }
+}
+
 // -------------------------------------------------------------------------------------------------
 
 /// AIS VDM/VDO type 13: Safety-Related Acknowledgment
@@ -64,22 +87,31 @@ pub(crate) fn handle(
     own_vessel: bool,
 ) -> Result<ParsedMessage, ParseError> {
     Ok(ParsedMessage::SafetyRelatedAcknowledgement(
-        SafetyRelatedAcknowledgement {
-            own_vessel: { own_vessel },
-            station: { station },
-            mmsi: { pick_u64(bv, 8, 30) as u32 },
-            mmsi1: { pick_u64(bv, 40, 30) as u32 },
-            mmsi1_seq: { pick_u64(bv, 70, 2) as u8 },
-            mmsi2: { pick_u64(bv, 72, 30) as u32 },
-            mmsi2_seq: { pick_u64(bv, 102, 2) as u8 },
-            mmsi3: { pick_u64(bv, 104, 30) as u32 },
-            mmsi3_seq: { pick_u64(bv, 134, 2) as u8 },
-            mmsi4: { pick_u64(bv, 136, 30) as u32 },
-            mmsi4_seq: { pick_u64(bv, 166, 2) as u8 },
-        },
+        parse_acknowledgement(bv, station, own_vessel),
     ))
 }
 
+/// Parse the acknowledgement layout shared by types 7 and 13.
+pub(crate) fn parse_acknowledgement(
+    bv: &BitVec,
+    station: Station,
+    own_vessel: bool,
+) -> SafetyRelatedAcknowledgement {
+    SafetyRelatedAcknowledgement {
+        own_vessel: { own_vessel },
+        station: { station },
+        mmsi: { pick_u64(bv, 8, 30) as u32 },
+        mmsi1: { pick_u64(bv, 40, 30) as u32 },
+        mmsi1_seq: { pick_u64(bv, 70, 2) as u8 },
+        mmsi2: { pick_u64(bv, 72, 30) as u32 },
+        mmsi2_seq: { pick_u64(bv, 102, 2) as u8 },
+        mmsi3: { pick_u64(bv, 104, 30) as u32 },
+        mmsi3_seq: { pick_u64(bv, 134, 2) as u8 },
+        mmsi4: { pick_u64(bv, 136, 30) as u32 },
+        mmsi4_seq: { pick_u64(bv, 166, 2) as u8 },
+    }
+}
+
 // -------------------------------------------------------------------------------------------------
 
 #[cfg(test)]
@@ -103,6 +135,9 @@ mod test {
                         assert_eq!(sra.mmsi3_seq, 0);
                         assert_eq!(sra.mmsi4, 0);
                         assert_eq!(sra.mmsi4_seq, 0);
+                        assert_eq!(sra.acknowledgements(), vec![(211217560, 2)]);
+                        assert!(sra.acknowledges(211217560, 2));
+                        assert!(!sra.acknowledges(211217560, 1));
                     }
                     ParsedMessage::Incomplete => {
                         assert!(false);
@@ -34,6 +34,24 @@ pub struct SafetyRelatedBroadcastMessage {
     pub text: String,
 }
 
+impl SafetyRelatedBroadcastMessage {
+    /// Return true if the message is sent by an AIS search and rescue transmitter (SART),
+    /// man overboard (MOB) device or EPIRB, identified by the MMSI prefix 970, 972 or 974.
+    pub fn is_emergency_device(&self) -> bool {
+        matches!(self.mmsi / 1_000_000, 970 | 972 | 974)
+    }
+
+    /// Return true if the message is an emergency device test (e.g. "SART TEST").
+    pub fn is_test(&self) -> bool {
+        self.is_emergency_device() && self.text.contains("TEST")
+    }
+
+    /// Return true if the message is an emergency device alert (e.g. "SART ACTIVE").
+    pub fn is_active(&self) -> bool {
+        self.is_emergency_device() && self.text.contains("ACTIVE")
+    }
+}
+
 // -------------------------------------------------------------------------------------------------
 
 /// AIS VDM/VDO type 14: Safety-Related Broadcast Message
@@ -128,4 +146,57 @@ mod test {
             }
         }
     }
+    #[test]
+    fn test_parse_vdm_type14_emergency_devices() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("!AIVDM,1,1,,A,>>M4nfA<59B1@E=@,2*2F") {
+            Ok(ps) => match ps {
+                ParsedMessage::SafetyRelatedBroadcastMessage(srbm) => {
+                    assert_eq!(srbm.mmsi, 970012345);
+                    assert_eq!(srbm.text, "SART TEST");
+                    assert!(srbm.is_emergency_device());
+                    assert!(srbm.is_test());
+                    assert!(!srbm.is_active());
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+
+        match p.parse_sentence("!AIVDM,1,1,,A,>>O5e@0lt:04=@UHD,2*49") {
+            Ok(ps) => match ps {
+                ParsedMessage::SafetyRelatedBroadcastMessage(srbm) => {
+                    assert_eq!(srbm.text, "MOB ACTIVE");
+                    assert!(srbm.is_active());
+                    assert!(!srbm.is_test());
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+
+        // Ordinary station sending a test message
+        match p.parse_sentence("!AIVDM,1,1,,A,>4aDT81@E=@,2*2E") {
+            Ok(ps) => match ps {
+                ParsedMessage::SafetyRelatedBroadcastMessage(srbm) => {
+                    assert!(!srbm.is_emergency_device());
+                    assert!(!srbm.is_test());
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
 }
@@ -58,6 +58,23 @@ pub struct Interrogation {
     pub offset2_1: Option<u16>,
 }
 
+impl Interrogation {
+    /// Return the interrogation requests as (interrogated MMSI, message type, slot offset)
+    /// tuples.
+    pub fn requests(&self) -> Vec<(u32, u8, u16)> {
+        let mut v = vec![(self.mmsi1, self.type1_1, self.offset1_1)];
+        if let (Some(msg_type), Some(offset)) = (self.type1_2, self.offset1_2) {
+            v.push((self.mmsi1, msg_type, offset));
+        }
+        if let (Some(mmsi), Some(msg_type), Some(offset)) =
+            (self.mmsi2, self.type2_1, self.offset2_1)
+        {
+            v.push((mmsi, msg_type, offset));
+        }
+        v
+    }
+}
+
 /// The four cases of interrogation, depending on data length mostly.
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum InterrogationCase {
@@ -129,11 +146,13 @@ pub(crate) fn handle(
             _ => None,
         },
         type2_1: match case {
-            InterrogationCase::Case4 => Some(pick_u64(bv, 140, 6) as u8),
+            InterrogationCase::Case3 | InterrogationCase::Case4 => Some(pick_u64(bv, 140, 6) as u8),
             _ => None,
         },
         offset2_1: match case {
-            InterrogationCase::Case4 => Some(pick_u64(bv, 146, 12) as u16),
+            InterrogationCase::Case3 | InterrogationCase::Case4 => {
+                Some(pick_u64(bv, 146, 12) as u16)
+            }
             _ => None,
         },
     }))
@@ -162,6 +181,7 @@ mod test {
                         assert_eq!(i.mmsi2, None);
                         assert_eq!(i.type2_1, None);
                         assert_eq!(i.offset2_1, None);
+                        assert_eq!(i.requests(), vec![(367014320, 3, 516), (367014320, 5, 617)]);
                     }
                     ParsedMessage::Incomplete => {
                         assert!(false);
@@ -176,4 +196,30 @@ mod test {
             }
         }
     }
+    #[test]
+    fn test_parse_vdm_type15_case3() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("!AIVDM,1,1,,A,?02<Hnhno=iPD6@0003KS`d5@j0,2*6C") {
+            Ok(ps) => match ps {
+                ParsedMessage::Interrogation(i) => {
+                    assert_eq!(i.case, InterrogationCase::Case3);
+                    assert_eq!(i.mmsi, 2300123);
+                    assert_eq!(i.type1_2, None);
+                    assert_eq!(i.mmsi2, Some(230222000));
+                    assert_eq!(i.type2_1, Some(21));
+                    assert_eq!(i.offset2_1, Some(200));
+                    assert_eq!(
+                        i.requests(),
+                        vec![(230111000, 5, 100), (230222000, 21, 200)]
+                    );
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
 }
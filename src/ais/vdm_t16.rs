@@ -27,8 +27,8 @@ pub struct AssignmentModeCommand {
     /// AIS station type.
     pub station: Station,
 
-    // When the message is 96 bits long it is interpreted as an assignment for a single station,
-    // When the message is 144 bits long it is interpreted as a channel assignled for two stations.
+    /// When the message is 96 bits long it is interpreted as an assignment for a single station,
+    /// when it is 144 bits long it is interpreted as an assignment for two stations.
     pub assigned_for_single_station: bool,
 
     /// Source MMSI (30 bits)
@@ -53,6 +53,19 @@ pub struct AssignmentModeCommand {
     pub increment2: Option<u16>,
 }
 
+impl AssignmentModeCommand {
+    /// Return the assignments as (destination MMSI, offset, increment) tuples.
+    pub fn assignments(&self) -> Vec<(u32, u16, u16)> {
+        let mut v = vec![(self.mmsi1, self.offset1, self.increment1)];
+        if let (Some(mmsi), Some(offset), Some(increment)) =
+            (self.mmsi2, self.offset2, self.increment2)
+        {
+            v.push((mmsi, offset, increment));
+        }
+        v
+    }
+}
+
 // -------------------------------------------------------------------------------------------------
 
 /// AIS VDM/VDO type 16: Assignment Mode Command
@@ -117,6 +130,8 @@ mod test {
                         assert_eq!(i.mmsi2, None);
                         assert_eq!(i.offset2, None);
                         assert_eq!(i.increment2, None);
+                        assert!(i.assigned_for_single_station);
+                        assert_eq!(i.assignments(), vec![(224251000, 200, 0)]);
                     }
                     ParsedMessage::Incomplete => {
                         assert!(false);
@@ -131,4 +146,26 @@ mod test {
             }
         }
     }
+    #[test]
+    fn test_parse_vdm_type16_two_stations() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("!AIVDM,1,1,,A,@02<Hnhno=iP6@0SKS`d0UP3,0*1C") {
+            Ok(ps) => match ps {
+                ParsedMessage::AssignmentModeCommand(i) => {
+                    assert!(!i.assigned_for_single_station);
+                    assert_eq!(i.mmsi, 2300123);
+                    assert_eq!(
+                        i.assignments(),
+                        vec![(230111000, 100, 2), (230222000, 150, 3)]
+                    );
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
 }
@@ -40,6 +40,33 @@ pub struct DgnssBroadcastBinaryMessage {
     pub payload: BitVec,
 }
 
+impl DgnssBroadcastBinaryMessage {
+    /// Return the payload as bytes, most significant bit first, for passing it to an RTCM
+    /// decoder. The last byte is padded with zeros.
+    pub fn payload_bytes(&self) -> Vec<u8> {
+        pack_bytes(&self.payload)
+    }
+
+    /// Return the RTCM message type from the payload header, `None` if the payload is too short.
+    pub fn rtcm_message_type(&self) -> Option<u8> {
+        if self.payload.len() >= 16 {
+            Some(pick_u64(&self.payload, 0, 6) as u8)
+        } else {
+            None
+        }
+    }
+
+    /// Return the RTCM reference station ID from the payload header, `None` if the payload is
+    /// too short.
+    pub fn rtcm_station_id(&self) -> Option<u16> {
+        if self.payload.len() >= 16 {
+            Some(pick_u64(&self.payload, 6, 10) as u16)
+        } else {
+            None
+        }
+    }
+}
+
 // -------------------------------------------------------------------------------------------------
 
 /// AIS VDM/VDO type 17: DGNSS Broadcast Binary Message
@@ -107,6 +134,10 @@ mod test {
                         assert::close(i.latitude.unwrap_or(0.0), 59.987, 0.001);
                         assert::close(i.longitude.unwrap_or(0.0), 29.130, 0.001);
                         assert_eq!(i.payload.len(), 376);
+                        assert_eq!(i.payload_bytes().len(), 47);
+                        assert_eq!(i.payload_bytes()[0..4], [124, 5, 86, 192]);
+                        assert_eq!(i.rtcm_message_type(), Some(31));
+                        assert_eq!(i.rtcm_station_id(), Some(5));
                     }
                     ParsedMessage::Incomplete => {
                         assert!(false);
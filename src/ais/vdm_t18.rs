@@ -68,21 +68,32 @@ pub(crate) fn handle(
             }
         },
         timestamp_seconds: pick_u64(bv, 133, 6) as u8,
-        class_b_unit_flag: { None },
-        class_b_display: Some(pick_u64(bv, 141, 1) != 0),
-        class_b_dsc: Some(pick_u64(bv, 142, 1) != 0),
-        class_b_band_flag: Some(pick_u64(bv, 143, 1) != 0),
-        class_b_msg22_flag: Some(pick_u64(bv, 144, 1) != 0),
-        class_b_mode_flag: Some(pick_u64(bv, 145, 1) != 0),
-        raim_flag: pick_u64(bv, 141, 1) != 0,
-        class_b_css_flag: { None },
+        class_b_unit_flag: Some(pick_u64(bv, 141, 1) != 0),
+        class_b_display: Some(pick_u64(bv, 142, 1) != 0),
+        class_b_dsc: Some(pick_u64(bv, 143, 1) != 0),
+        class_b_band_flag: Some(pick_u64(bv, 144, 1) != 0),
+        class_b_msg22_flag: Some(pick_u64(bv, 145, 1) != 0),
+        class_b_mode_flag: Some(pick_u64(bv, 146, 1) != 0),
+        raim_flag: pick_u64(bv, 147, 1) != 0,
+        class_b_css_flag: Some(pick_u64(bv, 148, 1) != 0),
         radio_status: Some(pick_u64(bv, 149, 19) as u32),
+        communication_state: Some(CommunicationState::new(
+            pick_u64(bv, 148, 1) != 0,
+            pick_u64(bv, 149, 19) as u32,
+        )),
         nav_status: NavigationStatus::NotDefined,
         rot: None,
         rot_direction: None,
         positioning_system_meta: None,
         current_gnss_position: None,
         special_manoeuvre: None,
+        dimension_to_bow: None,
+        dimension_to_stern: None,
+        dimension_to_port: None,
+        dimension_to_starboard: None,
+        regional: Some(pick_u64(bv, 38, 8) as u8),
+        regional2: Some(pick_u64(bv, 139, 2) as u8),
+        spare: None,
     }))
 }
 
@@ -99,6 +110,7 @@ mod test {
                     // The expected result
                     ParsedMessage::VesselDynamicData(vdd) => {
                         assert_eq!(vdd.mmsi, 338087471);
+                        assert_eq!(vdd.flag_state(), Some("US"));
                         assert_eq!(vdd.nav_status, NavigationStatus::NotDefined);
                         assert_eq!(vdd.rot, None);
                         assert_eq!(vdd.rot_direction, None);
@@ -112,6 +124,22 @@ mod test {
                         assert_eq!(vdd.positioning_system_meta, None);
                         assert_eq!(vdd.special_manoeuvre, None);
                         assert!(vdd.raim_flag);
+                        assert_eq!(vdd.class_b_unit_flag, Some(true));
+                        assert_eq!(vdd.class_b_display, Some(false));
+                        assert_eq!(vdd.class_b_dsc, Some(true));
+                        assert_eq!(vdd.class_b_band_flag, Some(true));
+                        assert_eq!(vdd.class_b_msg22_flag, Some(true));
+                        assert_eq!(vdd.class_b_mode_flag, Some(false));
+                        assert_eq!(vdd.class_b_css_flag, Some(true));
+                        assert_eq!(
+                            vdd.communication_state,
+                            Some(CommunicationState::Itdma(ItdmaState {
+                                sync_state: SyncState::OtherStation,
+                                slot_increment: 0,
+                                number_of_slots: 3,
+                                keep_flag: false,
+                            }))
+                        );
                     }
                     ParsedMessage::Incomplete => {
                         assert!(false);
@@ -126,4 +154,28 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_parse_vdm_type18_regional() {
+        match NmeaParser::new().parse_sentence("!AIVDM,1,1,,A,B3KMWh:D=hLOfh8V>n1=;wbEkP06,0*2C") {
+            Ok(ps) => match ps {
+                ParsedMessage::VesselDynamicData(vdd) => {
+                    assert_eq!(vdd.mmsi, 230123456);
+                    assert_eq!(vdd.sog_knots, Some(5.5));
+                    assert_eq!(vdd.timestamp_seconds, 20);
+                    assert_eq!(vdd.regional, Some(0xA5));
+                    assert_eq!(vdd.regional2, Some(2));
+                    assert_eq!(vdd.spare, None);
+                    assert_eq!(vdd.class_b_unit_flag, Some(true));
+                    assert!(!vdd.raim_flag);
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
 }
@@ -78,12 +78,20 @@ pub(crate) fn handle(
         raim_flag: pick_u64(bv, 305, 1) != 0,
         class_b_css_flag: { None },
         radio_status: { None },
+        communication_state: None,
         nav_status: NavigationStatus::NotDefined,
         rot: None,
         rot_direction: None,
         positioning_system_meta: None,
         current_gnss_position: None,
         special_manoeuvre: None,
+        dimension_to_bow: { Some(pick_u64(bv, 271, 9) as u16) },
+        dimension_to_stern: { Some(pick_u64(bv, 280, 9) as u16) },
+        dimension_to_port: { Some(pick_u64(bv, 289, 6) as u16) },
+        dimension_to_starboard: { Some(pick_u64(bv, 295, 6) as u16) },
+        regional: { Some(pick_u64(bv, 38, 8) as u8) },
+        regional2: { Some(pick_u64(bv, 139, 4) as u8) },
+        spare: { Some(pick_u64(bv, 308, 4) as u8) },
     }))
 }
 
@@ -115,6 +123,10 @@ mod test {
                         assert_eq!(vdd.positioning_system_meta, None);
                         assert_eq!(vdd.special_manoeuvre, None);
                         assert!(!vdd.raim_flag);
+                        assert_eq!(vdd.dimension_to_bow, Some(0));
+                        assert_eq!(vdd.length(), None);
+                        assert_eq!(vdd.breadth(), None);
+                        assert_eq!(vdd.reference_point(), None);
                     }
                     ParsedMessage::Incomplete => {
                         assert!(false);
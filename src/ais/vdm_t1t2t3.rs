@@ -127,6 +127,21 @@ pub(crate) fn handle(
         class_b_mode_flag: None,
         class_b_css_flag: None,
         radio_status: { Some(pick_u64(bv, 149, 19) as u32) },
+        communication_state: {
+            let raw = pick_u64(bv, 149, 19) as u32;
+            if pick_u64(bv, 0, 6) == 3 {
+                Some(CommunicationState::itdma(raw))
+            } else {
+                Some(CommunicationState::sotdma(raw))
+            }
+        },
+        dimension_to_bow: None,
+        dimension_to_stern: None,
+        dimension_to_port: None,
+        dimension_to_starboard: None,
+        regional: None,
+        regional2: None,
+        spare: { Some(pick_u64(bv, 145, 3) as u8) },
     }))
 }
 
@@ -146,6 +161,10 @@ mod test {
                     ParsedMessage::VesselDynamicData(vdd) => {
                         assert_eq!(vdd.mmsi, 371798000);
                         assert_eq!(vdd.nav_status, NavigationStatus::UnderWayUsingEngine);
+                        assert!(vdd.nav_status.is_underway());
+                        assert_eq!(vdd.spare, Some(0));
+                        assert_eq!(vdd.regional, None);
+                        assert!(!vdd.nav_status.is_stationary());
                         assert_eq!(vdd.rot, None);
                         assert_eq!(vdd.rot_direction, Some(RotDirection::Port));
                         assert_eq!(vdd.sog_knots, Some(12.3));
@@ -161,6 +180,14 @@ mod test {
                         );
                         assert_eq!(vdd.special_manoeuvre, None);
                         assert!(!vdd.raim_flag);
+                        assert_eq!(
+                            vdd.communication_state,
+                            Some(CommunicationState::Sotdma(SotdmaState {
+                                sync_state: SyncState::UtcDirect,
+                                slot_timeout: 2,
+                                sub_message: SotdmaSubMessage::SlotNumber(1249),
+                            }))
+                        );
                     }
                     ParsedMessage::Incomplete => {
                         assert!(false);
@@ -226,6 +253,10 @@ mod test {
                     ParsedMessage::VesselDynamicData(vdd) => {
                         assert_eq!(vdd.mmsi, 563808000);
                         assert_eq!(vdd.nav_status, NavigationStatus::Moored);
+                        assert!(vdd.nav_status.is_moored());
+                        assert!(vdd.nav_status.is_stationary());
+                        assert!(!vdd.nav_status.is_underway());
+                        assert_eq!(vdd.nav_status.to_string(), "moored");
                         assert_eq!(vdd.rot, Some(0.0));
                         assert_eq!(vdd.rot_direction, Some(RotDirection::Center));
                         assert_eq!(vdd.sog_knots, Some(0.0));
@@ -37,7 +37,7 @@ pub struct AidToNavigationReport {
     pub name: String,
 
     /// Position accuracy.
-    high_position_accuracy: bool,
+    pub high_position_accuracy: bool,
 
     /// Latitude
     pub latitude: Option<f64>,
@@ -51,10 +51,10 @@ pub struct AidToNavigationReport {
     pub dimension_to_stern: Option<u16>,
     /// Overall dimension / reference for position C (6 bits)
     pub dimension_to_port: Option<u16>,
-    /// Overall dimension / reference for position C (6 bits)
+    /// Overall dimension / reference for position D (6 bits)
     pub dimension_to_starboard: Option<u16>,
 
-    // Type of electronic position fixing device.
+    /// Type of electronic position fixing device.
     pub position_fix_type: Option<PositionFixType>,
 
     /// Derived from UTC second (6 bits)
@@ -67,7 +67,6 @@ pub struct AidToNavigationReport {
     /// Regional reserved, uninterpreted.
     pub regional: u8,
 
-    /// Riverine And Inland Navigation systems blue sign:
     /// RAIM (Receiver autonomous integrity monitoring) flag of electronic position
     /// fixing device; false = RAIM not in use = default; true = RAIM in use
     pub raim_flag: bool,
@@ -81,6 +80,19 @@ pub struct AidToNavigationReport {
     pub assigned_mode_flag: bool,
 }
 
+impl AidToNavigationReport {
+    /// Return true if the aid is a virtual one simulated by a nearby AIS station.
+    pub fn is_virtual(&self) -> bool {
+        self.virtual_aid_flag
+    }
+
+    /// Return true if the aid reports being off position. The indicator is only valid when the
+    /// timestamp is a valid UTC second (0-59).
+    pub fn is_off_position(&self) -> bool {
+        self.off_position_indicator && self.timestamp_seconds <= 59
+    }
+}
+
 impl LatLon for AidToNavigationReport {
     fn latitude(&self) -> Option<f64> {
         self.latitude
@@ -231,6 +243,24 @@ impl NavAidType {
     }
 }
 
+impl NavAidType {
+    /// Return true if the aid is a fixed one, such as a beacon or a light.
+    pub fn is_fixed(&self) -> bool {
+        matches!(self.code(), 1..=19) && *self != NavAidType::Reserved4
+    }
+
+    /// Return true if the aid is a floating one, such as a buoy or a light vessel. Off-position
+    /// alerts are meaningful mainly for floating aids.
+    pub fn is_floating(&self) -> bool {
+        self.code() >= 20
+    }
+
+    /// Return the numeric aid type code (0-31).
+    pub fn code(&self) -> u8 {
+        *self as u8
+    }
+}
+
 impl Default for NavAidType {
     fn default() -> NavAidType {
         NavAidType::NotSpecified
@@ -243,7 +273,7 @@ impl core::fmt::Display for NavAidType {
             NavAidType::NotSpecified => write!(f, "not specified"),
             NavAidType::ReferencePoint => write!(f, "reference point"),
             NavAidType::Racon => write!(f, "RACON"),
-            NavAidType::FixedStructure => write!(f, "FixedStructure"),
+            NavAidType::FixedStructure => write!(f, "fixed structure off shore"),
             NavAidType::Reserved4 => write!(f, "(reserved)"),
             NavAidType::LightWithoutSectors => write!(f, "light without sectors"),
             NavAidType::LightWithSectors => write!(f, "light with sectors"),
@@ -326,7 +356,7 @@ pub(crate) fn handle(
             dimension_to_starboard: { Some(pick_u64(bv, 243, 6) as u16) },
             position_fix_type: { Some(PositionFixType::new(pick_u64(bv, 249, 4) as u8)) },
             timestamp_seconds: { pick_u64(bv, 253, 6) as u8 },
-            off_position_indicator: { pick_u64(bv, 243, 1) != 0 },
+            off_position_indicator: { pick_u64(bv, 259, 1) != 0 },
             regional: { pick_u64(bv, 260, 8) as u8 },
             raim_flag: { pick_u64(bv, 268, 1) != 0 },
             virtual_aid_flag: { pick_u64(bv, 269, 1) != 0 },
@@ -380,6 +410,10 @@ mod test {
                         assert!(!atnr.raim_flag);
                         assert!(!atnr.virtual_aid_flag);
                         assert!(!atnr.assigned_mode_flag);
+                        assert!(!atnr.is_virtual());
+                        assert!(!atnr.is_off_position());
+                        assert!(atnr.aid_type.is_floating());
+                        assert_eq!(atnr.aid_type.to_string(), "cardinal mark, north");
                     }
                     ParsedMessage::Incomplete => {
                         assert!(false);
@@ -394,4 +428,40 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_parse_vdm_type21_off_position() {
+        match NmeaParser::new().parse_sentence(
+            "!AIVDM,1,1,,A,E>jGvCW4aWV0b2R@20W3Ra00000@pwMPA<Md000003V@33SlU281F5000000,0*68",
+        ) {
+            Ok(ps) => match ps {
+                ParsedMessage::AidToNavigationReport(atnr) => {
+                    assert_eq!(atnr.mmsi, 992345678);
+                    assert_eq!(atnr.aid_type, NavAidType::BeaconLateralStarboard);
+                    assert_eq!(atnr.name, "ISOLATED DANGERNORTH EXT");
+                    assert!(atnr.high_position_accuracy);
+                    assert::close(atnr.latitude.unwrap_or(0.0), 60.1, 0.00001);
+                    assert::close(atnr.longitude.unwrap_or(0.0), 24.9, 0.00001);
+                    assert_eq!(atnr.position_fix_type, Some(PositionFixType::Surveyed));
+                    assert_eq!(atnr.timestamp_seconds, 12);
+                    assert!(atnr.off_position_indicator);
+                    assert_eq!(atnr.regional, 0);
+                    assert!(atnr.raim_flag);
+                    assert!(atnr.virtual_aid_flag);
+                    assert!(!atnr.assigned_mode_flag);
+                    assert!(atnr.is_virtual());
+                    assert!(atnr.is_off_position());
+                    assert!(atnr.aid_type.is_fixed());
+                    assert!(!atnr.aid_type.is_floating());
+                    assert_eq!(atnr.aid_type.code(), 14);
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
 }
@@ -85,6 +85,66 @@ pub struct ChannelManagement {
     pub zonesize: u8,
 }
 
+impl ChannelManagement {
+    /// Return the transmit and receive mode of the channels.
+    pub fn tx_rx_mode(&self) -> TxRxMode {
+        TxRxMode::new(self.txrx)
+    }
+
+    /// Return the size of the transitional zone in nautical miles (1-8).
+    pub fn transitional_zone_size(&self) -> u8 {
+        self.zonesize + 1
+    }
+
+    /// Return the destination MMSIs of an addressed message.
+    pub fn destinations(&self) -> Vec<u32> {
+        self.dest1_mmsi
+            .iter()
+            .chain(self.dest2_mmsi.iter())
+            .filter(|mmsi| **mmsi != 0)
+            .copied()
+            .collect()
+    }
+}
+
+/// Transmit and receive mode (for message type 22)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TxRxMode {
+    /// Transmit on A and B, receive on A and B (default)
+    TxATxBRxARxB,
+
+    /// Transmit on A, receive on A and B
+    TxARxARxB,
+
+    /// Transmit on B, receive on A and B
+    TxBRxARxB,
+
+    /// Reserved for future use
+    Reserved,
+}
+
+impl TxRxMode {
+    pub(crate) fn new(val: u8) -> TxRxMode {
+        match val {
+            0 => TxRxMode::TxATxBRxARxB,
+            1 => TxRxMode::TxARxARxB,
+            2 => TxRxMode::TxBRxARxB,
+            _ => TxRxMode::Reserved,
+        }
+    }
+}
+
+impl core::fmt::Display for TxRxMode {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            TxRxMode::TxATxBRxARxB => write!(f, "TxA/TxB, RxA/RxB"),
+            TxRxMode::TxARxARxB => write!(f, "TxA, RxA/RxB"),
+            TxRxMode::TxBRxARxB => write!(f, "TxB, RxA/RxB"),
+            TxRxMode::Reserved => write!(f, "reserved"),
+        }
+    }
+}
+
 // -------------------------------------------------------------------------------------------------
 
 /// AIS VDM/VDO type 22: Channel Management
@@ -178,6 +238,9 @@ mod test {
                         assert!(!cm.channel_a_band);
                         assert!(!cm.channel_b_band);
                         assert_eq!(cm.zonesize, 4);
+                        assert_eq!(cm.tx_rx_mode(), TxRxMode::TxATxBRxARxB);
+                        assert_eq!(cm.transitional_zone_size(), 5);
+                        assert!(cm.destinations().is_empty());
                     }
                     ParsedMessage::Incomplete => {
                         assert!(false);
@@ -192,4 +255,34 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_parse_vdm_type22_addressed() {
+        match NmeaParser::new().parse_sentence("!AIVDM,1,1,,A,F02R5Pj2N2PIefkp03u?etPE0000,0*3D") {
+            Ok(ps) => match ps {
+                ParsedMessage::ChannelManagement(cm) => {
+                    assert_eq!(cm.mmsi, 2655619);
+                    assert_eq!(cm.channel_a, 2087);
+                    assert_eq!(cm.channel_b, 2088);
+                    assert_eq!(cm.tx_rx_mode(), TxRxMode::TxARxARxB);
+                    assert!(cm.power);
+                    assert!(cm.addressed);
+                    assert_eq!(cm.ne_lat, None);
+                    assert_eq!(cm.sw_lon, None);
+                    assert_eq!(cm.dest1_mmsi, Some(230123456));
+                    assert_eq!(cm.dest2_mmsi, Some(265547250));
+                    assert_eq!(cm.destinations(), vec![230123456, 265547250]);
+                    assert!(!cm.channel_a_band);
+                    assert!(cm.channel_b_band);
+                    assert_eq!(cm.transitional_zone_size(), 3);
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
 }
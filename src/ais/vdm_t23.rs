@@ -67,6 +67,26 @@ pub struct GroupAssignmentCommand {
     pub quiet: Option<u8>,
 }
 
+impl GroupAssignmentCommand {
+    /// Return the transmit and receive mode assigned to the stations.
+    pub fn tx_rx_mode(&self) -> TxRxMode {
+        TxRxMode::new(self.txrx)
+    }
+
+    /// Return true if the given position is inside the assigned region.
+    pub fn region_contains(&self, latitude: f64, longitude: f64) -> bool {
+        match (self.ne_lat, self.ne_lon, self.sw_lat, self.sw_lon) {
+            (Some(ne_lat), Some(ne_lon), Some(sw_lat), Some(sw_lon)) => {
+                latitude >= sw_lat
+                    && latitude <= ne_lat
+                    && longitude >= sw_lon
+                    && longitude <= ne_lon
+            }
+            _ => false,
+        }
+    }
+}
+
 /// Station Type (for message type 23).
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum StationType {
@@ -225,6 +245,23 @@ impl StationInterval {
     }
 }
 
+impl StationInterval {
+    /// Return the reporting interval in seconds, or `None` if the interval isn't a fixed time.
+    pub fn seconds(&self) -> Option<u32> {
+        match self {
+            StationInterval::Time10min => Some(600),
+            StationInterval::Time6min => Some(360),
+            StationInterval::Time3min => Some(180),
+            StationInterval::Time1min => Some(60),
+            StationInterval::Time30sec => Some(30),
+            StationInterval::Time15sec => Some(15),
+            StationInterval::Time10sec => Some(10),
+            StationInterval::Time5sec => Some(5),
+            _ => None,
+        }
+    }
+}
+
 impl Default for StationInterval {
     fn default() -> Self {
         StationInterval::Autonomous
@@ -256,7 +293,7 @@ pub(crate) fn handle(
                 if val < 4 {
                     val
                 } else {
-                    return Err(format!("Tx/Tr mode field out of range: {}", val).into());
+                    return Err(format!("Tx/Rx mode field out of range: {}", val).into());
                 }
             },
             interval: StationInterval::new(pick_u64(bv, 146, 4) as u8)?,
@@ -299,6 +336,10 @@ mod test {
                         assert_eq!(gac.txrx, 0);
                         assert_eq!(gac.interval, StationInterval::NextShorterReportingInverval);
                         assert_eq!(gac.quiet, None);
+                        assert_eq!(gac.tx_rx_mode(), TxRxMode::TxATxBRxARxB);
+                        assert_eq!(gac.interval.seconds(), None);
+                        assert!(gac.region_contains(50.9, 2.0));
+                        assert!(!gac.region_contains(52.0, 2.0));
                     }
                     ParsedMessage::Incomplete => {
                         assert!(false);
@@ -313,4 +354,32 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_parse_vdm_type23_quiet_time() {
+        match NmeaParser::new().parse_sentence("!AIVDM,1,1,,A,G02<HT@0BhtFWv?QnG29@000U`0,2*31") {
+            Ok(ps) => match ps {
+                ParsedMessage::GroupAssignmentCommand(gac) => {
+                    assert_eq!(gac.mmsi, 2300049);
+                    assert::close(gac.ne_lat.unwrap_or(0.0), 51.5, 0.001);
+                    assert::close(gac.ne_lon.unwrap_or(0.0), 0.5, 0.001);
+                    assert::close(gac.sw_lat.unwrap_or(0.0), 50.5, 0.001);
+                    assert::close(gac.sw_lon.unwrap_or(0.0), -1.5, 0.001);
+                    assert_eq!(gac.station_type, StationType::AllTypesOfClassBMobile);
+                    assert_eq!(gac.ship_type, ShipType::PleasureCraft);
+                    assert_eq!(gac.tx_rx_mode(), TxRxMode::TxBRxARxB);
+                    assert_eq!(gac.interval, StationInterval::Time30sec);
+                    assert_eq!(gac.interval.seconds(), Some(30));
+                    assert_eq!(gac.quiet, Some(10));
+                    assert!(gac.region_contains(51.0, -1.0));
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
 }
@@ -36,31 +36,27 @@ pub(crate) fn handle(
         }
     };
 
+    // Auxiliary craft (MMSI 98XXXXXXX) send the mothership MMSI in place of the dimensions
+    let mmsi = pick_u64(bv, 8, 30) as u32;
+    let auxiliary = part_b && is_auxiliary_craft(mmsi);
+
     // Pick the fields
     let vsd = VesselStaticData {
         own_vessel,
         ais_type: AisClass::ClassB,
-        mmsi: pick_u64(bv, 8, 30) as u32,
+        mmsi,
         ais_version_indicator: 0,
         imo_number: None,
         call_sign: {
             if part_b {
-                let raw = pick_string(bv, 90, 7);
-                match raw.as_str() {
-                    "" => None,
-                    _ => Some(raw),
-                }
+                store.pick_text(bv, 90, 7, TextField::CallSign)
             } else {
                 None
             }
         },
         name: {
             if part_a {
-                let raw = pick_string(bv, 40, 120);
-                match raw.as_str() {
-                    "" => None,
-                    _ => Some(raw),
-                }
+                store.pick_text(bv, 40, 120, TextField::Name)
             } else {
                 None
             }
@@ -101,28 +97,28 @@ pub(crate) fn handle(
             }
         },
         dimension_to_bow: {
-            if part_b {
+            if part_b && !auxiliary {
                 Some(pick_u64(bv, 132, 9) as u16)
             } else {
                 None
             }
         },
         dimension_to_stern: {
-            if part_b {
+            if part_b && !auxiliary {
                 Some(pick_u64(bv, 141, 9) as u16)
             } else {
                 None
             }
         },
         dimension_to_port: {
-            if part_b {
+            if part_b && !auxiliary {
                 Some(pick_u64(bv, 150, 6) as u16)
             } else {
                 None
             }
         },
         dimension_to_starboard: {
-            if part_b {
+            if part_b && !auxiliary {
                 Some(pick_u64(bv, 156, 6) as u16)
             } else {
                 None
@@ -133,7 +129,7 @@ pub(crate) fn handle(
         draught10: None,
         destination: None,
         mothership_mmsi: {
-            if part_b {
+            if auxiliary {
                 Some(pick_u64(bv, 132, 30) as u32)
             } else {
                 None
@@ -141,19 +137,28 @@ pub(crate) fn handle(
         },
     };
 
-    // Check whether we can return a complete or incomplete response
-    if let Some(vsd2) = store.pull_vsd(vsd.mmsi) {
-        Ok(ParsedMessage::VesselStaticData(vsd.merge(&vsd2)?))
-    } else {
-        store.push_vsd(vsd.mmsi, vsd);
-        Ok(ParsedMessage::Incomplete)
+    // Merging isn't possible when the text fields are borrowed from a decode buffer
+    if !store.merge_type24 || store.text_buffer.is_some() {
+        return Ok(ParsedMessage::VesselStaticData(vsd));
+    }
+
+    // Check whether we can return a complete or incomplete response. Only part B carries the
+    // vendor ID, so a repeated part replaces the saved one instead of being merged with it.
+    match store.pull_vsd(vsd.mmsi) {
+        Some(vsd2) if vsd2.equipment_vendor_id.is_some() != part_b => {
+            Ok(ParsedMessage::VesselStaticData(vsd.merge(&vsd2)?))
+        }
+        _ => {
+            store.push_vsd(vsd.mmsi, vsd);
+            Ok(ParsedMessage::Incomplete)
+        }
     }
 }
 
 impl VesselStaticData {
     /// Merge two data structures together. This is used to combine part A and B
     /// of class B AIVDM type 24 messages.
-    fn merge(&self, other: &VesselStaticData) -> Result<VesselStaticData, String> {
+    pub fn merge(&self, other: &VesselStaticData) -> Result<VesselStaticData, String> {
         if self.ais_type != other.ais_type {
             Err(format!(
                 "Mismatching AIS types: {} != {}",
@@ -223,6 +228,12 @@ impl VesselStaticData {
     }
 }
 
+/// Return true if the MMSI belongs to an auxiliary craft associated with a parent ship
+/// (98XXXXXXX).
+fn is_auxiliary_craft(mmsi: u32) -> bool {
+    (980_000_000..=989_999_999).contains(&mmsi)
+}
+
 /// Choose the argument which is Some. If both are Some, choose the first one.
 fn choose_some<T>(a: Option<T>, b: Option<T>) -> Option<T> {
     if a.is_some() {
@@ -289,10 +300,7 @@ mod test {
                         assert_eq!(vsd.cargo_type, CargoType::Undefined);
 
                         assert_eq!(vsd.equipment_vendor_id, Some("1D0".into()));
-                        //                                assert_eq!(vsd.equipment_model, None);
-                        //                                assert_eq!(vsd.equipment_serial_number, None);
-                        //                                assert_eq!(vsd.mothership_mmsi, None);
-                        // TODO: find the right hand side of the variables above
+                        assert_eq!(vsd.mothership_mmsi, None);
 
                         assert_eq!(vsd.dimension_to_bow, Some(0));
                         assert_eq!(vsd.dimension_to_stern, Some(15));
@@ -317,4 +325,76 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_parse_vdm_type24_auxiliary_craft() {
+        let mut p = NmeaParser::new();
+        assert_eq!(
+            p.parse_sentence("!AIVDM,1,1,,A,H>WikQi@Dp@E800000000000000,2*76"),
+            Ok(ParsedMessage::Incomplete)
+        );
+        match p.parse_sentence("!AIVDM,1,1,,A,H>WikQlj123830q?8ijkl0=enO00,0*62") {
+            Ok(ps) => match ps {
+                ParsedMessage::VesselStaticData(vsd) => {
+                    assert_eq!(vsd.mmsi, 981234567);
+                    assert_eq!(vsd.name, Some("TENDER".into()));
+                    assert_eq!(vsd.call_sign, Some("OH1234".into()));
+                    assert_eq!(vsd.mothership_mmsi, Some(230123456));
+                    assert_eq!(vsd.ship_and_cargo_type().description(), "Pilot vessel");
+                    assert_eq!(vsd.dimension_to_bow, None);
+                    assert_eq!(vsd.dimension_to_stern, None);
+                    assert_eq!(vsd.dimension_to_port, None);
+                    assert_eq!(vsd.dimension_to_starboard, None);
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_vdm_type24_merging() {
+        let part_a = "!AIVDM,1,1,,A,H>WikQi@Dp@E800000000000000,2*76";
+        let part_b = "!AIVDM,1,1,,A,H>WikQlj123830q?8ijkl0=enO00,0*62";
+
+        // Repeated part A doesn't complete the message
+        let mut p = NmeaParser::new();
+        assert_eq!(p.parse_sentence(part_a), Ok(ParsedMessage::Incomplete));
+        assert_eq!(p.parse_sentence(part_a), Ok(ParsedMessage::Incomplete));
+        match p.parse_sentence(part_b) {
+            Ok(ParsedMessage::VesselStaticData(vsd)) => {
+                assert_eq!(vsd.name, Some("TENDER".into()));
+                assert_eq!(vsd.call_sign, Some("OH1234".into()));
+            }
+            r => {
+                panic!("Unexpected result: {:?}", r);
+            }
+        }
+
+        // Parts are returned separately when merging is disabled
+        let mut p = NmeaParser::new();
+        p.set_type24_merging(false);
+        let vsd_a = match p.parse_sentence(part_a) {
+            Ok(ParsedMessage::VesselStaticData(vsd)) => vsd,
+            r => {
+                panic!("Unexpected result: {:?}", r);
+            }
+        };
+        assert_eq!(vsd_a.name, Some("TENDER".into()));
+        assert_eq!(vsd_a.call_sign, None);
+        let vsd_b = match p.parse_sentence(part_b) {
+            Ok(ParsedMessage::VesselStaticData(vsd)) => vsd,
+            r => {
+                panic!("Unexpected result: {:?}", r);
+            }
+        };
+        assert_eq!(vsd_b.name, None);
+        let vsd = vsd_a.merge(&vsd_b).unwrap_or_default();
+        assert_eq!(vsd.name, Some("TENDER".into()));
+        assert_eq!(vsd.mothership_mmsi, Some(230123456));
+    }
 }
@@ -30,21 +30,38 @@ pub struct SingleSlotBinaryMessage {
     /// User ID (30 bits)
     pub mmsi: u32,
 
+    /// True if the message is addressed to a specific station, false if broadcast.
+    pub addressed: bool,
+
+    /// True if the data begins with an application identifier.
+    pub structured: bool,
+
     /// When 'addressed' flag is on this field contains the parsed destination MMSI.
     pub dest_mmsi: Option<u32>,
 
-    /// When 'addressed' flag is off and 'structured' flag on this field contains
-    /// application ID which consists of 10-bit DAC and 6-bit FID as in message types 6 and 8.
+    /// When 'structured' flag is on this field contains application ID which consists of
+    /// 10-bit DAC and 6-bit FID as in message types 6 and 8.
     pub app_id: Option<u16>,
 
     /// Data field of length 0-128 bits.
     pub data: BitVec,
 }
 
+impl SingleSlotBinaryMessage {
+    /// Return the designated area code (DAC) of a structured message.
+    pub fn dac(&self) -> Option<u16> {
+        self.app_id.map(|id| id >> 6)
+    }
+
+    /// Return the function identifier (FI) of a structured message.
+    pub fn fi(&self) -> Option<u8> {
+        self.app_id.map(|id| (id & 0x3f) as u8)
+    }
+}
+
 // -------------------------------------------------------------------------------------------------
 
 /// AIS VDM/VDO type 25: Single Slot Binary Message
-#[allow(clippy::collapsible_if)]
 pub(crate) fn handle(
     bv: &BitVec,
     station: Station,
@@ -52,12 +69,20 @@ pub(crate) fn handle(
 ) -> Result<ParsedMessage, ParseError> {
     let addressed = pick_u64(bv, 38, 1) != 0;
     let structured = pick_u64(bv, 39, 1) != 0;
+    let app_id_start = if addressed { 70 } else { 40 };
+    let data_start = if structured {
+        app_id_start + 16
+    } else {
+        app_id_start
+    };
 
     Ok(ParsedMessage::SingleSlotBinaryMessage(
         SingleSlotBinaryMessage {
             own_vessel: { own_vessel },
             station: { station },
             mmsi: { pick_u64(bv, 8, 30) as u32 },
+            addressed,
+            structured,
             dest_mmsi: {
                 if addressed {
                     Some(pick_u64(bv, 40, 30) as u32)
@@ -66,23 +91,13 @@ pub(crate) fn handle(
                 }
             },
             app_id: {
-                if addressed {
-                    None
-                } else if structured {
-                    Some(pick_u64(bv, 70, 16) as u16)
+                if structured {
+                    Some(pick_u64(bv, app_id_start, 16) as u16)
                 } else {
                     None
                 }
             },
-            data: {
-                if addressed {
-                    BitVec::from_bitslice(&bv[70..max(70, bv.len())])
-                } else if structured {
-                    BitVec::from_bitslice(&bv[86..max(86, bv.len())])
-                } else {
-                    BitVec::from_bitslice(&bv[40..max(40, bv.len())])
-                }
-            },
+            data: { BitVec::from_bitslice(&bv[min(data_start, bv.len())..]) },
         },
     ))
 }
@@ -104,8 +119,11 @@ mod test {
                     // The expected result
                     ParsedMessage::SingleSlotBinaryMessage(ssbm) => {
                         assert_eq!(ssbm.mmsi, 440006460);
+                        assert!(ssbm.addressed);
+                        assert!(!ssbm.structured);
                         assert_eq!(ssbm.dest_mmsi, Some(134218384));
                         assert_eq!(ssbm.app_id, None);
+                        assert_eq!(ssbm.data.len(), 168 - 70);
                     }
                     ParsedMessage::Incomplete => {
                         assert!(false);
@@ -128,7 +146,10 @@ mod test {
                     ParsedMessage::SingleSlotBinaryMessage(ssbm) => {
                         assert_eq!(ssbm.mmsi, 563648328);
                         assert_eq!(ssbm.dest_mmsi, None);
-                        assert_eq!(ssbm.app_id, Some(16424));
+                        assert_eq!(ssbm.app_id, Some(134));
+                        assert_eq!(ssbm.dac(), Some(2));
+                        assert_eq!(ssbm.fi(), Some(6));
+                        assert_eq!(ssbm.data.len(), 168 - 56);
                     }
                     ParsedMessage::Incomplete => {
                         assert!(false);
@@ -30,11 +30,17 @@ pub struct MultipleSlotBinaryMessage {
     /// User ID (30 bits)
     pub mmsi: u32,
 
+    /// True if the message is addressed to a specific station, false if broadcast.
+    pub addressed: bool,
+
+    /// True if the data begins with an application identifier.
+    pub structured: bool,
+
     /// When 'addressed' flag is on this field contains the parsed destination MMSI.
     pub dest_mmsi: Option<u32>,
 
-    /// When 'addressed' flag is off and 'structured' flag on this field contains
-    /// application ID which consists of 10-bit DAC and 6-bit FID as in message types 6 and 8.
+    /// When 'structured' flag is on this field contains application ID which consists of
+    /// 10-bit DAC and 6-bit FID as in message types 6 and 8.
     pub app_id: Option<u16>,
 
     /// Data field of length 0-1004 bits.
@@ -42,12 +48,26 @@ pub struct MultipleSlotBinaryMessage {
 
     /// Radio status
     pub radio: u32,
+
+    /// Decoded communication state
+    pub communication_state: Option<CommunicationState>,
+}
+
+impl MultipleSlotBinaryMessage {
+    /// Return the designated area code (DAC) of a structured message.
+    pub fn dac(&self) -> Option<u16> {
+        self.app_id.map(|id| id >> 6)
+    }
+
+    /// Return the function identifier (FI) of a structured message.
+    pub fn fi(&self) -> Option<u8> {
+        self.app_id.map(|id| (id & 0x3f) as u8)
+    }
 }
 
 // -------------------------------------------------------------------------------------------------
 
 /// AIS VDM/VDO type 26: Multiple Slot Binary Message
-#[allow(clippy::collapsible_if)]
 pub(crate) fn handle(
     bv: &BitVec,
     station: Station,
@@ -55,12 +75,21 @@ pub(crate) fn handle(
 ) -> Result<ParsedMessage, ParseError> {
     let addressed = pick_u64(bv, 38, 1) != 0;
     let structured = pick_u64(bv, 39, 1) != 0;
+    let app_id_start = if addressed { 70 } else { 40 };
+    let data_start = if structured {
+        app_id_start + 16
+    } else {
+        app_id_start
+    };
+    let radio_start = bv.len().saturating_sub(20);
 
     Ok(ParsedMessage::MultipleSlotBinaryMessage(
         MultipleSlotBinaryMessage {
             own_vessel: { own_vessel },
             station: { station },
             mmsi: { pick_u64(bv, 8, 30) as u32 },
+            addressed,
+            structured,
             dest_mmsi: {
                 if addressed {
                     Some(pick_u64(bv, 40, 30) as u32)
@@ -69,24 +98,20 @@ pub(crate) fn handle(
                 }
             },
             app_id: {
-                if addressed {
-                    None
-                } else if structured {
-                    Some(pick_u64(bv, 70, 16) as u16)
+                if structured {
+                    Some(pick_u64(bv, app_id_start, 16) as u16)
                 } else {
                     None
                 }
             },
-            data: {
-                if addressed {
-                    BitVec::from_bitslice(&bv[70..max(70, bv.len() - 20)])
-                } else if structured {
-                    BitVec::from_bitslice(&bv[86..max(86, bv.len() - 20)])
-                } else {
-                    BitVec::from_bitslice(&bv[40..max(40, bv.len() - 20)])
-                }
+            data: { BitVec::from_bitslice(&bv[min(data_start, radio_start)..radio_start]) },
+            radio: { pick_u64(bv, radio_start, 20) as u32 },
+            communication_state: {
+                Some(CommunicationState::new(
+                    pick_u64(bv, radio_start, 1) != 0,
+                    pick_u64(bv, radio_start + 1, 19) as u32,
+                ))
             },
-            radio: { pick_u64(bv, bv.len() - 20, 20) as u32 },
         },
     ))
 }
@@ -109,7 +134,7 @@ mod test {
                     ParsedMessage::MultipleSlotBinaryMessage(msbm) => {
                         assert_eq!(msbm.mmsi, 137920605);
                         assert_eq!(msbm.dest_mmsi, Some(838351848));
-                        assert_eq!(msbm.app_id, None);
+                        assert_eq!(msbm.app_id, Some(23587));
                     }
                     ParsedMessage::Incomplete => {
                         assert!(false);
@@ -132,7 +157,12 @@ mod test {
                     ParsedMessage::MultipleSlotBinaryMessage(msbm) => {
                         assert_eq!(msbm.mmsi, 285913259);
                         assert_eq!(msbm.dest_mmsi, None);
-                        assert_eq!(msbm.app_id, Some(16254));
+                        assert!(!msbm.addressed);
+                        assert!(msbm.structured);
+                        assert_eq!(msbm.app_id, Some(21398));
+                        assert_eq!(msbm.dac(), Some(334));
+                        assert_eq!(msbm.fi(), Some(22));
+                        assert_eq!(msbm.data.len(), 96 - 56 - 20);
                     }
                     ParsedMessage::Incomplete => {
                         assert!(false);
@@ -147,4 +177,44 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_parse_vdm_type26_multipart() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence(
+            "!AIVDM,2,1,3,B,J3LBhD<rEGnh0GrFUaJFUaJFUaJFUaJFUaJFUaJFUaJFUaJFUaJFUaJFUaJF,0*7F",
+        ) {
+            Ok(ps) => match ps {
+                ParsedMessage::Incomplete => {}
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+
+        match p.parse_sentence("!AIVDM,2,2,3,B,UaJFUaJDTJ:,0*30") {
+            Ok(ps) => match ps {
+                ParsedMessage::MultipleSlotBinaryMessage(msbm) => {
+                    assert_eq!(msbm.mmsi, 230994000);
+                    assert!(msbm.addressed);
+                    assert!(msbm.structured);
+                    assert_eq!(msbm.dest_mmsi, Some(244670316));
+                    assert_eq!(msbm.dac(), Some(1));
+                    assert_eq!(msbm.fi(), Some(31));
+                    assert_eq!(msbm.data.len(), 320);
+                    assert_eq!(pick_u64(&msbm.data, 0, 8), 0xA5);
+                    assert_eq!(msbm.radio, 0x2468A);
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
 }
@@ -31,7 +31,7 @@ pub(crate) fn handle(
         rot: { None },
         rot_direction: { None },
         sog_knots: {
-            let sog_raw = pick_u64(bv, 62, 6);
+            let sog_raw = pick_u64(bv, 79, 6);
             if sog_raw != 63 {
                 Some(sog_raw as f64)
             } else {
@@ -56,9 +56,9 @@ pub(crate) fn handle(
             }
         },
         cog: {
-            let cog_raw = pick_u64(bv, 62, 17);
-            if cog_raw != 91000 {
-                Some(cog_raw as f64 * 0.1)
+            let cog_raw = pick_u64(bv, 85, 9);
+            if cog_raw != 511 {
+                Some(cog_raw as f64)
             } else {
                 None
             }
@@ -66,8 +66,15 @@ pub(crate) fn handle(
         heading_true: None,
         timestamp_seconds: 0,
         positioning_system_meta: None,
-        current_gnss_position: Some(pick_u64(bv, 62, 1) == 0),
+        current_gnss_position: Some(pick_u64(bv, 94, 1) == 0),
         special_manoeuvre: None,
+        dimension_to_bow: None,
+        dimension_to_stern: None,
+        dimension_to_port: None,
+        dimension_to_starboard: None,
+        regional: None,
+        regional2: None,
+        spare: None,
         raim_flag: pick_u64(bv, 39, 1) != 0,
         class_b_unit_flag: None,
         class_b_display: None,
@@ -77,6 +84,7 @@ pub(crate) fn handle(
         class_b_mode_flag: None,
         class_b_css_flag: None,
         radio_status: None,
+        communication_state: None,
     }))
 }
 
@@ -98,11 +106,11 @@ mod test {
                         assert_eq!(vdd.nav_status, NavigationStatus::NotUnderCommand);
                         assert_eq!(vdd.rot, None);
                         assert_eq!(vdd.rot_direction, None);
-                        assert_eq!(vdd.sog_knots, Some(1.0));
+                        assert_eq!(vdd.sog_knots, Some(57.0));
                         assert!(!vdd.high_position_accuracy);
                         assert::close(vdd.latitude.unwrap_or(0.0), 4.8, 0.1);
                         assert::close(vdd.longitude.unwrap_or(0.0), 137.0, 0.1);
-                        assert::close(vdd.cog.unwrap_or(0.0), 290.0, 1.0);
+                        assert_eq!(vdd.cog, Some(167.0));
                         assert_eq!(vdd.timestamp_seconds, 0);
                         assert_eq!(vdd.current_gnss_position, Some(true));
                         assert!(!vdd.raim_flag);
@@ -120,4 +128,29 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_parse_vdm_type27_not_available() {
+        match NmeaParser::new().parse_sentence("!AIVDM,1,1,,A,K5Dlg`=M5c0afOwv,0*28") {
+            Ok(ps) => match ps {
+                ParsedMessage::VesselDynamicData(vdd) => {
+                    assert_eq!(vdd.mmsi, 357380000);
+                    assert_eq!(vdd.nav_status, NavigationStatus::Moored);
+                    assert!(vdd.high_position_accuracy);
+                    assert!(vdd.raim_flag);
+                    assert::close(vdd.latitude.unwrap_or(0.0), 8.9, 0.002);
+                    assert::close(vdd.longitude.unwrap_or(0.0), -79.5, 0.002);
+                    assert_eq!(vdd.sog_knots, None);
+                    assert_eq!(vdd.cog, None);
+                    assert_eq!(vdd.current_gnss_position, Some(false));
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
 }
@@ -30,7 +30,7 @@ pub struct BaseStationReport {
     /// User ID (30 bits)
     pub mmsi: u32,
 
-    /// Timestamp
+    /// UTC timestamp, `None` if not available
     pub timestamp: Option<DateTime<Utc>>,
 
     /// Position accuracy: true = high (<= 10 m), false = low (> 10 m)
@@ -42,7 +42,7 @@ pub struct BaseStationReport {
     /// Longitude
     pub longitude: Option<f64>,
 
-    // Type of electronic position fixing device.
+    /// Type of electronic position fixing device.
     pub position_fix_type: Option<PositionFixType>,
 
     /// Riverine And Inland Navigation systems blue sign:
@@ -54,6 +54,9 @@ pub struct BaseStationReport {
     /// Diagnostic information for the radio system.
     /// <https://www.itu.int/dms_pubrec/itu-r/rec/m/R-REC-M.1371-1-200108-S!!PDF-E.pdf>
     pub radio_status: u32,
+
+    /// Decoded SOTDMA communication state
+    pub communication_state: Option<CommunicationState>,
 }
 
 impl LatLon for BaseStationReport {
@@ -74,20 +77,22 @@ pub(crate) fn handle(
     station: Station,
     own_vessel: bool,
 ) -> Result<ParsedMessage, ParseError> {
-    Ok(ParsedMessage::BaseStationReport(BaseStationReport {
+    Ok(ParsedMessage::BaseStationReport(parse_base_station_report(
+        bv, station, own_vessel,
+    )?))
+}
+
+/// Parse the layout shared by type 4 (base station report) and type 11 (UTC/date response).
+pub(crate) fn parse_base_station_report(
+    bv: &BitVec,
+    station: Station,
+    own_vessel: bool,
+) -> Result<BaseStationReport, ParseError> {
+    Ok(BaseStationReport {
         own_vessel: { own_vessel },
         station: { station },
         mmsi: { pick_u64(bv, 8, 30) as u32 },
-        timestamp: {
-            Some(parse_ymdhs(
-                pick_u64(bv, 38, 14) as i32,
-                pick_u64(bv, 52, 4) as u32,
-                pick_u64(bv, 56, 5) as u32,
-                pick_u64(bv, 61, 5) as u32,
-                pick_u64(bv, 66, 6) as u32,
-                pick_u64(bv, 72, 6) as u32,
-            )?)
-        },
+        timestamp: { pick_report_timestamp(bv)? },
         high_position_accuracy: { pick_u64(bv, 78, 1) != 0 },
         latitude: {
             let lat_raw = pick_i64(bv, 107, 27) as i32;
@@ -114,7 +119,24 @@ pub(crate) fn handle(
         },
         raim_flag: { pick_u64(bv, 148, 1) != 0 },
         radio_status: { pick_u64(bv, 149, 19) as u32 },
-    }))
+        communication_state: { Some(CommunicationState::sotdma(pick_u64(bv, 149, 19) as u32)) },
+    })
+}
+
+/// Pick the UTC date and time fields of type 4 and 11 messages. `None` is returned when any of
+/// the fields has its "not available" value (year 0, month 0, day 0, hour 24, minute 60 or
+/// second 60).
+pub(crate) fn pick_report_timestamp(bv: &BitVec) -> Result<Option<DateTime<Utc>>, ParseError> {
+    let year = pick_u64(bv, 38, 14) as i32;
+    let month = pick_u64(bv, 52, 4) as u32;
+    let day = pick_u64(bv, 56, 5) as u32;
+    let hour = pick_u64(bv, 61, 5) as u32;
+    let minute = pick_u64(bv, 66, 6) as u32;
+    let second = pick_u64(bv, 72, 6) as u32;
+    if year == 0 || month == 0 || day == 0 || hour >= 24 || minute >= 60 || second >= 60 {
+        return Ok(None);
+    }
+    Ok(Some(parse_ymdhs(year, month, day, hour, minute, second)?))
 }
 
 // -------------------------------------------------------------------------------------------------
@@ -142,6 +164,14 @@ mod test {
                         assert_eq!(bsr.position_fix_type, Some(PositionFixType::Surveyed));
                         assert!(!bsr.raim_flag);
                         assert_eq!(bsr.radio_status, 67039);
+                        assert_eq!(
+                            bsr.communication_state,
+                            Some(CommunicationState::Sotdma(SotdmaState {
+                                sync_state: SyncState::UtcDirect,
+                                slot_timeout: 4,
+                                sub_message: SotdmaSubMessage::SlotNumber(1503),
+                            }))
+                        );
                     }
                     ParsedMessage::Incomplete => {
                         assert!(false);
@@ -156,4 +186,26 @@ mod test {
             }
         }
     }
+    #[test]
+    fn test_parse_vdm_type4_not_available() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("!AIVDM,1,1,,A,402<Hnh000Htt<tSF0l4Q@002000,0*3C") {
+            Ok(ps) => match ps {
+                ParsedMessage::BaseStationReport(bsr) => {
+                    assert_eq!(bsr.mmsi, 2300123);
+                    assert_eq!(bsr.timestamp, None);
+                    assert_eq!(bsr.latitude, None);
+                    assert_eq!(bsr.longitude, None);
+                    assert_eq!(bsr.position_fix_type, None);
+                    assert!(bsr.raim_flag);
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
 }
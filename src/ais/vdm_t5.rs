@@ -19,11 +19,12 @@ use super::*;
 pub(crate) fn handle(
     bv: &BitVec,
     _station: Station,
+    store: &mut NmeaParser,
     own_vessel: bool,
 ) -> Result<ParsedMessage, ParseError> {
     Ok(ParsedMessage::VesselStaticData(VesselStaticData {
         own_vessel,
-        ais_type: AisClass::ClassB,
+        ais_type: AisClass::ClassA,
         mmsi: pick_u64(bv, 8, 30) as u32,
         ais_version_indicator: pick_u64(bv, 38, 2) as u8,
         imo_number: {
@@ -33,20 +34,8 @@ pub(crate) fn handle(
                 _ => Some(raw),
             }
         },
-        call_sign: {
-            let raw = pick_string(bv, 70, 7);
-            match raw.as_str() {
-                "" => None,
-                _ => Some(raw),
-            }
-        },
-        name: {
-            let raw = pick_string(bv, 112, 20);
-            match raw.as_str() {
-                "" => None,
-                _ => Some(raw),
-            }
-        },
+        call_sign: store.pick_text(bv, 70, 7, TextField::CallSign),
+        name: store.pick_text(bv, 112, 20, TextField::Name),
         ship_type: { ShipType::new(pick_u64(bv, 232, 8) as u8) },
         cargo_type: { CargoType::new(pick_u64(bv, 232, 8) as u8) },
         equipment_vendor_id: {
@@ -71,13 +60,7 @@ pub(crate) fn handle(
         },
         eta: pick_eta(bv, 274)?,
         draught10: Some(pick_u64(bv, 294, 8) as u8),
-        destination: {
-            let raw = pick_string(bv, 302, 20);
-            match raw.as_str() {
-                "" => None,
-                _ => Some(raw),
-            }
-        },
+        destination: store.pick_text(bv, 302, 20, TextField::Destination),
         mothership_mmsi: { None },
     }))
 }
@@ -110,6 +93,7 @@ mod test {
                 match ps {
                     // The expected result
                     ParsedMessage::VesselStaticData(vsd) => {
+                        assert_eq!(vsd.ais_type, AisClass::ClassA);
                         assert_eq!(vsd.mmsi, 351759000);
                         assert_eq!(vsd.ais_version_indicator, 0);
                         assert_eq!(vsd.imo_number, Some(9134270));
@@ -124,10 +108,18 @@ mod test {
                         assert_eq!(vsd.name, Some("EVER DIADEM".into()));
                         assert_eq!(vsd.ship_type, ShipType::Cargo);
                         assert_eq!(vsd.cargo_type, CargoType::Undefined);
+                        assert_eq!(vsd.ship_and_cargo_type(), ShipAndCargoType(70));
+                        assert_eq!(
+                            vsd.ship_and_cargo_type().to_string(),
+                            "Cargo, all ships of this type"
+                        );
                         assert_eq!(vsd.dimension_to_bow, Some(225));
                         assert_eq!(vsd.dimension_to_stern, Some(70));
                         assert_eq!(vsd.dimension_to_port, Some(1));
                         assert_eq!(vsd.dimension_to_starboard, Some(31));
+                        assert_eq!(vsd.length(), Some(295));
+                        assert_eq!(vsd.breadth(), Some(32));
+                        assert_eq!(vsd.reference_point(), Some((-77.5, -15.0)));
                         assert_eq!(vsd.position_fix_type, Some(PositionFixType::GPS));
                         assert_eq!(vsd.eta, {
                             vsd.eta.map(|dt| {
@@ -136,6 +128,14 @@ mod test {
                                     .unwrap()
                             })
                         });
+                        assert_eq!(
+                            vsd.resolve_eta(Utc.with_ymd_and_hms(2026, 10, 16, 0, 0, 0).unwrap()),
+                            Utc.with_ymd_and_hms(2026, 5, 15, 14, 0, 30).single()
+                        );
+                        assert_eq!(
+                            vsd.resolve_eta(Utc.with_ymd_and_hms(2026, 12, 1, 0, 0, 0).unwrap()),
+                            Utc.with_ymd_and_hms(2027, 5, 15, 14, 0, 30).single()
+                        );
                         assert_eq!(vsd.draught10, Some(122));
                         assert_eq!(vsd.destination, Some("NEW YORK".into()));
                     }
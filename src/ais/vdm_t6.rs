@@ -44,7 +44,12 @@ pub struct BinaryAddressedMessage {
 
     /// Functional ID, FID (6 bits)
     pub fid: u8,
-    // TODO: data (depending on DAC and FID
+
+    /// Application data bits following the FID field.
+    pub data: BitVec,
+
+    /// Decoded application data when the DAC and FID combination is known.
+    pub application_data: Option<ApplicationData>,
 }
 
 impl LatLon for BinaryAddressedMessage {
@@ -59,13 +64,16 @@ impl LatLon for BinaryAddressedMessage {
 
 // -------------------------------------------------------------------------------------------------
 
-/// AIS VDM/VDO type 6: Binary Addressed Message. Implementation of the 920-bit data field is
-/// unimplemented currently.
+/// AIS VDM/VDO type 6: Binary Addressed Message
 pub(crate) fn handle(
     bv: &BitVec,
     station: Station,
     own_vessel: bool,
+    decoders: &asm::AsmDecoders,
 ) -> Result<ParsedMessage, ParseError> {
+    let dac = pick_u64(bv, 72, 10) as u16;
+    let fid = pick_u64(bv, 82, 6) as u8;
+    let data = BitVec::from_bitslice(&bv[min(88, bv.len())..]);
     Ok(ParsedMessage::BinaryAddressedMessage(
         BinaryAddressedMessage {
             own_vessel: { own_vessel },
@@ -74,8 +82,10 @@ pub(crate) fn handle(
             sequence_number: { pick_u64(bv, 38, 2) as u8 },
             destination_mmsi: { pick_u64(bv, 40, 30) as u32 },
             retransmit_flag: { pick_u64(bv, 70, 1) != 0 },
-            dac: { pick_u64(bv, 72, 10) as u16 },
-            fid: { pick_u64(bv, 82, 6) as u8 }, // TODO: data (depending on DAC and FID
+            dac,
+            fid,
+            application_data: { asm::decode(dac, fid, true, &data, decoders)? },
+            data,
         },
     ))
 }
@@ -100,7 +110,8 @@ mod test {
                         assert!(!bam.retransmit_flag);
                         assert_eq!(bam.dac, 669);
                         assert_eq!(bam.fid, 11);
-                        // TODO: check data
+                        assert_eq!(bam.data.len(), 48);
+                        assert_eq!(bam.application_data, None);
                     }
                     ParsedMessage::Incomplete => {
                         assert!(false);
@@ -115,4 +126,36 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_parse_vdm_type6_text_telegram() {
+        match NmeaParser::new()
+            .parse_sentence("!AIVDM,1,1,,A,63KMWfTr=1k`0422a0ThuB08t58@TpN37C30,2*76")
+        {
+            Ok(ps) => match ps {
+                ParsedMessage::BinaryAddressedMessage(bam) => {
+                    assert_eq!(bam.mmsi, 230123450);
+                    assert_eq!(bam.destination_mmsi, 244123450);
+                    assert_eq!(bam.dac, 1);
+                    assert_eq!(bam.fid, 0);
+                    match bam.application_data {
+                        Some(ApplicationData::TextTelegram(tt)) => {
+                            assert!(tt.acknowledge_required);
+                            assert_eq!(tt.sequence_number, 42);
+                            assert_eq!(tt.text, "PILOT BOARDING 1400");
+                        }
+                        _ => {
+                            panic!("Unexpected application data: {:?}", bam.application_data);
+                        }
+                    }
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
 }
@@ -16,6 +16,54 @@ limitations under the License.
 
 use super::*;
 
-// Message type 13 is a receipt acknowledgement to senders of previous messages of type 12. 
+// Message type 13 is a receipt acknowledgement to senders of previous messages of type 12.
 // The message layout is identical to a type 7 Binary Acknowledge.
 
+/// Type 7: Binary Acknowledge. Acknowledges up to four type 6 messages and shares its layout
+/// with the type 13 safety-related acknowledgement.
+pub type BinaryAcknowledge = SafetyRelatedAcknowledgement;
+
+// -------------------------------------------------------------------------------------------------
+
+/// AIS VDM/VDO type 7: Binary Acknowledge
+pub(crate) fn handle(
+    bv: &BitVec,
+    station: Station,
+    own_vessel: bool,
+) -> Result<ParsedMessage, ParseError> {
+    Ok(ParsedMessage::BinaryAcknowledge(
+        vdm_t13::parse_acknowledgement(bv, station, own_vessel),
+    ))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_vdm_type7() {
+        match NmeaParser::new().parse_sentence("!AIVDM,1,1,,A,702R5`hwCjq;?DUV@@,4*14") {
+            Ok(ps) => match ps {
+                ParsedMessage::BinaryAcknowledge(ba) => {
+                    assert_eq!(ba.mmsi, 2655651);
+                    assert_eq!(ba.mmsi1, 265538450);
+                    assert_eq!(ba.mmsi1_seq, 3);
+                    assert_eq!(ba.mmsi2, 257055120);
+                    assert_eq!(ba.mmsi2_seq, 1);
+                    assert_eq!(ba.mmsi3, 0);
+                    assert_eq!(ba.mmsi4, 0);
+                    assert_eq!(ba.acknowledgements(), vec![(265538450, 3), (257055120, 1)]);
+                    assert!(ba.acknowledges(257055120, 1));
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+}
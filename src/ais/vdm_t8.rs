@@ -0,0 +1,145 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+// -------------------------------------------------------------------------------------------------
+
+/// Type 8: Binary Broadcast Message
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct BinaryBroadcastMessage {
+    /// True if the data is about own vessel, false if about other.
+    pub own_vessel: bool,
+
+    /// AIS station type.
+    pub station: Station,
+
+    /// User ID (30 bits)
+    pub mmsi: u32,
+
+    /// Designated area code, DAC (10 bits)
+    pub dac: u16,
+
+    /// Functional ID, FID (6 bits)
+    pub fid: u8,
+
+    /// Application data bits following the FID field.
+    pub data: BitVec,
+
+    /// Decoded application data when the DAC and FID combination is known.
+    pub application_data: Option<ApplicationData>,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// AIS VDM/VDO type 8: Binary Broadcast Message
+pub(crate) fn handle(
+    bv: &BitVec,
+    station: Station,
+    own_vessel: bool,
+    decoders: &asm::AsmDecoders,
+) -> Result<ParsedMessage, ParseError> {
+    let dac = pick_u64(bv, 40, 10) as u16;
+    let fid = pick_u64(bv, 50, 6) as u8;
+    let data = BitVec::from_bitslice(&bv[min(56, bv.len())..]);
+    Ok(ParsedMessage::BinaryBroadcastMessage(
+        BinaryBroadcastMessage {
+            own_vessel: { own_vessel },
+            station: { station },
+            mmsi: { pick_u64(bv, 8, 30) as u32 },
+            dac,
+            fid,
+            application_data: { asm::decode(dac, fid, false, &data, decoders)? },
+            data,
+        },
+    ))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_vdm_type8() {
+        match NmeaParser::new().parse_sentence("!AIVDM,1,1,,A,85Mwp`1Kgi8lEW,0*33") {
+            Ok(ps) => match ps {
+                ParsedMessage::BinaryBroadcastMessage(bbm) => {
+                    assert_eq!(bbm.mmsi, 366999712);
+                    assert_eq!(bbm.flag_state(), Some("US"));
+                    assert_eq!(bbm.dac, 366);
+                    assert_eq!(bbm.fid, 63);
+                    assert_eq!(bbm.data.len(), 28);
+                    assert_eq!(pick_u64(&bbm.data, 0, 28), 0x1234567);
+                    assert_eq!(bbm.application_data, None);
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_vdm_type8_registered_decoder() {
+        let mut p = NmeaParser::new();
+        p.register_asm_decoder(
+            366,
+            63,
+            Box::new(|data: &BitVec| {
+                Ok(ApplicationData::Custom(CustomMessage::new(
+                    "366/63",
+                    pick_u64(data, 0, 28) as u32,
+                )))
+            }),
+        );
+        match p.parse_sentence("!AIVDM,1,1,,A,85Mwp`1Kgi8lEW,0*33") {
+            Ok(ps) => match ps {
+                ParsedMessage::BinaryBroadcastMessage(bbm) => match bbm.application_data {
+                    Some(ApplicationData::Custom(msg)) => {
+                        assert_eq!(msg.sentence_type, "366/63");
+                        assert_eq!(msg.downcast_ref::<u32>(), Some(&0x1234567));
+                    }
+                    _ => {
+                        panic!("Unexpected application data: {:?}", bbm.application_data);
+                    }
+                },
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+
+        // Raw bits are available again after unregistering
+        p.unregister_asm_decoder(366, 63);
+        match p.parse_sentence("!AIVDM,1,1,,A,85Mwp`1Kgi8lEW,0*33") {
+            Ok(ParsedMessage::BinaryBroadcastMessage(bbm)) => {
+                assert_eq!(bbm.application_data, None);
+                assert_eq!(bbm.data.len(), 28);
+            }
+            r => {
+                panic!("Unexpected result: {:?}", r);
+            }
+        }
+    }
+}
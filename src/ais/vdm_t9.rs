@@ -69,6 +69,9 @@ pub struct StandardSarAircraftPositionReport {
 
     /// Radio status (20 bits).
     pub radio_status: u32,
+
+    /// Decoded communication state
+    pub communication_state: Option<CommunicationState>,
 }
 
 impl LatLon for StandardSarAircraftPositionReport {
@@ -141,6 +144,12 @@ pub(crate) fn handle(
             assigned: { pick_u64(bv, 146, 1) != 0 },
             raim_flag: { pick_u64(bv, 147, 1) != 0 },
             radio_status: { pick_u64(bv, 148, 20) as u32 },
+            communication_state: {
+                Some(CommunicationState::new(
+                    pick_u64(bv, 148, 1) != 0,
+                    pick_u64(bv, 149, 19) as u32,
+                ))
+            },
         },
     ))
 }
@@ -0,0 +1,259 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+/// State of a single vessel combined from its position reports and static data reports
+#[derive(Clone, Debug, PartialEq)]
+pub struct VesselState {
+    /// User ID (30 bits)
+    pub mmsi: u32,
+
+    /// The latest position report (types 1, 2, 3, 18, 19 and 27)
+    pub dynamic_data: Option<VesselDynamicData>,
+
+    /// Time of the latest position report
+    pub dynamic_updated: Option<DateTime<Utc>>,
+
+    /// Static data combined from static data reports (types 5 and 24)
+    pub static_data: Option<VesselStaticData>,
+
+    /// Time of the latest static data report
+    pub static_updated: Option<DateTime<Utc>>,
+}
+
+impl VesselState {
+    fn new(mmsi: u32) -> VesselState {
+        VesselState {
+            mmsi,
+            dynamic_data: None,
+            dynamic_updated: None,
+            static_data: None,
+            static_updated: None,
+        }
+    }
+
+    /// Return the name of the vessel, if known
+    pub fn name(&self) -> Option<&str> {
+        self.static_data
+            .as_ref()
+            .and_then(|vsd| vsd.name.as_deref())
+    }
+
+    /// Return the overall length of the vessel in metres, if known. Static data reports are
+    /// preferred over the dimensions of type 19 position reports.
+    pub fn length(&self) -> Option<u16> {
+        self.static_data
+            .as_ref()
+            .and_then(|vsd| vsd.length())
+            .or_else(|| self.dynamic_data.as_ref().and_then(|vdd| vdd.length()))
+    }
+
+    /// Return the overall breadth of the vessel in metres, if known. Static data reports are
+    /// preferred over the dimensions of type 19 position reports.
+    pub fn breadth(&self) -> Option<u16> {
+        self.static_data
+            .as_ref()
+            .and_then(|vsd| vsd.breadth())
+            .or_else(|| self.dynamic_data.as_ref().and_then(|vdd| vdd.breadth()))
+    }
+
+    /// Return the time of the latest update of any kind
+    pub fn last_updated(&self) -> Option<DateTime<Utc>> {
+        match (self.dynamic_updated, self.static_updated) {
+            (Some(d), Some(s)) => Some(d.max(s)),
+            (d, s) => d.or(s),
+        }
+    }
+}
+
+impl LatLon for VesselState {
+    fn latitude(&self) -> Option<f64> {
+        self.dynamic_data.as_ref().and_then(|vdd| vdd.latitude)
+    }
+
+    fn longitude(&self) -> Option<f64> {
+        self.dynamic_data.as_ref().and_then(|vdd| vdd.longitude)
+    }
+}
+
+impl HasMmsi for VesselState {
+    fn mmsi(&self) -> u32 {
+        self.mmsi
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Index of vessel states by MMSI. Position and static data reports pushed to the index are
+/// combined into one `VesselState` per vessel.
+#[derive(Clone, Debug, Default)]
+pub struct VesselIndex {
+    vessels: HashMap<u32, VesselState>,
+}
+
+impl VesselIndex {
+    /// Create an empty index
+    pub fn new() -> VesselIndex {
+        VesselIndex::default()
+    }
+
+    /// Push a parsed message received at the given time to the index. Returns the updated state
+    /// of the vessel, or `None` if the message doesn't contain vessel data.
+    pub fn push(
+        &mut self,
+        message: &ParsedMessage,
+        timestamp: DateTime<Utc>,
+    ) -> Option<&VesselState> {
+        match message {
+            ParsedMessage::VesselDynamicData(vdd) => {
+                let state = self
+                    .vessels
+                    .entry(vdd.mmsi)
+                    .or_insert_with(|| VesselState::new(vdd.mmsi));
+                let mut vdd = vdd.clone();
+                if let Some(old) = &state.dynamic_data {
+                    // Only type 19 carries dimensions, keep them over the other report types
+                    if vdd.dimension_to_bow.is_none() {
+                        vdd.dimension_to_bow = old.dimension_to_bow;
+                        vdd.dimension_to_stern = old.dimension_to_stern;
+                        vdd.dimension_to_port = old.dimension_to_port;
+                        vdd.dimension_to_starboard = old.dimension_to_starboard;
+                    }
+                }
+                state.dynamic_data = Some(vdd);
+                state.dynamic_updated = Some(timestamp);
+                Some(state)
+            }
+            ParsedMessage::VesselStaticData(vsd) => {
+                let state = self
+                    .vessels
+                    .entry(vsd.mmsi)
+                    .or_insert_with(|| VesselState::new(vsd.mmsi));
+                // Keep the fields missing from the new report, e.g. the other type 24 part
+                let merged = state
+                    .static_data
+                    .as_ref()
+                    .and_then(|old| vsd.merge(old).ok());
+                state.static_data = Some(merged.unwrap_or_else(|| vsd.clone()));
+                state.static_updated = Some(timestamp);
+                Some(state)
+            }
+            _ => None,
+        }
+    }
+
+    /// Return the state of the vessel with the given MMSI
+    pub fn get(&self, mmsi: u32) -> Option<&VesselState> {
+        self.vessels.get(&mmsi)
+    }
+
+    /// Remove the vessel with the given MMSI from the index
+    pub fn remove(&mut self, mmsi: u32) -> Option<VesselState> {
+        self.vessels.remove(&mmsi)
+    }
+
+    /// Remove the vessels which haven't been updated since the given time
+    pub fn remove_older_than(&mut self, cutoff: DateTime<Utc>) {
+        self.vessels
+            .retain(|_, state| state.last_updated().map(|t| t >= cutoff).unwrap_or(false));
+    }
+
+    /// Iterate over the vessel states in arbitrary order
+    pub fn iter(&self) -> impl Iterator<Item = &VesselState> {
+        self.vessels.values()
+    }
+
+    /// Return the number of vessels in the index
+    pub fn len(&self) -> usize {
+        self.vessels.len()
+    }
+
+    /// Return true if the index contains no vessels
+    pub fn is_empty(&self) -> bool {
+        self.vessels.is_empty()
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_vessel_index() {
+        let mut p = NmeaParser::new();
+        p.set_type24_merging(false);
+        let mut index = VesselIndex::new();
+        let t1 = Utc.with_ymd_and_hms(2026, 10, 16, 12, 0, 0).unwrap();
+        let t2 = Utc.with_ymd_and_hms(2026, 10, 16, 12, 5, 0).unwrap();
+
+        let msg = p
+            .parse_sentence("$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47")
+            .unwrap();
+        assert_eq!(index.push(&msg, t1), None);
+        assert!(index.is_empty());
+
+        // Type 24 parts of the same vessel are merged
+        for s in [
+            "!AIVDM,1,1,,A,H42O55i18tMET00000000000000,2*6D",
+            "!AIVDM,1,1,,A,H42O55lti4hhhilD3nink000?050,0*40",
+        ] {
+            let msg = p.parse_sentence(s).unwrap();
+            assert!(index.push(&msg, t1).is_some());
+        }
+        let msg = p
+            .parse_sentence("!AIVDM,1,1,,A,15RTgt0PAso;90TKcjM8h6g208CQ,0*4A")
+            .unwrap();
+        index.push(&msg, t2);
+        assert_eq!(index.len(), 2);
+
+        match index.get(271041815) {
+            Some(state) => {
+                assert_eq!(state.name(), Some("PROGUY"));
+                assert_eq!(
+                    state.static_data.as_ref().and_then(|s| s.call_sign.clone()),
+                    Some("TC6163".into())
+                );
+                assert_eq!(state.length(), Some(15));
+                assert_eq!(state.dynamic_data, None);
+                assert_eq!(state.static_updated, Some(t1));
+                assert_eq!(state.flag_state(), Some("TR"));
+            }
+            None => {
+                panic!("Vessel not found");
+            }
+        }
+        match index.get(371798000) {
+            Some(state) => {
+                assert_eq!(state.name(), None);
+                assert::close(state.latitude().unwrap_or(0.0), 48.4, 0.1);
+                assert_eq!(state.dynamic_updated, Some(t2));
+                assert_eq!(state.last_updated(), Some(t2));
+            }
+            None => {
+                panic!("Vessel not found");
+            }
+        }
+
+        index.remove_older_than(t2);
+        assert_eq!(index.len(), 1);
+        assert!(index.get(271041815).is_none());
+        assert!(index.remove(371798000).is_some());
+        assert!(index.is_empty());
+    }
+}
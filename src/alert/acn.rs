@@ -0,0 +1,122 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+/// ACN - Alert command
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct AcnData {
+    /// Time of the command
+    #[serde(with = "json_date_time_utc")]
+    pub timestamp: Option<DateTime<Utc>>,
+
+    /// Manufacturer mnemonic code, `None` for standardized alerts
+    pub manufacturer: Option<String>,
+
+    /// Alert identifier
+    pub alert_id: Option<u32>,
+
+    /// Alert instance
+    pub alert_instance: Option<u32>,
+
+    /// Alert command
+    pub command: Option<AlertCommand>,
+}
+
+/// Command given to an alert source
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub enum AlertCommand {
+    /// Acknowledge
+    Acknowledge,
+
+    /// Request or repeat information
+    RequestInformation,
+
+    /// Responsibility transfer
+    ResponsibilityTransfer,
+
+    /// Silence
+    Silence,
+}
+
+impl AlertCommand {
+    pub fn new(val: &str) -> Result<AlertCommand, String> {
+        match val {
+            "A" => Ok(AlertCommand::Acknowledge),
+            "Q" => Ok(AlertCommand::RequestInformation),
+            "O" => Ok(AlertCommand::ResponsibilityTransfer),
+            "S" => Ok(AlertCommand::Silence),
+            _ => Err(format!("Unrecognized alert command: {}", val)),
+        }
+    }
+}
+
+impl core::fmt::Display for AlertCommand {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            AlertCommand::Acknowledge => write!(f, "acknowledge"),
+            AlertCommand::RequestInformation => write!(f, "request information"),
+            AlertCommand::ResponsibilityTransfer => write!(f, "responsibility transfer"),
+            AlertCommand::Silence => write!(f, "silence"),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// xxACN: Alert command
+pub(crate) fn handle(sentence: &str) -> Result<ParsedMessage, ParseError> {
+    let now: DateTime<Utc> = Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).single().unwrap();
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    Ok(ParsedMessage::Acn(AcnData {
+        timestamp: parse_hhmmss_ss(split.get(1).unwrap_or(&""), now).ok(),
+        manufacturer: pick_string_field(&split, 2),
+        alert_id: pick_number_field(&split, 3)?,
+        alert_instance: pick_number_field(&split, 4)?,
+        command: match pick_string_field(&split, 5) {
+            Some(s) => Some(AlertCommand::new(&s)?),
+            None => None,
+        },
+    }))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_acn() {
+        match NmeaParser::new().parse_sentence("$VRACN,081950.10,,192,1,A,C*6B") {
+            Ok(ps) => match ps {
+                ParsedMessage::Acn(acn) => {
+                    assert_eq!(acn.manufacturer, None);
+                    assert_eq!(acn.alert_id, Some(192));
+                    assert_eq!(acn.alert_instance, Some(1));
+                    assert_eq!(acn.command, Some(AlertCommand::Acknowledge));
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+}
@@ -0,0 +1,114 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+/// ALC - Cyclic alert list
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct AlcData {
+    /// Total number of sentences for this message
+    pub sentence_count: Option<u8>,
+
+    /// Sentence number
+    pub sentence_number: Option<u8>,
+
+    /// Sequential message identifier
+    pub message_id: Option<u8>,
+
+    /// Number of alert entries in the sentence
+    pub entry_count: Option<u8>,
+
+    /// Alert entries
+    pub entries: Vec<AlcEntry>,
+}
+
+/// Alert entry of ALC sentence
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct AlcEntry {
+    /// Manufacturer mnemonic code, `None` for standardized alerts
+    pub manufacturer: Option<String>,
+
+    /// Alert identifier
+    pub alert_id: Option<u32>,
+
+    /// Alert instance
+    pub alert_instance: Option<u32>,
+
+    /// Revision counter
+    pub revision: Option<u8>,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// xxALC: Cyclic alert list
+pub(crate) fn handle(sentence: &str) -> Result<ParsedMessage, ParseError> {
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    Ok(ParsedMessage::Alc(AlcData {
+        sentence_count: pick_number_field(&split, 1)?,
+        sentence_number: pick_number_field(&split, 2)?,
+        message_id: pick_number_field(&split, 3)?,
+        entry_count: pick_number_field(&split, 4)?,
+        entries: {
+            let mut v = Vec::new();
+            let mut i = 5;
+            while i + 3 < split.len() {
+                v.push(AlcEntry {
+                    manufacturer: pick_string_field(&split, i),
+                    alert_id: pick_number_field(&split, i + 1)?,
+                    alert_instance: pick_number_field(&split, i + 2)?,
+                    revision: pick_number_field(&split, i + 3)?,
+                });
+                i += 4;
+            }
+            v
+        },
+    }))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_alc() {
+        match NmeaParser::new().parse_sentence("$VRALC,01,01,00,2,,192,1,1,FEC,3022,1,2*02") {
+            Ok(ps) => match ps {
+                ParsedMessage::Alc(alc) => {
+                    assert_eq!(alc.sentence_count, Some(1));
+                    assert_eq!(alc.sentence_number, Some(1));
+                    assert_eq!(alc.message_id, Some(0));
+                    assert_eq!(alc.entry_count, Some(2));
+                    assert_eq!(alc.entries.len(), 2);
+                    assert_eq!(alc.entries[0].manufacturer, None);
+                    assert_eq!(alc.entries[0].alert_id, Some(192));
+                    assert_eq!(alc.entries[1].manufacturer, Some("FEC".into()));
+                    assert_eq!(alc.entries[1].alert_id, Some(3022));
+                    assert_eq!(alc.entries[1].alert_instance, Some(1));
+                    assert_eq!(alc.entries[1].revision, Some(2));
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+}
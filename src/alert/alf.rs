@@ -0,0 +1,216 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+/// ALF - Alert sentence
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct AlfData {
+    /// Time of last change
+    #[serde(with = "json_date_time_utc")]
+    pub timestamp: Option<DateTime<Utc>>,
+
+    /// Alert category
+    pub category: Option<AlertCategory>,
+
+    /// Alert priority
+    pub priority: Option<AlertPriority>,
+
+    /// Alert state
+    pub state: Option<AlertState>,
+
+    /// Manufacturer mnemonic code, `None` for standardized alerts
+    pub manufacturer: Option<String>,
+
+    /// Alert identifier
+    pub alert_id: Option<u32>,
+
+    /// Alert instance (1-999999)
+    pub alert_instance: Option<u32>,
+
+    /// Revision counter (1-99)
+    pub revision: Option<u8>,
+
+    /// Escalation counter (0-9)
+    pub escalation: Option<u8>,
+
+    /// Alert title text from the first sentence
+    pub title: Option<String>,
+
+    /// Alert description text from the second sentence, if sent
+    pub description: Option<String>,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// xxALF: Alert sentence
+pub(crate) fn handle(sentence: &str, store: &mut NmeaParser) -> Result<ParsedMessage, ParseError> {
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    let msg_type = split.first().unwrap_or(&"");
+    let msg_count = pick_number_field(&split, 1)?.unwrap_or(1);
+    let msg_num = pick_number_field(&split, 2)?.unwrap_or(1);
+    let msg_id = split.get(3).unwrap_or(&"");
+    if msg_count <= 1 {
+        return Ok(ParsedMessage::Alf(parse_alf(&split, None)?));
+    }
+    store.push_string(
+        make_alf_key(msg_type, msg_id, msg_count, msg_num),
+        sentence.into(),
+    );
+
+    for i in 1..=msg_count {
+        if !store.contains_key(make_alf_key(msg_type, msg_id, msg_count, i)) {
+            return Ok(ParsedMessage::Incomplete);
+        }
+    }
+
+    let mut sentences = Vec::new();
+    for i in 1..=msg_count {
+        if let Some(s) = store.pull_string(make_alf_key(msg_type, msg_id, msg_count, i)) {
+            sentences.push(s);
+        }
+    }
+    let first: Vec<&str> = sentences
+        .first()
+        .ok_or_else(|| {
+            ParseError::CorruptedSentence(format!("ALF sentences missing: {}", sentence))
+        })?
+        .split(',')
+        .collect();
+    let mut description = String::new();
+    for s in sentences.iter().skip(1) {
+        let split: Vec<&str> = s.split(',').collect();
+        description.push_str(split.get(13).unwrap_or(&""));
+    }
+    Ok(ParsedMessage::Alf(parse_alf(
+        &first,
+        if description.is_empty() {
+            None
+        } else {
+            Some(description)
+        },
+    )?))
+}
+
+/// Parse fields of the first ALF sentence
+fn parse_alf(split: &[&str], description: Option<String>) -> Result<AlfData, ParseError> {
+    let now: DateTime<Utc> = Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).single().unwrap();
+    Ok(AlfData {
+        timestamp: parse_hhmmss_ss(split.get(4).unwrap_or(&""), now).ok(),
+        category: match pick_string_field(split, 5) {
+            Some(s) => Some(AlertCategory::new(&s)?),
+            None => None,
+        },
+        priority: match pick_string_field(split, 6) {
+            Some(s) => Some(AlertPriority::new(&s)?),
+            None => None,
+        },
+        state: match pick_string_field(split, 7) {
+            Some(s) => Some(AlertState::new(&s)?),
+            None => None,
+        },
+        manufacturer: pick_string_field(split, 8),
+        alert_id: pick_number_field(split, 9)?,
+        alert_instance: pick_number_field(split, 10)?,
+        revision: pick_number_field(split, 11)?,
+        escalation: pick_number_field(split, 12)?,
+        title: pick_string_field(split, 13),
+        description,
+    })
+}
+
+/// Make key for store
+fn make_alf_key(sentence_type: &str, msg_id: &str, msg_count: u8, msg_num: u8) -> String {
+    format!("{},{},{},{}", sentence_type, msg_id, msg_count, msg_num)
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_alf() {
+        // Single sentence
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$VRALF,1,1,4,081950.10,B,W,A,FEC,3022,1,2,0,GPS SIGNAL LOST*07") {
+            Ok(ps) => match ps {
+                ParsedMessage::Alf(alf) => {
+                    assert_eq!(
+                        alf.timestamp,
+                        Utc.with_ymd_and_hms(2000, 1, 1, 8, 19, 50)
+                            .single()
+                            .map(|t| t + chrono::Duration::milliseconds(100))
+                    );
+                    assert_eq!(alf.category, Some(AlertCategory::B));
+                    assert_eq!(alf.priority, Some(AlertPriority::Warning));
+                    assert_eq!(alf.state, Some(AlertState::ActiveAcknowledged));
+                    assert_eq!(alf.manufacturer, Some("FEC".into()));
+                    assert_eq!(alf.alert_id, Some(3022));
+                    assert_eq!(alf.alert_instance, Some(1));
+                    assert_eq!(alf.revision, Some(2));
+                    assert_eq!(alf.escalation, Some(0));
+                    assert_eq!(alf.title, Some("GPS SIGNAL LOST".into()));
+                    assert_eq!(alf.description, None);
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+
+        // Two sentences arriving in reverse order
+        match p.parse_sentence("$VRALF,2,2,3,,,,,,192,1,1,0,TARGET ID 5*73") {
+            Ok(ps) => {
+                assert_eq!(ps, ParsedMessage::Incomplete);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+        assert_eq!(p.strings_count(), 1);
+        match p.parse_sentence("$VRALF,2,1,3,081950.10,B,A,V,,192,1,1,0,LOST TARGET*13") {
+            Ok(ps) => match ps {
+                ParsedMessage::Alf(alf) => {
+                    assert_eq!(alf.priority, Some(AlertPriority::Alarm));
+                    assert_eq!(alf.state, Some(AlertState::ActiveUnacknowledged));
+                    assert_eq!(alf.manufacturer, None);
+                    assert_eq!(alf.alert_id, Some(192));
+                    assert_eq!(alf.title, Some("LOST TARGET".into()));
+                    assert_eq!(alf.description, Some("TARGET ID 5".into()));
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+        assert_eq!(p.strings_count(), 0);
+
+        // Maximum sentence count
+        assert_eq!(
+            p.parse_sentence("$VRALF,255,1,3,081950.10,B,A,V,,192,1,1,0,LOST TARGET"),
+            Ok(ParsedMessage::Incomplete)
+        );
+    }
+}
@@ -0,0 +1,157 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+//! Bridge alert management data structures
+
+pub(crate) mod acn;
+pub(crate) mod alc;
+pub(crate) mod alf;
+
+use super::*;
+pub use acn::{AcnData, AlertCommand};
+pub use alc::{AlcData, AlcEntry};
+pub use alf::AlfData;
+use serde::Serialize;
+
+// -------------------------------------------------------------------------------------------------
+
+/// Alert category according to the bridge alert management performance standard
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub enum AlertCategory {
+    /// Category A: alerts where graphical information at the task station is required
+    A,
+
+    /// Category B: alerts where no additional information for decision support is necessary
+    B,
+
+    /// Category C: alerts that cannot be acknowledged on the bridge
+    C,
+}
+
+impl AlertCategory {
+    pub fn new(val: &str) -> Result<AlertCategory, String> {
+        match val {
+            "A" => Ok(AlertCategory::A),
+            "B" => Ok(AlertCategory::B),
+            "C" => Ok(AlertCategory::C),
+            _ => Err(format!("Unrecognized alert category: {}", val)),
+        }
+    }
+}
+
+impl core::fmt::Display for AlertCategory {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            AlertCategory::A => write!(f, "category A"),
+            AlertCategory::B => write!(f, "category B"),
+            AlertCategory::C => write!(f, "category C"),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Alert priority
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub enum AlertPriority {
+    /// Emergency alarm
+    EmergencyAlarm,
+
+    /// Alarm
+    Alarm,
+
+    /// Warning
+    Warning,
+
+    /// Caution
+    Caution,
+}
+
+impl AlertPriority {
+    pub fn new(val: &str) -> Result<AlertPriority, String> {
+        match val {
+            "E" => Ok(AlertPriority::EmergencyAlarm),
+            "A" => Ok(AlertPriority::Alarm),
+            "W" => Ok(AlertPriority::Warning),
+            "C" => Ok(AlertPriority::Caution),
+            _ => Err(format!("Unrecognized alert priority: {}", val)),
+        }
+    }
+}
+
+impl core::fmt::Display for AlertPriority {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            AlertPriority::EmergencyAlarm => write!(f, "emergency alarm"),
+            AlertPriority::Alarm => write!(f, "alarm"),
+            AlertPriority::Warning => write!(f, "warning"),
+            AlertPriority::Caution => write!(f, "caution"),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Alert state
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub enum AlertState {
+    /// Active, unacknowledged
+    ActiveUnacknowledged,
+
+    /// Active, silenced
+    ActiveSilenced,
+
+    /// Active, acknowledged
+    ActiveAcknowledged,
+
+    /// Active, responsibility transferred
+    ActiveResponsibilityTransferred,
+
+    /// Rectified, unacknowledged
+    RectifiedUnacknowledged,
+
+    /// Normal state
+    Normal,
+}
+
+impl AlertState {
+    pub fn new(val: &str) -> Result<AlertState, String> {
+        match val {
+            "V" => Ok(AlertState::ActiveUnacknowledged),
+            "S" => Ok(AlertState::ActiveSilenced),
+            "A" => Ok(AlertState::ActiveAcknowledged),
+            "O" => Ok(AlertState::ActiveResponsibilityTransferred),
+            "U" => Ok(AlertState::RectifiedUnacknowledged),
+            "N" => Ok(AlertState::Normal),
+            _ => Err(format!("Unrecognized alert state: {}", val)),
+        }
+    }
+}
+
+impl core::fmt::Display for AlertState {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            AlertState::ActiveUnacknowledged => write!(f, "active, unacknowledged"),
+            AlertState::ActiveSilenced => write!(f, "active, silenced"),
+            AlertState::ActiveAcknowledged => write!(f, "active, acknowledged"),
+            AlertState::ActiveResponsibilityTransferred => {
+                write!(f, "active, responsibility transferred")
+            }
+            AlertState::RectifiedUnacknowledged => write!(f, "rectified, unacknowledged"),
+            AlertState::Normal => write!(f, "normal"),
+        }
+    }
+}
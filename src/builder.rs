@@ -0,0 +1,154 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+/// Builder for `NmeaParser` collecting the tolerance and storage settings in one place. Settings
+/// not given keep the defaults of `NmeaParser::new()`.
+///
+/// ```
+/// use nmea_parser::{ChecksumPolicy, NmeaParser};
+///
+/// let parser = NmeaParser::builder()
+///     .checksum_policy(ChecksumPolicy::Require)
+///     .max_fragment_count(5)
+///     .store_capacity(1000)
+///     .date_inference(true)
+///     .build();
+/// ```
+#[derive(Clone, Default)]
+pub struct NmeaParserBuilder {
+    parser: NmeaParser,
+}
+
+impl NmeaParserBuilder {
+    /// Construct a builder with the default settings
+    pub fn new() -> NmeaParserBuilder {
+        NmeaParserBuilder::default()
+    }
+
+    /// See `NmeaParser::set_checksum_policy()`
+    pub fn checksum_policy(mut self, policy: ChecksumPolicy) -> Self {
+        self.parser.set_checksum_policy(policy);
+        self
+    }
+
+    /// See `NmeaParser::set_sentence_filter()`
+    pub fn sentence_filter(mut self, filter: SentenceFilter) -> Self {
+        self.parser.set_sentence_filter(filter);
+        self
+    }
+
+    /// See `NmeaParser::set_max_fragment_count()`
+    pub fn max_fragment_count(mut self, max_count: u8) -> Self {
+        self.parser.set_max_fragment_count(Some(max_count));
+        self
+    }
+
+    /// See `NmeaParser::set_fragment_max_age()`
+    pub fn fragment_max_age(mut self, max_age: u64) -> Self {
+        self.parser.set_fragment_max_age(Some(max_age));
+        self
+    }
+
+    /// See `NmeaParser::set_store_capacity()`
+    pub fn store_capacity(mut self, capacity: usize) -> Self {
+        self.parser.set_store_capacity(Some(capacity));
+        self
+    }
+
+    /// See `NmeaParser::set_strict_fields()`
+    pub fn strict_fields(mut self, enabled: bool) -> Self {
+        self.parser.set_strict_fields(enabled);
+        self
+    }
+
+    /// See `NmeaParser::set_date_inference()`
+    pub fn date_inference(mut self, enabled: bool) -> Self {
+        self.parser.set_date_inference(enabled);
+        self
+    }
+
+    /// See `NmeaParser::set_week_rollover_correction()`
+    pub fn week_rollover_correction(mut self, enabled: bool) -> Self {
+        self.parser.set_week_rollover_correction(enabled);
+        self
+    }
+
+    /// See `NmeaParser::set_type24_merging()`
+    pub fn type24_merging(mut self, enabled: bool) -> Self {
+        self.parser.set_type24_merging(enabled);
+        self
+    }
+
+    /// See `NmeaParser::set_dedup_window()`
+    pub fn dedup_window(mut self, window: chrono::Duration) -> Self {
+        self.parser.set_dedup_window(Some(window));
+        self
+    }
+
+    /// Construct the parser
+    pub fn build(self) -> NmeaParser {
+        self.parser
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_builder() {
+        let mut p = NmeaParser::builder()
+            .checksum_policy(ChecksumPolicy::Require)
+            .max_fragment_count(2)
+            .strict_fields(true)
+            .build();
+
+        assert!(p
+            .parse_sentence("!AIVDM,1,1,,A,15RTgt0PAso;90TKcjM8h6g208CQ,0*4A")
+            .is_ok());
+        assert_eq!(
+            p.parse_sentence("!AIVDM,1,1,,A,15RTgt0PAso;90TKcjM8h6g208CQ,0"),
+            Err(ParseError::CorruptedSentence(
+                "Missing checksum: !AIVDM,1,1,,A,15RTgt0PAso;90TKcjM8h6g208CQ,0".to_string()
+            ))
+        );
+        assert_eq!(
+            p.parse_sentence("!AIVDM,3,1,3,B,>3KMWh1<D=E8U@F0p5HT,0*13"),
+            Err(ParseError::InvalidField {
+                name: "fragment count".to_string(),
+                value: "3".to_string()
+            })
+        );
+        assert_eq!(
+            p.parse_sentence("!AIVDM,2,3,1,A,88888888880,2*24"),
+            Err(ParseError::InvalidField {
+                name: "fragment number".to_string(),
+                value: "3".to_string()
+            })
+        );
+
+        // Lenient defaults
+        let mut p = NmeaParser::builder().build();
+        assert_eq!(
+            p.parse_sentence("!AIVDM,2,3,1,A,88888888880,2*24"),
+            Ok(ParsedMessage::Incomplete)
+        );
+    }
+}
@@ -0,0 +1,154 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+use core::any::Any;
+
+/// Handler for sentences not supported by the crate itself, such as vendor specific proprietary
+/// sentences. Handlers are registered with `NmeaParser::register_handler()`.
+pub trait SentenceHandler: Send + Sync {
+    /// Parse the given sentence. Checksum has already been verified and removed. Handlers
+    /// typically return `ParsedMessage::Custom` but any other variant can be returned as well.
+    fn handle(&self, sentence: &str) -> Result<ParsedMessage, ParseError>;
+}
+
+impl<F> SentenceHandler for F
+where
+    F: Fn(&str) -> Result<ParsedMessage, ParseError> + Send + Sync,
+{
+    fn handle(&self, sentence: &str) -> Result<ParsedMessage, ParseError> {
+        self(sentence)
+    }
+}
+
+/// Result of a user-registered sentence handler. The payload can be of any type and it is
+/// accessed with `downcast_ref()`.
+#[derive(Clone)]
+pub struct CustomMessage {
+    /// Sentence identifier the handler was registered with (e.g. "PXYZ")
+    pub sentence_type: String,
+
+    data: Arc<dyn Any + Send + Sync>,
+}
+
+impl CustomMessage {
+    /// Construct a new message with the given sentence type and payload.
+    pub fn new<T: Any + Send + Sync>(sentence_type: &str, data: T) -> CustomMessage {
+        CustomMessage {
+            sentence_type: sentence_type.into(),
+            data: Arc::new(data),
+        }
+    }
+
+    /// Return the payload if it is of type `T`.
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        self.data.downcast_ref::<T>()
+    }
+}
+
+impl core::fmt::Debug for CustomMessage {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CustomMessage")
+            .field("sentence_type", &self.sentence_type)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for CustomMessage {
+    /// Messages are equal when they have the same sentence type and share the same payload.
+    fn eq(&self, other: &Self) -> bool {
+        self.sentence_type == other.sentence_type && Arc::ptr_eq(&self.data, &other.data)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Xyz {
+        value: u32,
+    }
+
+    struct XyzHandler;
+
+    impl SentenceHandler for XyzHandler {
+        fn handle(&self, sentence: &str) -> Result<ParsedMessage, ParseError> {
+            let split: Vec<&str> = sentence.split(',').collect();
+            Ok(ParsedMessage::Custom(CustomMessage::new(
+                "PXYZ",
+                Xyz {
+                    value: pick_number_field(&split, 1)?.unwrap_or(0),
+                },
+            )))
+        }
+    }
+
+    #[test]
+    fn test_custom_handler() {
+        let mut p = NmeaParser::new();
+        assert_eq!(
+            p.parse_sentence("$PXYZ,42"),
+            Err(ParseError::UnsupportedSentenceType(String::from(
+                "Unsupported sentence type: $PXYZ"
+            )))
+        );
+
+        p.register_handler("PXYZ", Box::new(XyzHandler));
+        match p.parse_sentence("$PXYZ,42") {
+            Ok(ps) => match ps {
+                ParsedMessage::Custom(msg) => {
+                    assert_eq!(msg.sentence_type, "PXYZ");
+                    assert_eq!(msg.downcast_ref::<Xyz>(), Some(&Xyz { value: 42 }));
+                    assert_eq!(msg.downcast_ref::<u32>(), None);
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+
+        // Handlers survive reset
+        p.reset();
+        assert!(p.parse_sentence("$PXYZ,1").is_ok());
+
+        p.unregister_handler("PXYZ");
+        assert!(p.parse_sentence("$PXYZ,1").is_err());
+    }
+
+    #[test]
+    fn test_custom_handler_any_talker() {
+        // Three character identifiers match any talker
+        let mut p = NmeaParser::new();
+        p.register_handler(
+            "ABC",
+            Box::new(|_: &str| Ok(ParsedMessage::Custom(CustomMessage::new("ABC", 1u8)))),
+        );
+        assert!(matches!(
+            p.parse_sentence("$GPABC,1"),
+            Ok(ParsedMessage::Custom(_))
+        ));
+        assert!(matches!(
+            p.parse_sentence("$IIABC,1"),
+            Ok(ParsedMessage::Custom(_))
+        ));
+    }
+}
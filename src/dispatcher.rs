@@ -0,0 +1,159 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+//! Routing of parsed messages to callbacks by message kind
+
+use super::*;
+
+type Callback<'a, T> = Box<dyn FnMut(&T) + 'a>;
+
+/// Parses the sentences fed to it and routes the resulting messages to the callbacks
+/// registered for their kind. Several callbacks can be registered for the same kind, and they
+/// are called in registration order. Incomplete multipart messages and filtered sentences are
+/// not dispatched.
+#[derive(Default)]
+pub struct Dispatcher<'a> {
+    parser: NmeaParser,
+    on_message: Vec<Callback<'a, ParsedMessage>>,
+    on_position: Vec<Callback<'a, ais::VesselDynamicData>>,
+    on_static_data: Vec<Callback<'a, ais::VesselStaticData>>,
+    on_gga: Vec<Callback<'a, gnss::GgaData>>,
+    on_rmc: Vec<Callback<'a, gnss::RmcData>>,
+    on_error: Vec<Callback<'a, ParseError>>,
+}
+
+impl<'a> Dispatcher<'a> {
+    /// Create a dispatcher with a default parser and no callbacks
+    pub fn new() -> Dispatcher<'a> {
+        Dispatcher::default()
+    }
+
+    /// Create a dispatcher using the given parser
+    pub fn with_parser(parser: NmeaParser) -> Dispatcher<'a> {
+        Dispatcher {
+            parser,
+            ..Dispatcher::default()
+        }
+    }
+
+    /// Return the parser of the dispatcher
+    pub fn parser(&self) -> &NmeaParser {
+        &self.parser
+    }
+
+    /// Register a callback for all messages
+    pub fn on_message(&mut self, callback: impl FnMut(&ParsedMessage) + 'a) -> &mut Self {
+        self.on_message.push(Box::new(callback));
+        self
+    }
+
+    /// Register a callback for AIS vessel position reports
+    pub fn on_position(&mut self, callback: impl FnMut(&ais::VesselDynamicData) + 'a) -> &mut Self {
+        self.on_position.push(Box::new(callback));
+        self
+    }
+
+    /// Register a callback for AIS vessel static data reports
+    pub fn on_static_data(
+        &mut self,
+        callback: impl FnMut(&ais::VesselStaticData) + 'a,
+    ) -> &mut Self {
+        self.on_static_data.push(Box::new(callback));
+        self
+    }
+
+    /// Register a callback for GGA sentences
+    pub fn on_gga(&mut self, callback: impl FnMut(&gnss::GgaData) + 'a) -> &mut Self {
+        self.on_gga.push(Box::new(callback));
+        self
+    }
+
+    /// Register a callback for RMC sentences
+    pub fn on_rmc(&mut self, callback: impl FnMut(&gnss::RmcData) + 'a) -> &mut Self {
+        self.on_rmc.push(Box::new(callback));
+        self
+    }
+
+    /// Register a callback for parse errors
+    pub fn on_error(&mut self, callback: impl FnMut(&ParseError) + 'a) -> &mut Self {
+        self.on_error.push(Box::new(callback));
+        self
+    }
+
+    /// Parse the given sentence and call the callbacks registered for the result. The parse
+    /// error is passed to the error callbacks and returned as well.
+    pub fn feed(&mut self, sentence: &str) -> Result<(), ParseError> {
+        let message = match self.parser.parse_sentence(sentence) {
+            Ok(ParsedMessage::Incomplete) | Ok(ParsedMessage::Skipped) => return Ok(()),
+            Ok(message) => message,
+            Err(e) => {
+                call(&mut self.on_error, &e);
+                return Err(e);
+            }
+        };
+        match &message {
+            ParsedMessage::VesselDynamicData(vdd) => call(&mut self.on_position, vdd),
+            ParsedMessage::VesselStaticData(vsd) => call(&mut self.on_static_data, vsd),
+            ParsedMessage::Gga(gga) => call(&mut self.on_gga, gga),
+            ParsedMessage::Rmc(rmc) => call(&mut self.on_rmc, rmc),
+            _ => {}
+        }
+        call(&mut self.on_message, &message);
+        Ok(())
+    }
+}
+
+/// Call the given callbacks with the given value.
+fn call<T>(callbacks: &mut [Callback<'_, T>], value: &T) {
+    for callback in callbacks.iter_mut() {
+        callback(value);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_dispatcher() {
+        let mut positions = Vec::new();
+        let mut gga_count = 0;
+        let mut message_count = 0;
+        let mut error_count = 0;
+        {
+            let mut d = Dispatcher::new();
+            d.on_position(|vdd| positions.push(vdd.mmsi))
+                .on_gga(|_| gga_count += 1)
+                .on_message(|_| message_count += 1)
+                .on_error(|_| error_count += 1);
+            for s in [
+                "!AIVDM,1,1,,A,15RTgt0PAso;90TKcjM8h6g208CQ,0*4A",
+                "$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47",
+                "!AIVDM,2,1,0,A,55?MbV02;H;s<HtKR20EHE:0@T4@Dn2222222216L961O5Gf0NSQEp6ClRp8,0*1D",
+                "!AIVDM,2,2,0,A,88888888880,2*24",
+            ] {
+                assert_eq!(d.feed(s), Ok(()));
+            }
+            assert!(d.feed("$SDDPT,2.4,0.5*55").is_err());
+        }
+        assert_eq!(positions, vec![371798000]);
+        assert_eq!(gga_count, 1);
+        assert_eq!(message_count, 3);
+        assert_eq!(error_count, 1);
+    }
+}
@@ -0,0 +1,290 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+/// DSC - Digital selective calling information
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct DscData {
+    /// Format specifier
+    pub format: Option<DscFormat>,
+
+    /// MMSI of the called station, or of the ship in distress for distress alerts
+    pub mmsi: Option<u32>,
+
+    /// Addressed area for geographical area calls
+    pub area: Option<String>,
+
+    /// Category
+    pub category: Option<DscCategory>,
+
+    /// Nature of distress for distress alerts and distress relays
+    pub nature_of_distress: Option<DistressNature>,
+
+    /// First telecommand, `None` for distress alerts
+    pub first_telecommand: Option<u8>,
+
+    /// Second telecommand or proposed type of subsequent communication
+    pub second_telecommand: Option<u8>,
+
+    /// Latitude of the ship in distress, reported with one minute resolution
+    pub latitude: Option<f64>,
+
+    /// Longitude of the ship in distress, reported with one minute resolution
+    pub longitude: Option<f64>,
+
+    /// UTC time of the position
+    #[serde(with = "json_date_time_utc")]
+    pub time: Option<DateTime<Utc>>,
+
+    /// Working frequency or channel for calls other than distress
+    pub frequency: Option<String>,
+
+    /// MMSI of the ship in distress for distress relays and acknowledgements
+    pub distress_mmsi: Option<u32>,
+
+    /// Acknowledgement type
+    pub acknowledgement: Option<DscAcknowledgement>,
+
+    /// True if a DSE expansion sentence follows
+    pub expansion: bool,
+}
+
+impl DscData {
+    /// Return true if the call is a distress alert, distress relay or distress acknowledgement.
+    pub fn is_distress(&self) -> bool {
+        self.format == Some(DscFormat::Distress) || self.category == Some(DscCategory::Distress)
+    }
+}
+
+impl LatLon for DscData {
+    fn latitude(&self) -> Option<f64> {
+        self.latitude
+    }
+
+    fn longitude(&self) -> Option<f64> {
+        self.longitude
+    }
+}
+
+/// DSC acknowledgement type
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub enum DscAcknowledgement {
+    /// Acknowledgement request
+    Request,
+
+    /// Acknowledgement reply
+    Acknowledgement,
+
+    /// End of sequence, neither request nor reply
+    EndOfSequence,
+}
+
+impl DscAcknowledgement {
+    pub fn new(val: &str) -> Result<DscAcknowledgement, String> {
+        match val {
+            "R" => Ok(DscAcknowledgement::Request),
+            "B" => Ok(DscAcknowledgement::Acknowledgement),
+            "S" => Ok(DscAcknowledgement::EndOfSequence),
+            _ => Err(format!("Unrecognized DSC acknowledgement: {}", val)),
+        }
+    }
+}
+
+impl core::fmt::Display for DscAcknowledgement {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DscAcknowledgement::Request => write!(f, "request"),
+            DscAcknowledgement::Acknowledgement => write!(f, "acknowledgement"),
+            DscAcknowledgement::EndOfSequence => write!(f, "end of sequence"),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// xxDSC: Digital selective calling information
+pub(crate) fn handle(sentence: &str) -> Result<ParsedMessage, ParseError> {
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    let format = match pick_string_field(&split, 1) {
+        Some(s) => Some(DscFormat::new(&s)?),
+        None => None,
+    };
+    let category = match pick_string_field(&split, 3) {
+        Some(s) => Some(DscCategory::new(&s)?),
+        None => None,
+    };
+
+    let mut dsc = DscData {
+        format,
+        mmsi: None,
+        area: None,
+        category,
+        nature_of_distress: None,
+        first_telecommand: None,
+        second_telecommand: pick_number_field(&split, 5)?,
+        latitude: None,
+        longitude: None,
+        time: None,
+        frequency: None,
+        distress_mmsi: None,
+        acknowledgement: match pick_string_field(&split, 10) {
+            Some(s) => Some(DscAcknowledgement::new(&s)?),
+            None => None,
+        },
+        expansion: pick_string_field(&split, 11).as_deref() == Some("E"),
+    };
+
+    // Area calls carry the area in the address field instead of an MMSI
+    if format == Some(DscFormat::GeographicalArea) {
+        dsc.area = pick_string_field(&split, 2);
+    } else {
+        dsc.mmsi = pick_dsc_mmsi(&split, 2)?;
+    }
+
+    if format == Some(DscFormat::Distress) {
+        dsc.nature_of_distress = match pick_string_field(&split, 4) {
+            Some(s) => Some(DistressNature::new(&s)?),
+            None => None,
+        };
+    } else {
+        dsc.first_telecommand = pick_number_field(&split, 4)?;
+    }
+
+    if dsc.is_distress() {
+        if let Some(s) = pick_string_field(&split, 6) {
+            let (lat, lon) = parse_dsc_position(&s)?;
+            dsc.latitude = lat;
+            dsc.longitude = lon;
+        }
+        if let Some(s) = pick_string_field(&split, 7) {
+            dsc.time = parse_dsc_time(&s)?;
+        }
+        if format != Some(DscFormat::Distress) {
+            dsc.distress_mmsi = pick_dsc_mmsi(&split, 8)?;
+            dsc.nature_of_distress = match pick_string_field(&split, 9) {
+                Some(s) => Some(DistressNature::new(&s)?),
+                None => None,
+            };
+        }
+    } else {
+        dsc.frequency = pick_string_field(&split, 6);
+    }
+
+    Ok(ParsedMessage::Dsc(dsc))
+}
+
+/// Parse ten digit DSC position "qddmmdddmm" where the first digit is the quadrant (0 = NE,
+/// 1 = NW, 2 = SE, 3 = SW). Position "9999999999" means not available.
+fn parse_dsc_position(s: &str) -> Result<(Option<f64>, Option<f64>), ParseError> {
+    if s.len() != 10 || !s.chars().all(|c| c.is_ascii_digit()) {
+        return Err(format!("Invalid DSC position: {}", s).into());
+    }
+    if s == "9999999999" {
+        return Ok((None, None));
+    }
+    let num = |a: usize, b: usize| -> f64 { s[a..b].parse::<f64>().unwrap_or(0.0) };
+    let lat = num(1, 3) + num(3, 5) / 60.0;
+    let lon = num(5, 8) + num(8, 10) / 60.0;
+    match &s[0..1] {
+        "0" => Ok((Some(lat), Some(lon))),
+        "1" => Ok((Some(lat), Some(-lon))),
+        "2" => Ok((Some(-lat), Some(lon))),
+        "3" => Ok((Some(-lat), Some(-lon))),
+        _ => Err(format!("Invalid DSC position quadrant: {}", s).into()),
+    }
+}
+
+/// Parse four digit DSC time "hhmm". Time "8888" means not available.
+fn parse_dsc_time(s: &str) -> Result<Option<DateTime<Utc>>, ParseError> {
+    if s == "8888" {
+        return Ok(None);
+    }
+    if s.len() != 4 {
+        return Err(format!("Invalid DSC time: {}", s).into());
+    }
+    let now: DateTime<Utc> = Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).single().unwrap();
+    Ok(Some(parse_hhmmss(&format!("{}00", s), now)?))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_dsc_distress_alert() {
+        match NmeaParser::new()
+            .parse_sentence("$CDDSC,12,3380400790,12,06,00,1423108312,2019,,,S,E*6A")
+        {
+            Ok(ps) => match ps {
+                ParsedMessage::Dsc(dsc) => {
+                    assert_eq!(dsc.format, Some(DscFormat::Distress));
+                    assert_eq!(dsc.mmsi, Some(338040079));
+                    assert_eq!(dsc.category, Some(DscCategory::Distress));
+                    assert_eq!(
+                        dsc.nature_of_distress,
+                        Some(DistressNature::DisabledAndAdrift)
+                    );
+                    assert_eq!(dsc.first_telecommand, None);
+                    assert_eq!(dsc.second_telecommand, Some(0));
+                    assert::close(dsc.latitude.unwrap_or(0.0), 42.517, 0.001);
+                    assert::close(dsc.longitude.unwrap_or(0.0), -83.200, 0.001);
+                    assert_eq!(dsc.time.map(|t| t.hour()), Some(20));
+                    assert_eq!(dsc.time.map(|t| t.minute()), Some(19));
+                    assert_eq!(dsc.distress_mmsi, None);
+                    assert_eq!(dsc.acknowledgement, Some(DscAcknowledgement::EndOfSequence));
+                    assert!(dsc.expansion);
+                    assert!(dsc.is_distress());
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_dsc_distress_relay() {
+        match NmeaParser::new()
+            .parse_sentence("$CDDSC,16,0000000000,12,12,00,0223108312,2019,2320002000,01,S,*2B")
+        {
+            Ok(ps) => match ps {
+                ParsedMessage::Dsc(dsc) => {
+                    assert_eq!(dsc.format, Some(DscFormat::AllShips));
+                    assert_eq!(dsc.category, Some(DscCategory::Distress));
+                    assert_eq!(dsc.first_telecommand, Some(12));
+                    assert_eq!(dsc.distress_mmsi, Some(232000200));
+                    assert_eq!(dsc.nature_of_distress, Some(DistressNature::Flooding));
+                    assert::close(dsc.latitude.unwrap_or(0.0), 22.517, 0.001);
+                    assert::close(dsc.longitude.unwrap_or(0.0), 83.200, 0.001);
+                    assert!(!dsc.expansion);
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+}
@@ -0,0 +1,157 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+/// DSE - Expanded digital selective calling
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct DseData {
+    /// Total number of sentences
+    pub sentence_count: Option<u8>,
+
+    /// Sentence number
+    pub sentence_number: Option<u8>,
+
+    /// Query/reply flag
+    pub query_reply: Option<DseQueryFlag>,
+
+    /// MMSI of the station the expansion relates to
+    pub mmsi: Option<u32>,
+
+    /// Expansion data sets
+    pub data_sets: Vec<DseDataSet>,
+}
+
+impl DseData {
+    /// Return the enhanced position resolution (data set code 00) as additional latitude and
+    /// longitude minutes, to be added to the one minute resolution position of the preceding
+    /// DSC sentence.
+    pub fn enhanced_position(&self) -> Option<(f64, f64)> {
+        let data = self.data_sets.iter().find(|d| d.code == 0)?.data.as_ref()?;
+        if data.len() != 8 {
+            return None;
+        }
+        let lat = data[0..4].parse::<f64>().ok()? / 10000.0;
+        let lon = data[4..8].parse::<f64>().ok()? / 10000.0;
+        Some((lat, lon))
+    }
+}
+
+/// Single DSE expansion data set
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct DseDataSet {
+    /// Data set code (e.g. 00 = enhanced position resolution, 06 = number of persons on board)
+    pub code: u8,
+
+    /// Data field
+    pub data: Option<String>,
+}
+
+/// DSE query/reply flag
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub enum DseQueryFlag {
+    /// Query
+    Query,
+
+    /// Reply
+    Reply,
+
+    /// Automatic, neither query nor reply
+    Automatic,
+}
+
+impl DseQueryFlag {
+    pub fn new(val: &str) -> Result<DseQueryFlag, String> {
+        match val {
+            "Q" => Ok(DseQueryFlag::Query),
+            "R" => Ok(DseQueryFlag::Reply),
+            "A" => Ok(DseQueryFlag::Automatic),
+            _ => Err(format!("Unrecognized DSE query/reply flag: {}", val)),
+        }
+    }
+}
+
+impl core::fmt::Display for DseQueryFlag {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DseQueryFlag::Query => write!(f, "query"),
+            DseQueryFlag::Reply => write!(f, "reply"),
+            DseQueryFlag::Automatic => write!(f, "automatic"),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// xxDSE: Expanded digital selective calling
+pub(crate) fn handle(sentence: &str) -> Result<ParsedMessage, ParseError> {
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    let mut data_sets = Vec::new();
+    let mut i = 5;
+    while i < split.len() {
+        if let Some(code) = pick_number_field::<u8>(&split, i)? {
+            data_sets.push(DseDataSet {
+                code,
+                data: pick_string_field(&split, i + 1),
+            });
+        }
+        i += 2;
+    }
+
+    Ok(ParsedMessage::Dse(DseData {
+        sentence_count: pick_number_field(&split, 1)?,
+        sentence_number: pick_number_field(&split, 2)?,
+        query_reply: match pick_string_field(&split, 3) {
+            Some(s) => Some(DseQueryFlag::new(&s)?),
+            None => None,
+        },
+        mmsi: pick_dsc_mmsi(&split, 4)?,
+        data_sets,
+    }))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_dse() {
+        match NmeaParser::new().parse_sentence("$CDDSE,1,1,A,3380400790,00,46504347*15") {
+            Ok(ps) => match ps {
+                ParsedMessage::Dse(dse) => {
+                    assert_eq!(dse.sentence_count, Some(1));
+                    assert_eq!(dse.sentence_number, Some(1));
+                    assert_eq!(dse.query_reply, Some(DseQueryFlag::Automatic));
+                    assert_eq!(dse.mmsi, Some(338040079));
+                    assert_eq!(dse.data_sets.len(), 1);
+                    assert_eq!(dse.data_sets[0].code, 0);
+                    let (lat, lon) = dse.enhanced_position().unwrap_or((0.0, 0.0));
+                    assert::close(lat, 0.4650, 0.00001);
+                    assert::close(lon, 0.4347, 0.00001);
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+}
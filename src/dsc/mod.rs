@@ -0,0 +1,216 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+//! Digital selective calling (DSC) data structures
+
+pub(crate) mod call;
+pub(crate) mod expansion;
+
+use super::*;
+pub use call::{DscAcknowledgement, DscData};
+pub use expansion::{DseData, DseDataSet, DseQueryFlag};
+use serde::Serialize;
+
+// -------------------------------------------------------------------------------------------------
+
+/// DSC call format specifier
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub enum DscFormat {
+    /// Selective call to ships in a geographical area
+    GeographicalArea,
+
+    /// Distress alert
+    Distress,
+
+    /// Selective call to a group of ships
+    Group,
+
+    /// Call to all ships
+    AllShips,
+
+    /// Selective call to an individual station
+    Individual,
+
+    /// Selective call to an individual station using the automatic service
+    IndividualAutomatic,
+}
+
+impl DscFormat {
+    pub fn new(val: &str) -> Result<DscFormat, String> {
+        match val {
+            "02" => Ok(DscFormat::GeographicalArea),
+            "12" => Ok(DscFormat::Distress),
+            "14" => Ok(DscFormat::Group),
+            "16" => Ok(DscFormat::AllShips),
+            "20" => Ok(DscFormat::Individual),
+            "23" => Ok(DscFormat::IndividualAutomatic),
+            _ => Err(format!("Unrecognized DSC format specifier: {}", val)),
+        }
+    }
+}
+
+impl core::fmt::Display for DscFormat {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DscFormat::GeographicalArea => write!(f, "geographical area"),
+            DscFormat::Distress => write!(f, "distress"),
+            DscFormat::Group => write!(f, "group"),
+            DscFormat::AllShips => write!(f, "all ships"),
+            DscFormat::Individual => write!(f, "individual"),
+            DscFormat::IndividualAutomatic => write!(f, "individual automatic"),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// DSC call category
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub enum DscCategory {
+    /// Routine
+    Routine,
+
+    /// Safety
+    Safety,
+
+    /// Urgency
+    Urgency,
+
+    /// Distress
+    Distress,
+}
+
+impl DscCategory {
+    pub fn new(val: &str) -> Result<DscCategory, String> {
+        match val {
+            "00" => Ok(DscCategory::Routine),
+            "08" => Ok(DscCategory::Safety),
+            "10" => Ok(DscCategory::Urgency),
+            "12" => Ok(DscCategory::Distress),
+            _ => Err(format!("Unrecognized DSC category: {}", val)),
+        }
+    }
+}
+
+impl core::fmt::Display for DscCategory {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DscCategory::Routine => write!(f, "routine"),
+            DscCategory::Safety => write!(f, "safety"),
+            DscCategory::Urgency => write!(f, "urgency"),
+            DscCategory::Distress => write!(f, "distress"),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Nature of distress
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub enum DistressNature {
+    /// Fire, explosion
+    Fire,
+
+    /// Flooding
+    Flooding,
+
+    /// Collision
+    Collision,
+
+    /// Grounding
+    Grounding,
+
+    /// Listing, in danger of capsizing
+    Listing,
+
+    /// Sinking
+    Sinking,
+
+    /// Disabled and adrift
+    DisabledAndAdrift,
+
+    /// Undesignated distress
+    Undesignated,
+
+    /// Abandoning ship
+    AbandoningShip,
+
+    /// Piracy or armed robbery attack
+    Piracy,
+
+    /// Man overboard
+    ManOverboard,
+
+    /// Emergency position-indicating radio beacon (EPIRB) emission
+    Epirb,
+}
+
+impl DistressNature {
+    pub fn new(val: &str) -> Result<DistressNature, String> {
+        match val {
+            "00" => Ok(DistressNature::Fire),
+            "01" => Ok(DistressNature::Flooding),
+            "02" => Ok(DistressNature::Collision),
+            "03" => Ok(DistressNature::Grounding),
+            "04" => Ok(DistressNature::Listing),
+            "05" => Ok(DistressNature::Sinking),
+            "06" => Ok(DistressNature::DisabledAndAdrift),
+            "07" => Ok(DistressNature::Undesignated),
+            "08" => Ok(DistressNature::AbandoningShip),
+            "09" => Ok(DistressNature::Piracy),
+            "10" => Ok(DistressNature::ManOverboard),
+            "12" => Ok(DistressNature::Epirb),
+            _ => Err(format!("Unrecognized nature of distress: {}", val)),
+        }
+    }
+}
+
+impl core::fmt::Display for DistressNature {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DistressNature::Fire => write!(f, "fire, explosion"),
+            DistressNature::Flooding => write!(f, "flooding"),
+            DistressNature::Collision => write!(f, "collision"),
+            DistressNature::Grounding => write!(f, "grounding"),
+            DistressNature::Listing => write!(f, "listing, in danger of capsizing"),
+            DistressNature::Sinking => write!(f, "sinking"),
+            DistressNature::DisabledAndAdrift => write!(f, "disabled and adrift"),
+            DistressNature::Undesignated => write!(f, "undesignated distress"),
+            DistressNature::AbandoningShip => write!(f, "abandoning ship"),
+            DistressNature::Piracy => write!(f, "piracy/armed robbery attack"),
+            DistressNature::ManOverboard => write!(f, "man overboard"),
+            DistressNature::Epirb => write!(f, "EPIRB emission"),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Pick MMSI from a ten digit DSC address field. The last digit is always zero and it is dropped.
+pub(crate) fn pick_dsc_mmsi(split: &[&str], num: usize) -> Result<Option<u32>, ParseError> {
+    match pick_string_field(split, num) {
+        Some(s) => {
+            if s.len() != 10 || !s.chars().all(|c| c.is_ascii_digit()) {
+                return Err(format!("Invalid DSC address field: {}", s).into());
+            }
+            let mmsi = s[0..9]
+                .parse::<u32>()
+                .map_err(|_| format!("Invalid DSC address field: {}", s))?;
+            Ok(Some(mmsi))
+        }
+        None => Ok(None),
+    }
+}
@@ -25,11 +25,29 @@ pub enum ParseError {
     /// Unsupported (or unimplemented) sentence type
     UnsupportedSentenceType(String),
 
-    /// NMEA checksum doesn't match
+    /// The sentence is damaged, e.g. a mandatory field is missing
     CorruptedSentence(String),
 
+    /// NMEA checksum calculated from the sentence doesn't match the one given in the sentence
+    ChecksumMismatch { calculated: String, given: String },
+
+    /// Unsupported message type within a supported sentence type (e.g. `$PUBX,41`)
+    UnsupportedMessageType(String),
+
+    /// A field value couldn't be parsed
+    InvalidField { name: String, value: String },
+
     /// The sentence format isn't what expected
     InvalidSentence(String),
+
+    /// AIS payload is shorter than required by the message type
+    TruncatedPayload(String),
+
+    /// Byte input contains invalid UTF-8 at the given byte position
+    InvalidEncoding(usize),
+
+    /// Reading the input failed
+    Io(String),
 }
 
 impl From<String> for ParseError {
@@ -57,7 +75,20 @@ impl fmt::Display for ParseError {
                 write!(f, "Unsupported NMEA sentence type: {}", s)
             }
             ParseError::CorruptedSentence(s) => write!(f, "Corrupted NMEA sentence: {}", s),
+            ParseError::ChecksumMismatch { calculated, given } => {
+                write!(f, "NMEA checksum mismatch: {} != {}", calculated, given)
+            }
+            ParseError::UnsupportedMessageType(s) => write!(f, "Unsupported message type: {}", s),
+            ParseError::InvalidField { name, value } => {
+                write!(f, "Invalid value for {}: {}", name, value)
+            }
             ParseError::InvalidSentence(s) => write!(f, "Invalid NMEA sentence: {}", s),
+            ParseError::TruncatedPayload(s) => write!(f, "Truncated AIS payload: {}", s),
+            ParseError::InvalidEncoding(pos) => write!(f, "Invalid UTF-8 at byte {}", pos),
+            ParseError::Io(s) => write!(f, "I/O error: {}", s),
         }
     }
 }
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
@@ -0,0 +1,125 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+/// ALR - Set alarm state
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct AlrData {
+    /// Time of the alarm condition change
+    #[serde(with = "json_date_time_utc")]
+    pub timestamp: Option<DateTime<Utc>>,
+
+    /// Unique alarm number (identifier) at the alarm source
+    pub alarm_id: Option<u16>,
+
+    /// Alarm condition: true = threshold exceeded, false = not exceeded
+    pub condition_active: Option<bool>,
+
+    /// Alarm acknowledge state: true = acknowledged, false = unacknowledged
+    pub acknowledged: Option<bool>,
+
+    /// Alarm description text
+    pub description: Option<String>,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// xxALR: Set alarm state
+pub(crate) fn handle(sentence: &str) -> Result<ParsedMessage, ParseError> {
+    let now: DateTime<Utc> = Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).single().unwrap();
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    Ok(ParsedMessage::Alr(AlrData {
+        timestamp: parse_hhmmss_ss(split.get(1).unwrap_or(&""), now).ok(),
+        alarm_id: pick_number_field(&split, 2)?,
+        condition_active: {
+            let s = split.get(3).unwrap_or(&"");
+            match *s {
+                "A" => Some(true),
+                "V" => Some(false),
+                "" => None,
+                _ => {
+                    return Err(format!("Invalid ALR alarm condition: {}", s).into());
+                }
+            }
+        },
+        acknowledged: {
+            let s = split.get(4).unwrap_or(&"");
+            match *s {
+                "A" => Some(true),
+                "V" => Some(false),
+                "" => None,
+                _ => {
+                    return Err(format!("Invalid ALR acknowledge state: {}", s).into());
+                }
+            }
+        },
+        description: pick_string_field(&split, 5),
+    }))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_alr() {
+        // General test
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$IIALR,122015.00,023,A,V,Bilge level high*43") {
+            Ok(ps) => match ps {
+                ParsedMessage::Alr(alr) => {
+                    assert_eq!(
+                        alr.timestamp,
+                        Utc.with_ymd_and_hms(2000, 1, 1, 12, 20, 15).single()
+                    );
+                    assert_eq!(alr.alarm_id, Some(23));
+                    assert_eq!(alr.condition_active, Some(true));
+                    assert_eq!(alr.acknowledged, Some(false));
+                    assert_eq!(alr.description, Some("Bilge level high".into()));
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+
+        // Empty fields test
+        match p.parse_sentence("$IIALR,,7,V,A,*53") {
+            Ok(ps) => match ps {
+                ParsedMessage::Alr(alr) => {
+                    assert_eq!(alr.timestamp, None);
+                    assert_eq!(alr.alarm_id, Some(7));
+                    assert_eq!(alr.condition_active, Some(false));
+                    assert_eq!(alr.acknowledged, Some(true));
+                    assert_eq!(alr.description, None);
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+}
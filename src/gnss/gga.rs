@@ -47,10 +47,11 @@ pub struct GgaData {
     /// Height of geoid (mean sea level) above WGS84 ellipsoid
     pub geoid_separation: Option<f64>,
 
-    /// Age of differential GPS data record, Type 1 or Type 9.
+    /// Age of differential GPS data record, Type 1 or Type 9, in seconds. `None` when
+    /// differential corrections aren't used.
     pub age_of_dgps: Option<f64>,
 
-    /// Reference station ID, range 0000-4095
+    /// Differential reference station ID, range 0000-4095
     pub ref_station_id: Option<u16>,
 }
 
@@ -67,15 +68,32 @@ impl LatLon for GgaData {
 /// GGA GPS quality indicator
 #[derive(Clone, Copy, Debug, PartialEq, Serialize)]
 pub enum GgaQualityIndicator {
-    Invalid,                // 0
-    GpsFix,                 // 1
-    DGpsFix,                // 2
-    PpsFix,                 // 3
-    RealTimeKinematic,      // 4
-    RealTimeKinematicFloat, // 5
-    DeadReckoning,          // 6
-    ManualInputMode,        // 7
-    SimulationMode,         // 8
+    /// Fix not available or invalid (0)
+    Invalid,
+
+    /// Autonomous GPS fix (1)
+    GpsFix,
+
+    /// Differential GPS fix (2)
+    DGpsFix,
+
+    /// PPS fix (3)
+    PpsFix,
+
+    /// Real time kinematic with fixed integers (4)
+    RealTimeKinematic,
+
+    /// Real time kinematic with floating integers (5)
+    RealTimeKinematicFloat,
+
+    /// Estimated (dead reckoning) mode (6)
+    DeadReckoning,
+
+    /// Manual input mode (7)
+    ManualInputMode,
+
+    /// Simulator mode (8)
+    SimulationMode,
 }
 
 impl GgaQualityIndicator {
@@ -93,6 +111,37 @@ impl GgaQualityIndicator {
             _ => GgaQualityIndicator::Invalid,
         }
     }
+
+    /// Return true if the fix is based on satellite measurements, i.e. it isn't invalid,
+    /// estimated, manually entered or simulated.
+    pub fn is_valid(&self) -> bool {
+        matches!(
+            self,
+            GgaQualityIndicator::GpsFix
+                | GgaQualityIndicator::DGpsFix
+                | GgaQualityIndicator::PpsFix
+                | GgaQualityIndicator::RealTimeKinematic
+                | GgaQualityIndicator::RealTimeKinematicFloat
+        )
+    }
+
+    /// Return true if the fix uses differential corrections (DGPS or RTK).
+    pub fn is_differential(&self) -> bool {
+        matches!(
+            self,
+            GgaQualityIndicator::DGpsFix
+                | GgaQualityIndicator::RealTimeKinematic
+                | GgaQualityIndicator::RealTimeKinematicFloat
+        )
+    }
+
+    /// Return true if the fix is an RTK solution with either fixed or floating integers.
+    pub fn is_rtk(&self) -> bool {
+        matches!(
+            self,
+            GgaQualityIndicator::RealTimeKinematic | GgaQualityIndicator::RealTimeKinematicFloat
+        )
+    }
 }
 
 impl core::fmt::Display for GgaQualityIndicator {
@@ -119,13 +168,14 @@ impl core::fmt::Display for GgaQualityIndicator {
 pub(crate) fn handle(
     sentence: &str,
     nav_system: NavigationSystem,
+    store: &mut NmeaParser,
 ) -> Result<ParsedMessage, ParseError> {
     let now: DateTime<Utc> = Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).single().unwrap();
     let split: Vec<&str> = sentence.split(',').collect();
 
     Ok(ParsedMessage::Gga(GgaData {
         source: nav_system,
-        timestamp: parse_hhmmss(split.get(1).unwrap_or(&""), now).ok(),
+        timestamp: store.infer_date(parse_hhmmss(split.get(1).unwrap_or(&""), now).ok()),
         latitude: parse_latitude_ddmm_mmm(
             split.get(2).unwrap_or(&""),
             split.get(3).unwrap_or(&""),
@@ -248,4 +298,90 @@ mod test {
             }
         }
     }
+    #[test]
+    fn test_parse_gga_differential() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence(
+            "$GPGGA,092750,5321.6802,N,00630.3372,W,2,08,1.03,61.7,M,55.2,M,1.2,0021*75",
+        ) {
+            Ok(ps) => match ps {
+                ParsedMessage::Gga(gga) => {
+                    assert_eq!(gga.quality, GgaQualityIndicator::DGpsFix);
+                    assert!(gga.quality.is_differential());
+                    assert!(!gga.quality.is_rtk());
+                    assert_eq!(gga.geoid_separation, Some(55.2));
+                    assert_eq!(gga.age_of_dgps, Some(1.2));
+                    assert_eq!(gga.ref_station_id, Some(21));
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+
+        match p.parse_sentence(
+            "$GNGGA,092750,5321.6802,N,00630.3372,W,4,12,0.8,61.7,M,55.2,M,0.8,4095*5C",
+        ) {
+            Ok(ps) => match ps {
+                ParsedMessage::Gga(gga) => {
+                    assert_eq!(gga.quality, GgaQualityIndicator::RealTimeKinematic);
+                    assert!(gga.quality.is_valid());
+                    assert!(gga.quality.is_differential());
+                    assert!(gga.quality.is_rtk());
+                    assert_eq!(gga.age_of_dgps, Some(0.8));
+                    assert_eq!(gga.ref_station_id, Some(4095));
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+    #[test]
+    fn test_gga_date_inference() {
+        let gga_timestamp = |p: &mut NmeaParser, sentence: &str| match p.parse_sentence(sentence) {
+            Ok(ParsedMessage::Gga(gga)) => gga.timestamp,
+            ps => {
+                panic!("Unexpected result: {:?}", ps);
+            }
+        };
+
+        let mut p = NmeaParser::new();
+        assert!(p
+            .parse_sentence("$GPRMC,235958,A,4916.45,N,12311.12,W,000.5,054.7,191120,020.3,E*64")
+            .is_ok());
+
+        // Disabled by default
+        assert_eq!(
+            gga_timestamp(
+                &mut p,
+                "$GPGGA,235959,4916.45,N,12311.12,W,1,08,0.9,545.4,M,46.9,M,,*53"
+            ),
+            Utc.with_ymd_and_hms(2000, 1, 1, 23, 59, 59).single()
+        );
+
+        p.set_date_inference(true);
+        assert_eq!(
+            gga_timestamp(
+                &mut p,
+                "$GPGGA,235959,4916.45,N,12311.12,W,1,08,0.9,545.4,M,46.9,M,,*53"
+            ),
+            Utc.with_ymd_and_hms(2020, 11, 19, 23, 59, 59).single()
+        );
+
+        // Midnight rollover
+        assert_eq!(
+            gga_timestamp(
+                &mut p,
+                "$GPGGA,000001,4916.45,N,12311.12,W,1,08,0.9,545.4,M,46.9,M,,*53"
+            ),
+            Utc.with_ymd_and_hms(2020, 11, 20, 0, 0, 1).single()
+        );
+    }
 }
@@ -38,6 +38,14 @@ pub struct GllData {
     pub faa_mode: Option<FaaMode>,
 }
 
+impl GllData {
+    /// Return true if the position is flagged valid and, when the FAA mode indicator is present,
+    /// based on satellite measurements rather than estimation, manual input or simulation.
+    pub fn is_valid(&self) -> bool {
+        self.data_valid.unwrap_or(false) && self.faa_mode.map(|m| m.is_valid()).unwrap_or(true)
+    }
+}
+
 impl LatLon for GllData {
     fn latitude(&self) -> Option<f64> {
         self.latitude
@@ -54,6 +62,7 @@ impl LatLon for GllData {
 pub(crate) fn handle(
     sentence: &str,
     nav_system: NavigationSystem,
+    store: &mut NmeaParser,
 ) -> Result<ParsedMessage, ParseError> {
     let now: DateTime<Utc> = Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).single().unwrap();
     let split: Vec<&str> = sentence.split(',').collect();
@@ -68,7 +77,7 @@ pub(crate) fn handle(
             split.get(3).unwrap_or(&""),
             split.get(4).unwrap_or(&""),
         )?,
-        timestamp: parse_hhmmss(split.get(5).unwrap_or(&""), now).ok(),
+        timestamp: store.infer_date(parse_hhmmss(split.get(5).unwrap_or(&""), now).ok()),
         data_valid: {
             match *split.get(6).unwrap_or(&"") {
                 "A" => Some(true),
@@ -113,4 +122,39 @@ mod test {
             }
         }
     }
+    #[test]
+    fn test_parse_gll_validity() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$GPGLL,4916.45,N,12311.12,W,225444,V,N*44") {
+            Ok(ps) => match ps {
+                ParsedMessage::Gll(gll) => {
+                    assert_eq!(gll.data_valid, Some(false));
+                    assert_eq!(gll.faa_mode, Some(FaaMode::NotValid));
+                    assert!(!gll.is_valid());
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+
+        match p.parse_sentence("$GPGLL,4916.45,N,12311.12,W,225444,A,E*58") {
+            Ok(ps) => match ps {
+                ParsedMessage::Gll(gll) => {
+                    assert_eq!(gll.data_valid, Some(true));
+                    assert_eq!(gll.faa_mode, Some(FaaMode::Estimated));
+                    assert!(!gll.is_valid());
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
 }
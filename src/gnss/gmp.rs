@@ -0,0 +1,172 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+/// GMP - GNSS map projection fix data
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct GmpData {
+    /// Navigation system
+    pub source: NavigationSystem,
+
+    /// UTC of position fix
+    #[serde(with = "json_date_time_utc")]
+    pub timestamp: Option<DateTime<Utc>>,
+
+    /// Map projection identification (e.g. "UTM" or "LOC" for local grid)
+    pub projection: Option<String>,
+
+    /// Map zone (e.g. "35V")
+    pub zone: Option<String>,
+
+    /// Northing (x) component of the grid coordinates in metres
+    pub northing: Option<f64>,
+
+    /// Easting (y) component of the grid coordinates in metres
+    pub easting: Option<f64>,
+
+    /// GPS mode indicator
+    pub gps_mode: GnsModeIndicator,
+
+    /// GLONASS mode indicator
+    pub glonass_mode: GnsModeIndicator,
+
+    /// Mode indicators for other navigation systems
+    pub other_modes: Vec<GnsModeIndicator>,
+
+    /// Number of satellites in use
+    pub satellite_count: Option<u8>,
+
+    /// Horizontal dilution of position using all the satellites.
+    pub hdop: Option<f64>,
+
+    /// Altitude above mean sea level (metres)
+    pub altitude: Option<f64>,
+
+    /// Height of geoid (mean sea level) above WGS84 ellipsoid
+    pub geoid_separation: Option<f64>,
+
+    /// Age of differential GPS data record
+    pub age_of_dgps: Option<f64>,
+
+    /// Reference station ID, range 0000-4095
+    pub ref_station_id: Option<u16>,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// xxGMP: GNSS map projection fix data
+pub(crate) fn handle(
+    sentence: &str,
+    nav_system: NavigationSystem,
+    store: &mut NmeaParser,
+) -> Result<ParsedMessage, ParseError> {
+    let now: DateTime<Utc> = Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).single().unwrap();
+    let split: Vec<&str> = sentence.split(',').collect();
+    let modes: Vec<char> = split.get(6).unwrap_or(&"").chars().collect();
+
+    Ok(ParsedMessage::Gmp(GmpData {
+        source: nav_system,
+        timestamp: store.infer_date(parse_hhmmss(split.get(1).unwrap_or(&""), now).ok()),
+        projection: pick_string_field(&split, 2),
+        zone: pick_string_field(&split, 3),
+        northing: pick_number_field(&split, 4)?,
+        easting: pick_number_field(&split, 5)?,
+        gps_mode: GnsModeIndicator::new(*modes.first().unwrap_or(&' ')),
+        glonass_mode: GnsModeIndicator::new(*modes.get(1).unwrap_or(&' ')),
+        other_modes: modes
+            .into_iter()
+            .skip(2)
+            .map(GnsModeIndicator::new)
+            .collect(),
+        satellite_count: pick_number_field(&split, 7)?,
+        hdop: pick_number_field(&split, 8)?,
+        altitude: pick_number_field(&split, 9)?,
+        geoid_separation: pick_number_field(&split, 10)?,
+        age_of_dgps: pick_number_field(&split, 11)?,
+        ref_station_id: pick_number_field(&split, 12)?,
+    }))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_gngmp() {
+        match NmeaParser::new().parse_sentence(
+            "$GNGMP,123519.00,UTM,35V,6673542.123,385621.456,AA,10,1.0,532.4,17.5,,*4A",
+        ) {
+            Ok(ps) => match ps {
+                ParsedMessage::Gmp(gmp) => {
+                    assert_eq!(gmp.source, NavigationSystem::Combination);
+                    assert_eq!(
+                        gmp.timestamp,
+                        Utc.with_ymd_and_hms(2000, 1, 1, 12, 35, 19).single()
+                    );
+                    assert_eq!(gmp.projection, Some("UTM".into()));
+                    assert_eq!(gmp.zone, Some("35V".into()));
+                    assert_eq!(gmp.northing, Some(6673542.123));
+                    assert_eq!(gmp.easting, Some(385621.456));
+                    assert_eq!(gmp.gps_mode, GnsModeIndicator::Autonomous);
+                    assert_eq!(gmp.glonass_mode, GnsModeIndicator::Autonomous);
+                    assert!(gmp.other_modes.is_empty());
+                    assert_eq!(gmp.satellite_count, Some(10));
+                    assert_eq!(gmp.hdop, Some(1.0));
+                    assert_eq!(gmp.altitude, Some(532.4));
+                    assert_eq!(gmp.geoid_separation, Some(17.5));
+                    assert_eq!(gmp.age_of_dgps, None);
+                    assert_eq!(gmp.ref_station_id, None);
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_gmp_date_inference() {
+        let mut p = NmeaParser::new();
+        p.set_date_inference(true);
+        assert!(p
+            .parse_sentence("$GPRMC,123500,A,4916.45,N,12311.12,W,000.5,054.7,191120,020.3,E*61")
+            .is_ok());
+        match p.parse_sentence(
+            "$GNGMP,123519.00,UTM,35V,6673542.123,385621.456,AA,10,1.0,532.4,17.5,,*4A",
+        ) {
+            Ok(ps) => match ps {
+                ParsedMessage::Gmp(gmp) => {
+                    assert_eq!(
+                        gmp.timestamp,
+                        Utc.with_ymd_and_hms(2020, 11, 19, 12, 35, 19).single()
+                    );
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+}
@@ -70,7 +70,7 @@ impl LatLon for GnsData {
 }
 
 /// GNS mode indicator
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
 pub enum GnsModeIndicator {
     /// Satellite system not used in position fix, or fix not valid
     Invalid,
@@ -137,6 +137,7 @@ impl core::fmt::Display for GnsModeIndicator {
 pub(crate) fn handle(
     sentence: &str,
     nav_system: NavigationSystem,
+    store: &mut NmeaParser,
 ) -> Result<ParsedMessage, ParseError> {
     let now: DateTime<Utc> = Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).single().unwrap();
     let split: Vec<&str> = sentence.split(',').collect();
@@ -144,7 +145,7 @@ pub(crate) fn handle(
 
     Ok(ParsedMessage::Gns(GnsData {
         source: nav_system,
-        timestamp: parse_hhmmss(split.get(1).unwrap_or(&""), now).ok(),
+        timestamp: store.infer_date(parse_hhmmss(split.get(1).unwrap_or(&""), now).ok()),
         latitude: parse_latitude_ddmm_mmm(
             split.get(2).unwrap_or(&""),
             split.get(3).unwrap_or(&""),
@@ -29,6 +29,33 @@ pub struct GsaData {
     /// PRN numbers used (space for 12)
     pub prn_numbers: Vec<u8>,
 
+    /// Position, horizontal and vertical dilution of precision
+    pub dop: DilutionOfPrecision,
+
+    /// NMEA 4.10 GNSS system ID (1 = GPS, 2 = GLONASS, 3 = Galileo, 4 = BeiDou, 5 = QZSS,
+    /// 6 = NavIC), `None` for older receivers
+    pub system_id: Option<u8>,
+}
+
+impl GsaData {
+    /// Return the satellite system the sentence describes. Multi-constellation receivers use
+    /// the GN talker ID and identify the system with the system ID field.
+    pub fn system(&self) -> NavigationSystem {
+        match self.system_id {
+            Some(1) => NavigationSystem::Gps,
+            Some(2) => NavigationSystem::Glonass,
+            Some(3) => NavigationSystem::Galileo,
+            Some(4) => NavigationSystem::Beidou,
+            Some(5) => NavigationSystem::Qzss,
+            Some(6) => NavigationSystem::Navic,
+            _ => self.source,
+        }
+    }
+}
+
+/// Dilution of precision values reported by GSA
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize)]
+pub struct DilutionOfPrecision {
     /// Position (3D) dilution of precision
     pub pdop: Option<f64>,
 
@@ -39,6 +66,64 @@ pub struct GsaData {
     pub vdop: Option<f64>,
 }
 
+impl DilutionOfPrecision {
+    /// Classify position (3D) dilution of precision
+    pub fn pdop_quality(&self) -> Option<DopQuality> {
+        self.pdop.map(DopQuality::new)
+    }
+
+    /// Classify horizontal dilution of precision
+    pub fn hdop_quality(&self) -> Option<DopQuality> {
+        self.hdop.map(DopQuality::new)
+    }
+
+    /// Classify vertical dilution of precision
+    pub fn vdop_quality(&self) -> Option<DopQuality> {
+        self.vdop.map(DopQuality::new)
+    }
+}
+
+/// Rough classification of a dilution of precision value
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub enum DopQuality {
+    /// DOP up to 2, suitable for all but the most demanding applications
+    Excellent,
+
+    /// DOP above 2 and up to 5, suitable for route navigation
+    Good,
+
+    /// DOP above 5 and up to 10, positions should be used with care
+    Moderate,
+
+    /// DOP above 10, positions are inaccurate
+    Poor,
+}
+
+impl DopQuality {
+    pub fn new(dop: f64) -> DopQuality {
+        if dop <= 2.0 {
+            DopQuality::Excellent
+        } else if dop <= 5.0 {
+            DopQuality::Good
+        } else if dop <= 10.0 {
+            DopQuality::Moderate
+        } else {
+            DopQuality::Poor
+        }
+    }
+}
+
+impl core::fmt::Display for DopQuality {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DopQuality::Excellent => write!(f, "excellent"),
+            DopQuality::Good => write!(f, "good"),
+            DopQuality::Moderate => write!(f, "moderate"),
+            DopQuality::Poor => write!(f, "poor"),
+        }
+    }
+}
+
 /// GSA position fix type
 #[derive(Clone, Copy, Debug, PartialEq, Serialize)]
 pub enum GsaFixMode {
@@ -68,10 +153,11 @@ impl core::fmt::Display for GsaFixMode {
 pub(crate) fn handle(
     sentence: &str,
     nav_system: NavigationSystem,
+    store: &mut NmeaParser,
 ) -> Result<ParsedMessage, ParseError> {
     let split: Vec<&str> = sentence.split(',').collect();
 
-    Ok(ParsedMessage::Gsa(GsaData {
+    let gsa = GsaData {
         source: nav_system,
         mode1_automatic: {
             let s = split.get(1).unwrap_or(&"");
@@ -107,10 +193,15 @@ pub(crate) fn handle(
             }
             v
         },
-        pdop: pick_number_field(&split, 15)?,
-        hdop: pick_number_field(&split, 16)?,
-        vdop: pick_number_field(&split, 17)?,
-    }))
+        dop: DilutionOfPrecision {
+            pdop: pick_number_field(&split, 15)?,
+            hdop: pick_number_field(&split, 16)?,
+            vdop: pick_number_field(&split, 17)?,
+        },
+        system_id: pick_hex_field(&split, 18)?,
+    };
+    store.push_gsa(gsa.system(), gsa.clone());
+    Ok(ParsedMessage::Gsa(gsa))
 }
 
 // -------------------------------------------------------------------------------------------------
@@ -130,9 +221,10 @@ mod test {
                         assert_eq!(gsa.mode1_automatic, Some(true));
                         assert_eq!(gsa.mode2_3d, Some(GsaFixMode::Fix3D));
                         assert_eq!(gsa.prn_numbers, vec![19, 28, 14, 18, 27, 22, 31, 39]);
-                        assert_eq!(gsa.pdop, Some(1.7));
-                        assert_eq!(gsa.hdop, Some(1.0));
-                        assert_eq!(gsa.vdop, Some(1.3));
+                        assert_eq!(gsa.dop.pdop, Some(1.7));
+                        assert_eq!(gsa.dop.hdop, Some(1.0));
+                        assert_eq!(gsa.dop.vdop, Some(1.3));
+                        assert_eq!(gsa.dop.pdop_quality(), Some(DopQuality::Excellent));
                     }
                     ParsedMessage::Incomplete => {
                         assert!(false);
@@ -147,4 +239,54 @@ mod test {
             }
         }
     }
+    #[test]
+    fn test_parse_gngsa_system_id() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$GNGSA,A,3,05,07,13,14,15,17,19,30,,,,,1.2,0.8,0.9,1*3F") {
+            Ok(ps) => match ps {
+                ParsedMessage::Gsa(gsa) => {
+                    assert_eq!(gsa.source, NavigationSystem::Combination);
+                    assert_eq!(gsa.system_id, Some(1));
+                    assert_eq!(gsa.system(), NavigationSystem::Gps);
+                    assert_eq!(gsa.prn_numbers.len(), 8);
+                    assert_eq!(gsa.dop.vdop, Some(0.9));
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+        assert!(p
+            .parse_sentence("$GNGSA,A,3,65,66,67,,,,,,,,,,1.2,0.8,0.9,2*32")
+            .is_ok());
+
+        // Both systems are kept by the parser
+        assert_eq!(
+            p.latest_gsa(NavigationSystem::Gps)
+                .map(|g| g.prn_numbers.len()),
+            Some(8)
+        );
+        assert_eq!(
+            p.latest_gsa(NavigationSystem::Glonass)
+                .map(|g| g.prn_numbers.clone()),
+            Some(vec![65, 66, 67])
+        );
+        assert!(p.latest_gsa(NavigationSystem::Galileo).is_none());
+    }
+    #[test]
+    fn test_dop_quality() {
+        let dop = DilutionOfPrecision {
+            pdop: Some(4.2),
+            hdop: Some(7.5),
+            vdop: Some(12.0),
+        };
+        assert_eq!(dop.pdop_quality(), Some(DopQuality::Good));
+        assert_eq!(dop.hdop_quality(), Some(DopQuality::Moderate));
+        assert_eq!(dop.vdop_quality(), Some(DopQuality::Poor));
+        assert_eq!(DilutionOfPrecision::default().hdop_quality(), None);
+        assert_eq!(DopQuality::new(2.0).to_string(), "excellent");
+    }
 }
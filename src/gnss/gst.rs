@@ -0,0 +1,155 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+/// GST - GNSS pseudorange error statistics
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct GstData {
+    /// Navigation system
+    pub source: NavigationSystem,
+
+    /// UTC of the associated position fix
+    #[serde(with = "json_date_time_utc")]
+    pub timestamp: Option<DateTime<Utc>>,
+
+    /// RMS value of the standard deviation of the range inputs to the navigation process
+    pub rms: Option<f64>,
+
+    /// Position accuracy estimate
+    pub accuracy: PositionAccuracy,
+}
+
+/// Position accuracy estimate as an error ellipse and per-axis standard deviations. All the
+/// values are one sigma (1σ) errors in metres.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize)]
+pub struct PositionAccuracy {
+    /// Standard deviation of the semi-major axis of the error ellipse
+    pub semi_major_sigma: Option<f64>,
+
+    /// Standard deviation of the semi-minor axis of the error ellipse
+    pub semi_minor_sigma: Option<f64>,
+
+    /// Orientation of the semi-major axis of the error ellipse in degrees from true north
+    pub semi_major_orientation: Option<f64>,
+
+    /// Standard deviation of latitude error
+    pub latitude_sigma: Option<f64>,
+
+    /// Standard deviation of longitude error
+    pub longitude_sigma: Option<f64>,
+
+    /// Standard deviation of altitude error
+    pub altitude_sigma: Option<f64>,
+}
+
+impl PositionAccuracy {
+    /// Return the worst-case horizontal one sigma error, i.e. the semi-major axis of the error
+    /// ellipse or, if it isn't available, the larger of the latitude and longitude errors.
+    pub fn horizontal_sigma(&self) -> Option<f64> {
+        self.semi_major_sigma
+            .or(match (self.latitude_sigma, self.longitude_sigma) {
+                (Some(lat), Some(lon)) => Some(lat.max(lon)),
+                (lat, lon) => lat.or(lon),
+            })
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// xxGST: GNSS pseudorange error statistics
+pub(crate) fn handle(
+    sentence: &str,
+    nav_system: NavigationSystem,
+    store: &mut NmeaParser,
+) -> Result<ParsedMessage, ParseError> {
+    let now: DateTime<Utc> = Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).single().unwrap();
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    let accuracy = PositionAccuracy {
+        semi_major_sigma: pick_number_field(&split, 3)?,
+        semi_minor_sigma: pick_number_field(&split, 4)?,
+        semi_major_orientation: pick_number_field(&split, 5)?,
+        latitude_sigma: pick_number_field(&split, 6)?,
+        longitude_sigma: pick_number_field(&split, 7)?,
+        altitude_sigma: pick_number_field(&split, 8)?,
+    };
+    store.push_accuracy(accuracy);
+
+    Ok(ParsedMessage::Gst(GstData {
+        source: nav_system,
+        timestamp: store.infer_date(parse_hhmmss(split.get(1).unwrap_or(&""), now).ok()),
+        rms: pick_number_field(&split, 2)?,
+        accuracy,
+    }))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_gst() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$GPGST,172814.0,0.006,0.023,0.020,273.6,0.023,0.020,0.031*6A") {
+            Ok(ps) => match ps {
+                ParsedMessage::Gst(gst) => {
+                    assert_eq!(gst.source, NavigationSystem::Gps);
+                    assert_eq!(
+                        gst.timestamp,
+                        Utc.with_ymd_and_hms(2000, 1, 1, 17, 28, 14).single()
+                    );
+                    assert_eq!(gst.rms, Some(0.006));
+                    assert_eq!(gst.accuracy.semi_major_sigma, Some(0.023));
+                    assert_eq!(gst.accuracy.semi_minor_sigma, Some(0.020));
+                    assert_eq!(gst.accuracy.semi_major_orientation, Some(273.6));
+                    assert_eq!(gst.accuracy.latitude_sigma, Some(0.023));
+                    assert_eq!(gst.accuracy.longitude_sigma, Some(0.020));
+                    assert_eq!(gst.accuracy.altitude_sigma, Some(0.031));
+                    assert_eq!(gst.accuracy.horizontal_sigma(), Some(0.023));
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+        assert_eq!(
+            p.latest_accuracy().and_then(|a| a.altitude_sigma),
+            Some(0.031)
+        );
+
+        match p.parse_sentence("$GNGST,000002.00,,,,,,,*65") {
+            Ok(ps) => match ps {
+                ParsedMessage::Gst(gst) => {
+                    assert_eq!(gst.rms, None);
+                    assert_eq!(gst.accuracy, PositionAccuracy::default());
+                    assert_eq!(gst.accuracy.horizontal_sigma(), None);
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+}
@@ -32,10 +32,51 @@ pub struct GsvData {
 
     /// SNR, 0-99 dB, None when not tracking
     pub snr: Option<f32>,
+
+    /// NMEA 4.10 signal ID, `None` for older receivers. Use `signal_name()` to resolve the
+    /// frequency band.
+    pub signal_id: Option<u8>,
+}
+
+impl GsvData {
+    /// Return the name of the signal band (e.g. "L1 C/A" or "E5a") based on the signal ID and
+    /// the satellite system. Signal ID 0 means all signals.
+    pub fn signal_name(&self) -> Option<&'static str> {
+        let signal_id = self.signal_id?;
+        if signal_id == 0 {
+            return Some("all signals");
+        }
+        let names: &[&'static str] = match self.source {
+            NavigationSystem::Gps => &[
+                "L1 C/A", "L1 P(Y)", "L1 M", "L2 P(Y)", "L2C-M", "L2C-L", "L5-I", "L5-Q",
+            ],
+            NavigationSystem::Glonass => &["G1 C/A", "G1 P", "G2 C/A", "G2 P"],
+            NavigationSystem::Galileo => {
+                &["E5a", "E5b", "E5 a+b", "E6-A", "E6-BC", "E1-A", "E1-BC"]
+            }
+            NavigationSystem::Beidou => &[
+                "B1I", "B1Q", "B1C", "B1A", "B2-a", "B2-b", "B2 a+b", "B3I", "B3Q", "B3A", "B2I",
+                "B2Q",
+            ],
+            NavigationSystem::Qzss => &[
+                "L1 C/A", "L1C (D)", "L1C (P)", "LIS", "L2C-M", "L2C-L", "L5-I", "L5-Q", "", "L6D",
+                "L6E",
+            ],
+            NavigationSystem::Navic => &["L5-SPS", "S-SPS", "L5-RS", "S-RS", "L1-SPS"],
+            _ => &[],
+        };
+        names
+            .get(signal_id as usize - 1)
+            .filter(|n| !n.is_empty())
+            .copied()
+    }
 }
 
 // -------------------------------------------------------------------------------------------------
 
+/// Maximum number of sentences in a GSV cycle. The sentence count is a single digit field.
+const MAX_GSV_SENTENCES: u32 = 9;
+
 /// xxGSV: GPS Satellites in view
 pub(crate) fn handle(
     sentence: &str,
@@ -47,52 +88,104 @@ pub(crate) fn handle(
     let msg_type = split.first().unwrap_or(&"");
     let msg_count = pick_number_field(&split, 1)?.unwrap_or(0);
     let msg_num = pick_number_field(&split, 2)?.unwrap_or(0);
-    store.push_string(make_gsv_key(msg_type, msg_count, msg_num), sentence.into());
+    let signal_id = pick_gsv_signal_id(&split)?;
+    if msg_num == 0 || msg_num > msg_count || msg_count > MAX_GSV_SENTENCES {
+        return Err(ParseError::InvalidSentence(format!(
+            "Invalid GSV sentence number {} of {}",
+            msg_num, msg_count
+        )));
+    }
+
+    // Sentences left over from an earlier, incomplete cycle are discarded. A new cycle is
+    // detected by a repeated sentence number or by a changed sentence count.
+    let key = make_gsv_key(msg_type, signal_id, msg_count, msg_num);
+    let group = make_gsv_key_prefix(msg_type, signal_id);
+    if store.contains_key(key.clone()) {
+        store.retain_strings(|k| !k.starts_with(&group));
+    } else {
+        let cycle = format!("{}{},", group, msg_count);
+        store.retain_strings(|k| !k.starts_with(&group) || k.starts_with(&cycle));
+    }
+    store.push_string(key, sentence.into());
 
     let mut found_count = 0;
-    for i in 1..(msg_count + 1) {
-        if store.contains_key(make_gsv_key(msg_type, msg_count, i)) {
+    for i in 1..=msg_count {
+        if store.contains_key(make_gsv_key(msg_type, signal_id, msg_count, i)) {
             found_count += 1;
         }
     }
 
     if found_count == msg_count {
         let mut v = Vec::new();
-        for i in 1..(msg_count + 1) {
-            if let Some(sentence) = store.pull_string(make_gsv_key(msg_type, msg_count, i)) {
+        for i in 1..=msg_count {
+            if let Some(sentence) =
+                store.pull_string(make_gsv_key(msg_type, signal_id, msg_count, i))
+            {
                 let split: Vec<&str> = sentence.split(',').collect();
-                for j in 0..4 {
-                    if let Some(prn) = pick_number_field(&split, 4 + 4 * j as usize)
-                        .ok()
-                        .unwrap_or(None)
-                    {
+                let field_count = if signal_id.is_some() {
+                    split.len() - 1
+                } else {
+                    split.len()
+                };
+                for j in 0..field_count.saturating_sub(4).div_ceil(4) {
+                    if let Some(prn) = pick_number_field(&split, 4 + 4 * j).ok().unwrap_or(None) {
                         v.push(GsvData {
                             source: nav_system,
                             prn_number: prn,
-                            elevation: pick_number_field(&split, 4 + 4 * j as usize + 1)
+                            elevation: pick_number_field(&split, 4 + 4 * j + 1)
                                 .ok()
                                 .unwrap_or(None),
-                            azimuth: pick_number_field(&split, 4 + 4 * j as usize + 2)
+                            azimuth: pick_number_field(&split, 4 + 4 * j + 2)
                                 .ok()
                                 .unwrap_or(None),
-                            snr: pick_number_field(&split, 4 + 4 * j as usize + 3)
+                            snr: pick_number_field(&split, 4 + 4 * j + 3)
                                 .ok()
                                 .unwrap_or(None),
+                            signal_id,
                         });
                     }
                 }
             }
         }
 
+        store.push_gsv(nav_system, signal_id, v.clone());
         Ok(ParsedMessage::Gsv(v))
     } else {
         Ok(ParsedMessage::Incomplete)
     }
 }
 
+/// Pick NMEA 4.10 signal ID which follows the satellite blocks of four fields.
+fn pick_gsv_signal_id(split: &[&str]) -> Result<Option<u8>, ParseError> {
+    if split.len() > 4 && (split.len() - 4) % 4 == 1 {
+        Ok(pick_hex_field(split, split.len() - 1)?)
+    } else {
+        Ok(None)
+    }
+}
+
 /// Make key for store
-fn make_gsv_key(sentence_type: &str, msg_count: u32, msg_num: u32) -> String {
-    format!("{},{},{}", sentence_type, msg_count, msg_num)
+fn make_gsv_key(
+    sentence_type: &str,
+    signal_id: Option<u8>,
+    msg_count: u32,
+    msg_num: u32,
+) -> String {
+    format!(
+        "{}{},{}",
+        make_gsv_key_prefix(sentence_type, signal_id),
+        msg_count,
+        msg_num
+    )
+}
+
+/// Make store key prefix shared by all sentences of the same talker and signal
+fn make_gsv_key_prefix(sentence_type: &str, signal_id: Option<u8>) -> String {
+    format!(
+        "{},{},",
+        sentence_type,
+        signal_id.map(|id| id.to_string()).unwrap_or_default()
+    )
 }
 
 // -------------------------------------------------------------------------------------------------
@@ -176,4 +269,136 @@ mod test {
         }
         assert_eq!(p.strings_count(), 0);
     }
+    #[test]
+    fn test_parse_gsv_signal_id() {
+        let mut p = NmeaParser::new();
+
+        match p.parse_sentence("$GPGSV,1,1,03,10,45,120,40,12,30,200,35,25,60,310,42,1*51") {
+            Ok(ps) => match ps {
+                ParsedMessage::Gsv(v) => {
+                    assert_eq!(v.len(), 3);
+                    assert_eq!(v[2].prn_number, 25);
+                    assert_eq!(v[2].snr, Some(42.0));
+                    assert_eq!(v[2].signal_id, Some(1));
+                    assert_eq!(v[2].signal_name(), Some("L1 C/A"));
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+
+        // Signal ID mustn't be taken as a PRN when the sentence has less than four satellites
+        match p.parse_sentence("$GPGSV,1,1,02,10,45,120,38,25,60,310,41,8*61") {
+            Ok(ps) => match ps {
+                ParsedMessage::Gsv(v) => {
+                    assert_eq!(v.len(), 2);
+                    assert_eq!(v[1].prn_number, 25);
+                    assert_eq!(v[1].signal_name(), Some("L5-Q"));
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+
+        match p.parse_sentence("$GAGSV,1,1,01,05,22,033,30,7*44") {
+            Ok(ps) => match ps {
+                ParsedMessage::Gsv(v) => {
+                    assert_eq!(v.len(), 1);
+                    assert_eq!(v[0].signal_name(), Some("E1-BC"));
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+        assert_eq!(p.strings_count(), 0);
+    }
+
+    #[test]
+    fn test_parse_gsv_cycles() {
+        let mut p = NmeaParser::new();
+
+        // Out-of-order arrival
+        match p.parse_sentence("$GPGSV,2,2,05,22,42,067,42*4D") {
+            Ok(ParsedMessage::Incomplete) => {}
+            ps => {
+                panic!("Unexpected result: {:?}", ps);
+            }
+        }
+        assert_eq!(p.strings_count(), 1);
+
+        // Repeated sentence number starts a new cycle and drops the stale sentence
+        match p.parse_sentence("$GPGSV,2,2,05,24,14,311,43*4B") {
+            Ok(ParsedMessage::Incomplete) => {}
+            ps => {
+                panic!("Unexpected result: {:?}", ps);
+            }
+        }
+        assert_eq!(p.strings_count(), 1);
+
+        match p
+            .parse_sentence("$GPGSV,2,1,05,03,03,111,00,04,15,270,00,06,01,010,00,13,06,292,00*70")
+        {
+            Ok(ps) => match ps {
+                ParsedMessage::Gsv(v) => {
+                    assert_eq!(v.len(), 5);
+                    assert_eq!(v[0].prn_number, 3);
+                    assert_eq!(v[4].prn_number, 24);
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+        assert_eq!(p.strings_count(), 0);
+
+        // Changed sentence count drops the incomplete cycle
+        match p
+            .parse_sentence("$GPGSV,2,1,05,03,03,111,00,04,15,270,00,06,01,010,00,13,06,292,00*70")
+        {
+            Ok(ParsedMessage::Incomplete) => {}
+            ps => {
+                panic!("Unexpected result: {:?}", ps);
+            }
+        }
+        match p.parse_sentence("$GPGSV,1,1,01,27,05,244,30*49") {
+            Ok(ps) => match ps {
+                ParsedMessage::Gsv(v) => {
+                    assert_eq!(v.len(), 1);
+                    assert_eq!(v[0].prn_number, 27);
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+        assert_eq!(p.strings_count(), 0);
+
+        // Sentence number out of range
+        assert!(p.parse_sentence("$GPGSV,2,3,05,24,14,311,43*4A").is_err());
+        assert!(p
+            .parse_sentence("$GPGSV,4294967295,1,05,24,14,311,43")
+            .is_err());
+        assert!(p
+            .parse_sentence("$GPGSV,2147483647,1,05,24,14,311,43")
+            .is_err());
+        assert_eq!(p.strings_count(), 0);
+    }
 }
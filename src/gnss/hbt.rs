@@ -0,0 +1,79 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+/// HBT - Heartbeat supervision sentence
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct HbtData {
+    /// Configured repeat interval in seconds
+    pub repeat_interval: Option<f64>,
+
+    /// Equipment status: true = normal, false = system fail
+    pub status_normal: Option<bool>,
+
+    /// Sequential sequence identifier (0-9)
+    pub sequence_id: Option<u8>,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// xxHBT: Heartbeat supervision sentence
+pub(crate) fn handle(sentence: &str) -> Result<ParsedMessage, ParseError> {
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    Ok(ParsedMessage::Hbt(HbtData {
+        repeat_interval: pick_number_field(&split, 1)?,
+        status_normal: {
+            let s = split.get(2).unwrap_or(&"");
+            match *s {
+                "A" => Some(true),
+                "V" => Some(false),
+                "" => None,
+                _ => {
+                    return Err(format!("Invalid HBT equipment status: {}", s).into());
+                }
+            }
+        },
+        sequence_id: pick_number_field(&split, 3)?,
+    }))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_hbt() {
+        match NmeaParser::new().parse_sentence("$AIHBT,30.0,A,3*15") {
+            Ok(ps) => match ps {
+                ParsedMessage::Hbt(hbt) => {
+                    assert_eq!(hbt.repeat_interval, Some(30.0));
+                    assert_eq!(hbt.status_normal, Some(true));
+                    assert_eq!(hbt.sequence_id, Some(3));
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+}
@@ -35,14 +35,30 @@ pub(crate) mod mtw;
 pub(crate) mod vhw;
 pub(crate) mod hdt;
 pub(crate) mod mwv;
+pub(crate) mod rsd;
+pub(crate) mod alr;
+pub(crate) mod ack;
+pub(crate) mod txt;
+pub(crate) mod ztg;
+pub(crate) mod zfo;
+pub(crate) mod hbt;
+pub(crate) mod trf;
+pub(crate) mod gmp;
+pub(crate) mod tlb;
+pub(crate) mod xdr;
+pub(crate) mod siv;
+pub(crate) mod gst;
+pub(crate) mod pvt;
+pub(crate) mod rte;
+pub(crate) mod wpl;
 
 use super::*;
 pub use gga::{GgaData, GgaQualityIndicator};
 pub use gll::GllData;
-pub use gns::GnsData;
-pub use gsa::{GsaData, GsaFixMode};
+pub use gns::{GnsData, GnsModeIndicator};
+pub use gsa::{DilutionOfPrecision, DopQuality, GsaData, GsaFixMode};
 pub use gsv::GsvData;
-pub use rmc::RmcData;
+pub use rmc::{RmcData, RmcNavStatus};
 use serde::Serialize;
 pub use vtg::VtgData;
 pub use alm::AlmData;
@@ -57,11 +73,27 @@ pub use mtw::MtwData;
 pub use vhw::VhwData;
 pub use hdt::HdtData;
 pub use mwv::MwvData;
+pub use rsd::{DisplayRotation, RangeUnit, RsdData};
+pub use alr::AlrData;
+pub use ack::AckData;
+pub use txt::{TextMessage, TextSeverity};
+pub use ztg::ZtgData;
+pub use zfo::ZfoData;
+pub use hbt::HbtData;
+pub use trf::TrfData;
+pub use gmp::GmpData;
+pub use tlb::{TargetLabel, TlbData};
+pub use xdr::{TransducerMeasurement, XdrData};
+pub use siv::{SatelliteInView, SatellitesInView, SignalObservation};
+pub use gst::{GstData, PositionAccuracy};
+pub use pvt::{PvtAssembler, PvtSolution};
+pub use rte::{Route, RteData};
+pub use wpl::WplData;
 
 // -------------------------------------------------------------------------------------------------
 
 /// Navigation system, identified with NMEA GNSS sentence prefix (e.g. $BDGGA)
-#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize)]
 pub enum NavigationSystem {
     /// Combination of several satellite systems
     Combination, // GNxxx
@@ -76,13 +108,13 @@ pub enum NavigationSystem {
     Galileo, // GAxxx
 
     // Chinese BeiDou
-    Beidou, // BDxxx
+    Beidou, // BDxxx or GBxxx
 
     // Indian NavIC
     Navic, // GIxxx
 
     // Japanese Qzss
-    Qzss, // QZxxx
+    Qzss, // QZxxx or GQxxx
 
     /// Proprietary manufacturer specific message
     Proprietary, // PMMM, P usually followed by a three character manufacturer code
@@ -129,9 +161,9 @@ impl core::str::FromStr for NavigationSystem {
                 "GP" => Ok(Self::Gps),
                 "GL" => Ok(Self::Glonass),
                 "GA" => Ok(Self::Galileo),
-                "BD" => Ok(Self::Beidou),
+                "BD" | "GB" => Ok(Self::Beidou),
                 "GI" => Ok(Self::Navic),
-                "QZ" => Ok(Self::Qzss),
+                "QZ" | "GQ" => Ok(Self::Qzss),
                 _ => Ok(Self::Other),
             }
         }
@@ -139,7 +171,7 @@ impl core::str::FromStr for NavigationSystem {
 }
 
 // -------------------------------------------------------------------------------------------------
-/// VTG/GLL FAA mode (NMEA 2.3 standard has this information)
+/// VTG/GLL/RMC FAA mode (NMEA 2.3 standard has this information)
 #[derive(Clone, Copy, Debug, PartialEq, Serialize)]
 pub enum FaaMode {
     /// Autonomous mode (automatic 2D/3D)
@@ -156,6 +188,18 @@ pub enum FaaMode {
 
     /// Simulated data.
     Simulator,
+
+    /// Manual input mode.
+    Manual,
+
+    /// Precise mode, no deliberate degradation.
+    Precise,
+
+    /// Real time kinematic with fixed integers.
+    RealTimeKinematic,
+
+    /// Real time kinematic with floating integers.
+    RealTimeKinematicFloat,
 }
 
 impl FaaMode {
@@ -165,9 +209,27 @@ impl FaaMode {
             "D" => Ok(FaaMode::Differential),
             "E" => Ok(FaaMode::Estimated),
             "N" => Ok(FaaMode::NotValid),
+            "S" => Ok(FaaMode::Simulator),
+            "M" => Ok(FaaMode::Manual),
+            "P" => Ok(FaaMode::Precise),
+            "R" => Ok(FaaMode::RealTimeKinematic),
+            "F" => Ok(FaaMode::RealTimeKinematicFloat),
             _ => Err(format!("Unrecognized FAA information value: {}", val)),
         }
     }
+
+    /// Return true if the data is based on satellite measurements, i.e. it isn't estimated,
+    /// manually entered, simulated or invalid.
+    pub fn is_valid(&self) -> bool {
+        matches!(
+            self,
+            FaaMode::Autonomous
+                | FaaMode::Differential
+                | FaaMode::Precise
+                | FaaMode::RealTimeKinematic
+                | FaaMode::RealTimeKinematicFloat
+        )
+    }
 }
 
 impl core::fmt::Display for FaaMode {
@@ -177,7 +239,11 @@ impl core::fmt::Display for FaaMode {
             FaaMode::Differential => write!(f, "D"),
             FaaMode::Estimated => write!(f, "E"),
             FaaMode::NotValid => write!(f, "N"),
-            _ => write!(f, "?"),
+            FaaMode::Simulator => write!(f, "S"),
+            FaaMode::Manual => write!(f, "M"),
+            FaaMode::Precise => write!(f, "P"),
+            FaaMode::RealTimeKinematic => write!(f, "R"),
+            FaaMode::RealTimeKinematicFloat => write!(f, "F"),
         }
     }
 }
@@ -16,31 +16,31 @@ limitations under the License.
 
 use super::*;
 
-/// MSS - Multiple Data ID
+/// MSS - MSK receiver signal status
 #[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct MssData {
     /// Navigation system
     pub source: NavigationSystem,
 
-    /// Signal strength (dB)
-    pub ss: Option<u8>,
+    /// Signal strength (dB re 1 µV/m)
+    pub ss: Option<f64>,
 
-    /// Signal-to-noise ratio
-    pub snr: Option<u8>,
+    /// Signal-to-noise ratio (dB)
+    pub snr: Option<f64>,
 
-    /// Beacon frequency
+    /// Beacon frequency (kHz)
     pub frequency: Option<f64>,
 
-    /// Beacon bit rate
+    /// Beacon bit rate (bits per second)
     pub bit_rate: Option<u32>,
 
-    /// Channel number
+    /// Channel number, only present on multi-channel receivers
     pub channel: Option<u32>,
 }
 
 // -------------------------------------------------------------------------------------------------
 
-/// xxMSS: Multiple Data ID
+/// xxMSS: MSK receiver signal status
 pub(crate) fn handle(
     sentence: &str,
     nav_system: NavigationSystem,
@@ -69,8 +69,8 @@ mod test {
             Ok(ps) => match ps {
                 ParsedMessage::Mss(mss) => {
                     assert_eq!(mss.source, NavigationSystem::Gps);
-                    assert_eq!(mss.ss, Some(55));
-                    assert_eq!(mss.snr, Some(27));
+                    assert_eq!(mss.ss, Some(55.0));
+                    assert_eq!(mss.snr, Some(27.0));
                     assert_eq!(mss.frequency, Some(318.0));
                     assert_eq!(mss.bit_rate, Some(100));
                     assert_eq!(mss.channel, Some(1));
@@ -87,4 +87,43 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_parse_mss_decimal_values() {
+        // Receivers without a beacon lock report zeros with decimals
+        match NmeaParser::new().parse_sentence("$GPMSS,0.0,0.0,0.000,0,*68") {
+            Ok(ps) => match ps {
+                ParsedMessage::Mss(mss) => {
+                    assert_eq!(mss.ss, Some(0.0));
+                    assert_eq!(mss.snr, Some(0.0));
+                    assert_eq!(mss.frequency, Some(0.0));
+                    assert_eq!(mss.bit_rate, Some(0));
+                    assert_eq!(mss.channel, None);
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+
+        match NmeaParser::new().parse_sentence("$GPMSS,48.5,12.3,289.0,200*4C") {
+            Ok(ps) => match ps {
+                ParsedMessage::Mss(mss) => {
+                    assert_eq!(mss.ss, Some(48.5));
+                    assert_eq!(mss.snr, Some(12.3));
+                    assert_eq!(mss.frequency, Some(289.0));
+                    assert_eq!(mss.bit_rate, Some(200));
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
 }
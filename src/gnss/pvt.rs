@@ -0,0 +1,245 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+/// Position, velocity and time solution of a single epoch assembled from GGA, GNS, RMC, GLL,
+/// VTG, GSA and GST sentences by `PvtAssembler`
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct PvtSolution {
+    /// UTC of the fix. The date is available when the epoch contains RMC.
+    #[serde(with = "json_date_time_utc")]
+    pub timestamp: Option<DateTime<Utc>>,
+
+    /// Latitude in degrees
+    pub latitude: Option<f64>,
+
+    /// Longitude in degrees
+    pub longitude: Option<f64>,
+
+    /// Altitude above mean sea level (metres)
+    pub altitude: Option<f64>,
+
+    /// Speed over ground in knots
+    pub sog_knots: Option<f64>,
+
+    /// Course over ground in degrees True
+    pub cog_true: Option<f64>,
+
+    /// Dilution of precision
+    pub dop: DilutionOfPrecision,
+
+    /// Position accuracy estimate
+    pub accuracy: Option<PositionAccuracy>,
+
+    /// Number of satellites in use as reported by GGA or GNS
+    pub satellite_count: Option<u8>,
+
+    /// Satellite systems and PRN numbers of the satellites used in the fix as reported by GSA
+    pub satellites_used: Vec<(NavigationSystem, u8)>,
+}
+
+impl LatLon for PvtSolution {
+    fn latitude(&self) -> Option<f64> {
+        self.latitude
+    }
+
+    fn longitude(&self) -> Option<f64> {
+        self.longitude
+    }
+}
+
+/// Assembles parsed sentences into one `PvtSolution` per epoch. The sentences of an epoch are
+/// recognized by their time of day: the solution of an epoch is returned when the first
+/// sentence of the next epoch is pushed, or when `flush` is called.
+#[derive(Clone, Debug, Default)]
+pub struct PvtAssembler {
+    current: Option<PvtSolution>,
+}
+
+impl PvtAssembler {
+    /// Create a new assembler
+    pub fn new() -> PvtAssembler {
+        PvtAssembler::default()
+    }
+
+    /// Push a parsed message to the assembler. Returns the solution of the previous epoch when
+    /// the message starts a new one. Messages not contributing to the solution are ignored.
+    pub fn push(&mut self, message: &ParsedMessage) -> Option<PvtSolution> {
+        let timestamp = match message {
+            ParsedMessage::Gga(gga) => gga.timestamp,
+            ParsedMessage::Gns(gns) => gns.timestamp,
+            ParsedMessage::Rmc(rmc) => rmc.timestamp,
+            ParsedMessage::Gll(gll) => gll.timestamp,
+            ParsedMessage::Gst(gst) => gst.timestamp,
+            ParsedMessage::Vtg(_) | ParsedMessage::Gsa(_) => None,
+            _ => return None,
+        };
+
+        let new_epoch = match (timestamp, self.current.as_ref().and_then(|s| s.timestamp)) {
+            (Some(t), Some(current)) => t.time() != current.time(),
+            _ => false,
+        };
+        let completed = if new_epoch { self.flush() } else { None };
+
+        let solution = self.current.get_or_insert_with(PvtSolution::default);
+        if solution.timestamp.is_none() {
+            solution.timestamp = timestamp;
+        }
+        match message {
+            ParsedMessage::Gga(gga) => {
+                solution.latitude = gga.latitude;
+                solution.longitude = gga.longitude;
+                solution.altitude = gga.altitude;
+                solution.satellite_count = gga.satellite_count;
+                solution.dop.hdop = solution.dop.hdop.or(gga.hdop);
+            }
+            ParsedMessage::Gns(gns) => {
+                solution.latitude = gns.latitude;
+                solution.longitude = gns.longitude;
+                solution.altitude = gns.altitude;
+                solution.satellite_count = gns.satellite_count;
+                solution.dop.hdop = solution.dop.hdop.or(gns.hdop);
+            }
+            ParsedMessage::Rmc(rmc) => {
+                // RMC timestamp carries also the date
+                solution.timestamp = rmc.timestamp.or(solution.timestamp);
+                solution.latitude = solution.latitude.or(rmc.latitude);
+                solution.longitude = solution.longitude.or(rmc.longitude);
+                solution.sog_knots = rmc.sog_knots;
+                solution.cog_true = rmc.bearing;
+            }
+            ParsedMessage::Gll(gll) => {
+                solution.latitude = solution.latitude.or(gll.latitude);
+                solution.longitude = solution.longitude.or(gll.longitude);
+            }
+            ParsedMessage::Vtg(vtg) => {
+                solution.sog_knots = vtg.sog_knots.or(solution.sog_knots);
+                solution.cog_true = vtg.cog_true.or(solution.cog_true);
+            }
+            ParsedMessage::Gsa(gsa) => {
+                let system = gsa.system();
+                solution.dop.pdop = gsa.dop.pdop.or(solution.dop.pdop);
+                solution.dop.hdop = gsa.dop.hdop.or(solution.dop.hdop);
+                solution.dop.vdop = gsa.dop.vdop.or(solution.dop.vdop);
+                solution.satellites_used.retain(|(s, _)| *s != system);
+                solution
+                    .satellites_used
+                    .extend(gsa.prn_numbers.iter().map(|prn| (system, *prn)));
+            }
+            ParsedMessage::Gst(gst) => {
+                solution.accuracy = Some(gst.accuracy);
+            }
+            _ => {}
+        }
+        completed
+    }
+
+    /// Return the solution of the current epoch and start a new one. `None` is returned if the
+    /// epoch has no position.
+    pub fn flush(&mut self) -> Option<PvtSolution> {
+        self.current
+            .take()
+            .filter(|s| s.latitude.is_some() && s.longitude.is_some())
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_pvt_assembler() {
+        let mut p = NmeaParser::new();
+        let mut assembler = PvtAssembler::new();
+        for sentence in [
+            "$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47",
+            "$GPGSA,A,3,04,05,,09,12,,,24,,,,,2.5,1.3,2.1*39",
+            "$GPRMC,123519,A,4807.038,N,01131.000,E,022.4,084.4,230320,003.1,W*65",
+            "$GPGST,123519,0.006,0.023,0.020,273.6,0.023,0.020,0.031*70",
+        ] {
+            let msg = p.parse_sentence(sentence).unwrap();
+            assert_eq!(assembler.push(&msg), None);
+        }
+
+        let msg = p
+            .parse_sentence("$GPGGA,123520,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*4D")
+            .unwrap();
+        match assembler.push(&msg) {
+            Some(pvt) => {
+                assert_eq!(
+                    pvt.timestamp,
+                    Utc.with_ymd_and_hms(2020, 3, 23, 12, 35, 19).single()
+                );
+                assert::close(pvt.latitude.unwrap_or(0.0), 48.1, 0.1);
+                assert::close(pvt.longitude.unwrap_or(0.0), 11.5, 0.1);
+                assert_eq!(pvt.altitude, Some(545.4));
+                assert_eq!(pvt.sog_knots, Some(22.4));
+                assert_eq!(pvt.cog_true, Some(84.4));
+                assert_eq!(pvt.dop.pdop, Some(2.5));
+                assert_eq!(pvt.dop.hdop, Some(1.3));
+                assert_eq!(pvt.accuracy.and_then(|a| a.altitude_sigma), Some(0.031));
+                assert_eq!(pvt.satellite_count, Some(8));
+                assert_eq!(pvt.satellites_used.len(), 5);
+                assert_eq!(pvt.satellites_used[0], (NavigationSystem::Gps, 4));
+            }
+            None => {
+                panic!("No solution");
+            }
+        }
+
+        match assembler.flush() {
+            Some(pvt) => {
+                assert_eq!(
+                    pvt.timestamp,
+                    Utc.with_ymd_and_hms(2000, 1, 1, 12, 35, 20).single()
+                );
+                assert_eq!(pvt.sog_knots, None);
+            }
+            None => {
+                panic!("No solution");
+            }
+        }
+        assert_eq!(assembler.flush(), None);
+    }
+
+    #[test]
+    fn test_pvt_assembler_dop_merge() {
+        let mut p = NmeaParser::new();
+        let mut assembler = PvtAssembler::new();
+        for sentence in [
+            "$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47",
+            "$GPGSA,A,3,04,05,,09,12,,,24,,,,,2.5,,2.1*15",
+        ] {
+            let msg = p.parse_sentence(sentence).unwrap();
+            assert_eq!(assembler.push(&msg), None);
+        }
+
+        // GSA without HDOP keeps the HDOP of GGA
+        match assembler.flush() {
+            Some(pvt) => {
+                assert_eq!(pvt.dop.pdop, Some(2.5));
+                assert_eq!(pvt.dop.hdop, Some(0.9));
+                assert_eq!(pvt.dop.vdop, Some(2.1));
+            }
+            None => {
+                panic!("No solution");
+            }
+        }
+    }
+}
@@ -40,8 +40,68 @@ pub struct RmcData {
     /// Track angle in degrees (True)
     pub bearing: Option<f64>,
 
-    /// Magnetic variation in degrees
+    /// Magnetic variation in degrees, easterly variation positive and westerly negative
     pub variation: Option<f64>,
+
+    /// FAA mode indicator (NMEA 2.3 and later)
+    pub faa_mode: Option<FaaMode>,
+
+    /// Navigational status (NMEA 4.1 and later)
+    pub nav_status: Option<RmcNavStatus>,
+}
+
+/// RMC navigational status
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub enum RmcNavStatus {
+    /// Safe: the integrity of the position is within the limits of the application
+    Safe,
+
+    /// Caution: integrity is not available
+    Caution,
+
+    /// Unsafe: the integrity limits are exceeded
+    Unsafe,
+
+    /// Navigational status not valid, equipment is not providing status
+    NotValid,
+}
+
+impl RmcNavStatus {
+    pub fn new(val: &str) -> Result<RmcNavStatus, String> {
+        match val {
+            "S" => Ok(RmcNavStatus::Safe),
+            "C" => Ok(RmcNavStatus::Caution),
+            "U" => Ok(RmcNavStatus::Unsafe),
+            "V" => Ok(RmcNavStatus::NotValid),
+            _ => Err(format!("Unrecognized RMC navigational status: {}", val)),
+        }
+    }
+}
+
+impl core::fmt::Display for RmcNavStatus {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            RmcNavStatus::Safe => write!(f, "safe"),
+            RmcNavStatus::Caution => write!(f, "caution"),
+            RmcNavStatus::Unsafe => write!(f, "unsafe"),
+            RmcNavStatus::NotValid => write!(f, "not valid"),
+        }
+    }
+}
+
+impl RmcData {
+    /// Track angle in degrees (Magnetic) derived from the true track angle and the magnetic
+    /// variation.
+    pub fn magnetic_bearing(&self) -> Option<f64> {
+        let bearing = self.bearing? - self.variation?;
+        Some(if bearing < 0.0 {
+            bearing + 360.0
+        } else if bearing >= 360.0 {
+            bearing - 360.0
+        } else {
+            bearing
+        })
+    }
 }
 
 impl LatLon for RmcData {
@@ -60,13 +120,19 @@ impl LatLon for RmcData {
 pub(crate) fn handle(
     sentence: &str,
     nav_system: NavigationSystem,
+    store: &mut NmeaParser,
 ) -> Result<ParsedMessage, ParseError> {
     let split: Vec<&str> = sentence.split(',').collect();
+    let timestamp = parse_yymmdd_hhmmss(split.get(9).unwrap_or(&""), split.get(1).unwrap_or(&""))
+        .ok()
+        .map(|t| store.correct_date(t));
+    if let Some(timestamp) = timestamp {
+        store.push_date_time(timestamp);
+    }
 
     Ok(ParsedMessage::Rmc(RmcData {
         source: nav_system,
-        timestamp: parse_yymmdd_hhmmss(split.get(9).unwrap_or(&""), split.get(1).unwrap_or(&""))
-            .ok(),
+        timestamp,
         status_active: {
             let s = split.get(2).unwrap_or(&"");
             match *s {
@@ -102,6 +168,8 @@ pub(crate) fn handle(
                 None
             }
         },
+        faa_mode: FaaMode::new(split.get(12).unwrap_or(&"")).ok(),
+        nav_status: RmcNavStatus::new(split.get(13).unwrap_or(&"")).ok(),
     }))
 }
 
@@ -128,6 +196,7 @@ mod test {
                         assert_eq!(rmc.sog_knots.unwrap(), 0.5);
                         assert::close(rmc.bearing.unwrap_or(0.0), 54.7, 0.1);
                         assert_eq!(rmc.variation.unwrap(), 20.3);
+                        assert::close(rmc.magnetic_bearing().unwrap_or(0.0), 34.4, 0.01);
                     }
                     ParsedMessage::Incomplete => {
                         assert!(false);
@@ -170,4 +239,89 @@ mod test {
             }
         }
     }
+    #[test]
+    fn test_parse_rmc_westerly_variation() {
+        match NmeaParser::new()
+            .parse_sentence("$GPRMC,225446,A,4916.45,N,12311.12,W,000.5,054.7,191120,020.3,W*75")
+        {
+            Ok(ps) => match ps {
+                ParsedMessage::Rmc(rmc) => {
+                    assert_eq!(rmc.variation, Some(-20.3));
+                    assert::close(rmc.magnetic_bearing().unwrap_or(0.0), 75.0, 0.01);
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+    #[test]
+    fn test_parse_rmc_mode_and_nav_status() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence(
+            "$GNRMC,225446.00,A,4916.45,N,12311.12,W,000.5,054.7,191120,020.3,E,R,S*56",
+        ) {
+            Ok(ps) => match ps {
+                ParsedMessage::Rmc(rmc) => {
+                    assert_eq!(rmc.faa_mode, Some(FaaMode::RealTimeKinematic));
+                    assert!(rmc.faa_mode.map(|m| m.is_valid()).unwrap_or(false));
+                    assert_eq!(rmc.nav_status, Some(RmcNavStatus::Safe));
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+
+        match p.parse_sentence("$GPRMC,225446,V,,,,,,,191120,,,N,V*20") {
+            Ok(ps) => match ps {
+                ParsedMessage::Rmc(rmc) => {
+                    assert_eq!(rmc.status_active, Some(false));
+                    assert_eq!(rmc.faa_mode, Some(FaaMode::NotValid));
+                    assert_eq!(rmc.nav_status, Some(RmcNavStatus::NotValid));
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+    #[test]
+    fn test_parse_rmc_week_rollover() {
+        let sentence = "$GPRMC,225446,A,4916.45,N,12311.12,W,000.5,054.7,041106,020.3,E*6F";
+        let mut p = NmeaParser::new();
+        match p.parse_sentence(sentence) {
+            Ok(ParsedMessage::Rmc(rmc)) => {
+                assert_eq!(
+                    rmc.timestamp,
+                    Utc.with_ymd_and_hms(2006, 11, 4, 22, 54, 46).single()
+                );
+            }
+            ps => {
+                panic!("Unexpected result: {:?}", ps);
+            }
+        }
+
+        p.set_week_rollover_correction(true);
+        match p.parse_sentence(sentence) {
+            Ok(ParsedMessage::Rmc(rmc)) => {
+                assert_eq!(
+                    rmc.timestamp,
+                    Utc.with_ymd_and_hms(2026, 6, 20, 22, 54, 46).single()
+                );
+            }
+            ps => {
+                panic!("Unexpected result: {:?}", ps);
+            }
+        }
+    }
 }
@@ -0,0 +1,195 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+/// RSD - Radar system data
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct RsdData {
+    /// Origin 1 range from own ship
+    pub origin1_range: Option<f64>,
+
+    /// Origin 1 bearing in degrees from 0°
+    pub origin1_bearing: Option<f64>,
+
+    /// Variable range marker 1 (VRM1) range
+    pub vrm1_range: Option<f64>,
+
+    /// Electronic bearing line 1 (EBL1) in degrees from 0°
+    pub ebl1_bearing: Option<f64>,
+
+    /// Origin 2 range from own ship
+    pub origin2_range: Option<f64>,
+
+    /// Origin 2 bearing in degrees from 0°
+    pub origin2_bearing: Option<f64>,
+
+    /// Variable range marker 2 (VRM2) range
+    pub vrm2_range: Option<f64>,
+
+    /// Electronic bearing line 2 (EBL2) in degrees from 0°
+    pub ebl2_bearing: Option<f64>,
+
+    /// Cursor range from own ship
+    pub cursor_range: Option<f64>,
+
+    /// Cursor bearing in degrees clockwise from 0°
+    pub cursor_bearing: Option<f64>,
+
+    /// Range scale in use
+    pub range_scale: Option<f64>,
+
+    /// Unit of the range fields
+    pub range_units: Option<RangeUnit>,
+
+    /// Display rotation
+    pub display_rotation: Option<DisplayRotation>,
+}
+
+/// Unit of distance used by radar sentences
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub enum RangeUnit {
+    /// Kilometres
+    Kilometers,
+
+    /// Nautical miles
+    NauticalMiles,
+
+    /// Statute miles
+    StatuteMiles,
+}
+
+impl RangeUnit {
+    pub fn new(val: &str) -> Result<RangeUnit, String> {
+        match val {
+            "K" => Ok(RangeUnit::Kilometers),
+            "N" => Ok(RangeUnit::NauticalMiles),
+            "S" => Ok(RangeUnit::StatuteMiles),
+            _ => Err(format!("Unrecognized range unit: {}", val)),
+        }
+    }
+}
+
+impl core::fmt::Display for RangeUnit {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            RangeUnit::Kilometers => write!(f, "km"),
+            RangeUnit::NauticalMiles => write!(f, "nm"),
+            RangeUnit::StatuteMiles => write!(f, "mi"),
+        }
+    }
+}
+
+/// Radar display rotation
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub enum DisplayRotation {
+    /// Course-up, course-over-ground up, degrees true
+    CourseUp,
+
+    /// Head-up, ship's heading (centre line) 0° up
+    HeadUp,
+
+    /// North-up, true north is 0° up
+    NorthUp,
+}
+
+impl DisplayRotation {
+    pub fn new(val: &str) -> Result<DisplayRotation, String> {
+        match val {
+            "C" => Ok(DisplayRotation::CourseUp),
+            "H" => Ok(DisplayRotation::HeadUp),
+            "N" => Ok(DisplayRotation::NorthUp),
+            _ => Err(format!("Unrecognized display rotation: {}", val)),
+        }
+    }
+}
+
+impl core::fmt::Display for DisplayRotation {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DisplayRotation::CourseUp => write!(f, "course-up"),
+            DisplayRotation::HeadUp => write!(f, "head-up"),
+            DisplayRotation::NorthUp => write!(f, "north-up"),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// xxRSD: Radar system data
+pub(crate) fn handle(sentence: &str) -> Result<ParsedMessage, ParseError> {
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    Ok(ParsedMessage::Rsd(RsdData {
+        origin1_range: pick_number_field(&split, 1)?,
+        origin1_bearing: pick_number_field(&split, 2)?,
+        vrm1_range: pick_number_field(&split, 3)?,
+        ebl1_bearing: pick_number_field(&split, 4)?,
+        origin2_range: pick_number_field(&split, 5)?,
+        origin2_bearing: pick_number_field(&split, 6)?,
+        vrm2_range: pick_number_field(&split, 7)?,
+        ebl2_bearing: pick_number_field(&split, 8)?,
+        cursor_range: pick_number_field(&split, 9)?,
+        cursor_bearing: pick_number_field(&split, 10)?,
+        range_scale: pick_number_field(&split, 11)?,
+        range_units: match pick_string_field(&split, 12) {
+            Some(s) => Some(RangeUnit::new(&s)?),
+            None => None,
+        },
+        display_rotation: match pick_string_field(&split, 13) {
+            Some(s) => Some(DisplayRotation::new(&s)?),
+            None => None,
+        },
+    }))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_rsd() {
+        match NmeaParser::new()
+            .parse_sentence("$RARSD,0.00,0.0,1.50,45.0,,,,,3.20,300.5,6.0,N,H*56")
+        {
+            Ok(ps) => match ps {
+                ParsedMessage::Rsd(rsd) => {
+                    assert_eq!(rsd.origin1_range, Some(0.0));
+                    assert_eq!(rsd.origin1_bearing, Some(0.0));
+                    assert_eq!(rsd.vrm1_range, Some(1.5));
+                    assert_eq!(rsd.ebl1_bearing, Some(45.0));
+                    assert_eq!(rsd.origin2_range, None);
+                    assert_eq!(rsd.origin2_bearing, None);
+                    assert_eq!(rsd.vrm2_range, None);
+                    assert_eq!(rsd.ebl2_bearing, None);
+                    assert_eq!(rsd.cursor_range, Some(3.2));
+                    assert_eq!(rsd.cursor_bearing, Some(300.5));
+                    assert_eq!(rsd.range_scale, Some(6.0));
+                    assert_eq!(rsd.range_units, Some(RangeUnit::NauticalMiles));
+                    assert_eq!(rsd.display_rotation, Some(DisplayRotation::HeadUp));
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+}
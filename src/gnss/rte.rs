@@ -0,0 +1,216 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+/// RTE - Routes
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct RteData {
+    /// Navigation system
+    pub source: NavigationSystem,
+
+    /// Route identifier
+    pub route_id: Option<String>,
+
+    /// True if the list contains all the waypoints of the route, false if it contains only the
+    /// waypoints from the current position onwards (working route)
+    pub complete: bool,
+
+    /// Waypoint identifiers in route order
+    pub waypoint_ids: Vec<String>,
+}
+
+/// Route with waypoint locations, resolved from RTE and WPL sentences
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Route {
+    /// Route identifier
+    pub route_id: Option<String>,
+
+    /// True for a complete route, false for a working route
+    pub complete: bool,
+
+    /// Waypoints in route order
+    pub waypoints: Vec<WplData>,
+}
+
+impl Route {
+    /// Resolve the waypoints of the route. Returns `None` if some of the waypoints are unknown.
+    pub(crate) fn resolve<'a>(
+        rte: &RteData,
+        waypoint: impl Fn(&str) -> Option<&'a WplData>,
+    ) -> Option<Route> {
+        let waypoints = rte
+            .waypoint_ids
+            .iter()
+            .map(|id| waypoint(id).cloned())
+            .collect::<Option<Vec<WplData>>>()?;
+        Some(Route {
+            route_id: rte.route_id.clone(),
+            complete: rte.complete,
+            waypoints,
+        })
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// xxRTE: Routes. A route may be split into several sentences. `ParsedMessage::Incomplete` is
+/// returned until all of them have been received. If the locations of all the waypoints are
+/// known from earlier WPL sentences, `ParsedMessage::Route` is returned, otherwise
+/// `ParsedMessage::Rte`.
+pub(crate) fn handle(
+    sentence: &str,
+    nav_system: NavigationSystem,
+    store: &mut NmeaParser,
+) -> Result<ParsedMessage, ParseError> {
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    let msg_type = split.first().unwrap_or(&"");
+    let msg_count: u8 = pick_number_field(&split, 1)?.unwrap_or(0);
+    let msg_num: u8 = pick_number_field(&split, 2)?.unwrap_or(0);
+    if msg_num == 0 || msg_num > msg_count {
+        return Err(ParseError::InvalidSentence(format!(
+            "Invalid RTE sentence number {} of {}",
+            msg_num, msg_count
+        )));
+    }
+    let route_id = pick_string_field(&split, 4);
+
+    // Sentences left over from an earlier, incomplete transmission of the route are discarded.
+    // A new transmission is detected by a repeated sentence number or by a changed sentence
+    // count.
+    let group = format!("{},{},", msg_type, route_id.as_deref().unwrap_or(""));
+    let key = format!("{}{},{}", group, msg_count, msg_num);
+    if store.contains_key(key.clone()) {
+        store.retain_strings(|k| !k.starts_with(&group));
+    } else {
+        let transmission = format!("{}{},", group, msg_count);
+        store.retain_strings(|k| !k.starts_with(&group) || k.starts_with(&transmission));
+    }
+    store.push_string(key, sentence.into());
+
+    for i in 1..=msg_count {
+        if !store.contains_key(format!("{}{},{}", group, msg_count, i)) {
+            return Ok(ParsedMessage::Incomplete);
+        }
+    }
+
+    let mut rte = RteData {
+        source: nav_system,
+        route_id,
+        complete: split.get(3) == Some(&"c"),
+        waypoint_ids: Vec::new(),
+    };
+    for i in 1..=msg_count {
+        if let Some(sentence) = store.pull_string(format!("{}{},{}", group, msg_count, i)) {
+            rte.waypoint_ids.extend(
+                sentence
+                    .split(',')
+                    .skip(5)
+                    .filter(|id| !id.is_empty())
+                    .map(String::from),
+            );
+        }
+    }
+
+    store.push_route(rte.clone());
+    match store.route(rte.route_id.as_deref().unwrap_or("")) {
+        Some(route) => Ok(ParsedMessage::Route(route)),
+        None => Ok(ParsedMessage::Rte(rte)),
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_rte() {
+        let mut p = NmeaParser::new();
+        assert!(p
+            .parse_sentence("$GPWPL,4917.16,N,12310.64,W,003*65")
+            .is_ok());
+        assert!(p
+            .parse_sentence("$GPWPL,4916.00,N,12311.00,W,004*67")
+            .is_ok());
+
+        match p.parse_sentence("$GPRTE,2,2,c,0,005*1E") {
+            Ok(ParsedMessage::Incomplete) => {}
+            ps => {
+                panic!("Unexpected result: {:?}", ps);
+            }
+        }
+
+        // Waypoint 005 is still unknown
+        match p.parse_sentence("$GPRTE,2,1,c,0,003,004*03") {
+            Ok(ParsedMessage::Rte(rte)) => {
+                assert_eq!(rte.source, NavigationSystem::Gps);
+                assert_eq!(rte.route_id, Some("0".into()));
+                assert!(rte.complete);
+                assert_eq!(rte.waypoint_ids, vec!["003", "004", "005"]);
+            }
+            ps => {
+                panic!("Unexpected result: {:?}", ps);
+            }
+        }
+        assert_eq!(p.strings_count(), 0);
+        assert!(p.route("0").is_none());
+
+        assert!(p
+            .parse_sentence("$GPWPL,4915.50,N,12312.00,W,005*63")
+            .is_ok());
+        match p.route("0") {
+            Some(route) => {
+                assert_eq!(route.waypoints.len(), 3);
+                assert_eq!(route.waypoints[2].waypoint_id, Some("005".into()));
+            }
+            None => {
+                panic!("Route not resolved");
+            }
+        }
+
+        // All waypoints known when the route is received again
+        assert!(p.parse_sentence("$GPRTE,2,1,c,0,003,004*03").is_ok());
+        match p.parse_sentence("$GPRTE,2,2,c,0,005*1E") {
+            Ok(ParsedMessage::Route(route)) => {
+                assert_eq!(route.route_id, Some("0".into()));
+                assert_eq!(route.waypoints.len(), 3);
+                assert::close(route.waypoints[0].latitude.unwrap_or(0.0), 49.286, 0.001);
+            }
+            ps => {
+                panic!("Unexpected result: {:?}", ps);
+            }
+        }
+
+        // Sentence counts out of range
+        assert!(p.parse_sentence("$GPRTE,2147483647,1,c,0,003").is_err());
+        assert!(p.parse_sentence("$GPRTE,255,1,c,0,003").is_ok());
+        assert!(p.parse_sentence("$GPRTE,1,2,c,0,003").is_err());
+
+        // Leftovers of another sentence count are dropped
+        match p.parse_sentence("$GPRTE,1,1,c,0,003,004*00") {
+            Ok(ParsedMessage::Route(route)) => {
+                assert_eq!(route.waypoints.len(), 2);
+            }
+            ps => {
+                panic!("Unexpected result: {:?}", ps);
+            }
+        }
+        assert_eq!(p.strings_count(), 0);
+    }
+}
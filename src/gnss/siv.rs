@@ -0,0 +1,208 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+/// Satellites in view of all satellite systems, combined from the latest GSV and GSA sentences
+/// of each system. Returned by `NmeaParser::satellites_in_view`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct SatellitesInView {
+    /// Satellites ordered by satellite system and PRN number
+    pub satellites: Vec<SatelliteInView>,
+}
+
+/// Single satellite in the combined satellites-in-view summary
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct SatelliteInView {
+    /// Satellite system
+    pub source: NavigationSystem,
+
+    /// Satellite PRN number
+    pub prn_number: u8,
+
+    /// Elevation in degrees (max 90°)
+    pub elevation: Option<f32>,
+
+    /// Azimuth in degrees from True north (0°-359°)
+    pub azimuth: Option<f32>,
+
+    /// Observations of each signal band the satellite is tracked on. Receivers older than
+    /// NMEA 4.10 report a single observation without signal ID.
+    pub signals: Vec<SignalObservation>,
+
+    /// True if the latest GSA of the satellite system lists the satellite as used in the fix
+    pub used_in_fix: bool,
+}
+
+/// SNR observation of a single signal band
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct SignalObservation {
+    /// NMEA 4.10 signal ID, `None` for older receivers
+    pub signal_id: Option<u8>,
+
+    /// Name of the signal band (e.g. "L1 C/A" or "L5-Q") if known
+    pub signal_name: Option<&'static str>,
+
+    /// SNR, 0-99 dB, None when not tracking
+    pub snr: Option<f32>,
+}
+
+impl SatelliteInView {
+    /// Return the strongest SNR of all signal bands
+    pub fn snr(&self) -> Option<f32> {
+        self.signals
+            .iter()
+            .filter_map(|s| s.snr)
+            .fold(None, |max, snr| Some(max.map_or(snr, |m: f32| m.max(snr))))
+    }
+
+    /// Return the SNR of the given signal band
+    pub fn signal_snr(&self, signal_id: u8) -> Option<f32> {
+        self.signals
+            .iter()
+            .find(|s| s.signal_id == Some(signal_id))
+            .and_then(|s| s.snr)
+    }
+
+    /// Return true if the satellite is tracked on more than one signal band
+    pub fn is_multi_frequency(&self) -> bool {
+        self.signals.iter().filter(|s| s.snr.is_some()).count() > 1
+    }
+}
+
+impl SatellitesInView {
+    /// Build the summary from the latest GSV satellites and GSA sentences.
+    pub(crate) fn new<'a>(
+        gsvs: impl Iterator<Item = &'a GsvData>,
+        latest_gsa: impl Fn(NavigationSystem) -> Option<&'a GsaData>,
+    ) -> SatellitesInView {
+        let mut satellites: Vec<SatelliteInView> = Vec::new();
+        for gsv in gsvs {
+            let signal = SignalObservation {
+                signal_id: gsv.signal_id,
+                signal_name: gsv.signal_name(),
+                snr: gsv.snr,
+            };
+            let existing = satellites
+                .iter_mut()
+                .find(|s| s.source == gsv.source && s.prn_number == gsv.prn_number);
+            match existing {
+                Some(s) => {
+                    s.elevation = s.elevation.or(gsv.elevation);
+                    s.azimuth = s.azimuth.or(gsv.azimuth);
+                    s.signals.push(signal);
+                }
+                None => {
+                    let used_in_fix = [gsv.source, NavigationSystem::Combination]
+                        .iter()
+                        .filter_map(|system| latest_gsa(*system))
+                        .any(|gsa| gsa.prn_numbers.contains(&gsv.prn_number));
+                    satellites.push(SatelliteInView {
+                        source: gsv.source,
+                        prn_number: gsv.prn_number,
+                        elevation: gsv.elevation,
+                        azimuth: gsv.azimuth,
+                        signals: vec![signal],
+                        used_in_fix,
+                    });
+                }
+            }
+        }
+        for s in satellites.iter_mut() {
+            s.signals.sort_by_key(|o| o.signal_id);
+        }
+        satellites.sort_by_key(|s| (s.source as u8, s.prn_number));
+        SatellitesInView { satellites }
+    }
+
+    /// Number of satellites in view of the given satellite system
+    pub fn count(&self, system: NavigationSystem) -> usize {
+        self.satellites
+            .iter()
+            .filter(|s| s.source == system)
+            .count()
+    }
+
+    /// Number of satellites of the given satellite system used in the fix
+    pub fn used_count(&self, system: NavigationSystem) -> usize {
+        self.satellites
+            .iter()
+            .filter(|s| s.source == system && s.used_in_fix)
+            .count()
+    }
+
+    /// Total number of satellites used in the fix
+    pub fn total_used_count(&self) -> usize {
+        self.satellites.iter().filter(|s| s.used_in_fix).count()
+    }
+
+    /// Satellite systems having satellites in view
+    pub fn systems(&self) -> Vec<NavigationSystem> {
+        let mut v: Vec<NavigationSystem> = Vec::new();
+        for s in &self.satellites {
+            if !v.contains(&s.source) {
+                v.push(s.source);
+            }
+        }
+        v
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_satellites_in_view() {
+        let mut p = NmeaParser::new();
+        for s in [
+            "$GPGSV,1,1,03,10,45,120,40,12,30,200,35,25,60,310,42,1*51",
+            "$GPGSV,1,1,02,10,45,120,38,25,60,310,41,8*61",
+            "$GAGSV,1,1,01,05,22,033,30,7*44",
+            "$GPGSA,A,3,10,25,,,,,,,,,,,2.0,1.0,1.7*31",
+        ] {
+            assert!(p.parse_sentence(s).is_ok());
+        }
+
+        let siv = p.satellites_in_view();
+        assert_eq!(siv.satellites.len(), 4);
+        assert_eq!(siv.count(NavigationSystem::Gps), 3);
+        assert_eq!(siv.count(NavigationSystem::Galileo), 1);
+        assert_eq!(siv.used_count(NavigationSystem::Gps), 2);
+        assert_eq!(siv.used_count(NavigationSystem::Galileo), 0);
+        assert_eq!(siv.total_used_count(), 2);
+        assert_eq!(
+            siv.systems(),
+            vec![NavigationSystem::Gps, NavigationSystem::Galileo]
+        );
+
+        // Both signal bands are kept
+        let s10 = &siv.satellites[0];
+        assert_eq!(s10.prn_number, 10);
+        assert_eq!(s10.signals.len(), 2);
+        assert_eq!(s10.signals[0].signal_name, Some("L1 C/A"));
+        assert_eq!(s10.signal_snr(8), Some(38.0));
+        assert_eq!(s10.snr(), Some(40.0));
+        assert!(s10.is_multi_frequency());
+        assert!(s10.used_in_fix);
+        assert!(!siv.satellites[1].is_multi_frequency());
+
+        p.reset();
+        assert!(p.satellites_in_view().satellites.is_empty());
+    }
+}
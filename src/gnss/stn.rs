@@ -16,7 +16,7 @@ limitations under the License.
 
 use super::*;
 
-/// STN - MSK Receiver Signal
+/// STN - Multiple data ID
 #[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct StnData {
     /// Navigation system
@@ -28,7 +28,7 @@ pub struct StnData {
 
 // -------------------------------------------------------------------------------------------------
 
-/// xxSTN: MSK Receiver Signal
+/// xxSTN: Multiple data ID
 pub(crate) fn handle(
     sentence: &str,
     nav_system: NavigationSystem,
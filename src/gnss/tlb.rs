@@ -0,0 +1,95 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+/// TLB - Target label
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct TlbData {
+    /// Labels assigned to target numbers
+    pub labels: Vec<TargetLabel>,
+}
+
+impl TlbData {
+    /// Return the label of the given target number, if any.
+    pub fn label(&self, target_number: u32) -> Option<&str> {
+        self.labels
+            .iter()
+            .find(|l| l.target_number == target_number)
+            .and_then(|l| l.label.as_deref())
+    }
+}
+
+/// Label of a single tracked target
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct TargetLabel {
+    /// Target number as reported by the tracking device
+    pub target_number: u32,
+
+    /// Label assigned to the target, `None` if the label is empty
+    pub label: Option<String>,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// xxTLB: Target label
+pub(crate) fn handle(sentence: &str) -> Result<ParsedMessage, ParseError> {
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    let mut labels = Vec::new();
+    let mut i = 1;
+    while i < split.len() {
+        if let Some(target_number) = pick_number_field(&split, i)? {
+            labels.push(TargetLabel {
+                target_number,
+                label: pick_string_field(&split, i + 1),
+            });
+        }
+        i += 2;
+    }
+
+    Ok(ParsedMessage::Tlb(TlbData { labels }))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_tlb() {
+        match NmeaParser::new().parse_sentence("$RATLB,1,ALPHA,2,BRAVO,15,*52") {
+            Ok(ps) => match ps {
+                ParsedMessage::Tlb(tlb) => {
+                    assert_eq!(tlb.labels.len(), 3);
+                    assert_eq!(tlb.labels[0].target_number, 1);
+                    assert_eq!(tlb.labels[0].label, Some("ALPHA".into()));
+                    assert_eq!(tlb.labels[2].target_number, 15);
+                    assert_eq!(tlb.labels[2].label, None);
+                    assert_eq!(tlb.label(2), Some("BRAVO"));
+                    assert_eq!(tlb.label(3), None);
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+}
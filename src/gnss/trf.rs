@@ -0,0 +1,140 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+/// TRF - Transit fix data
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct TrfData {
+    /// Navigation system
+    pub source: NavigationSystem,
+
+    /// Fix datetime based on HHMMSS and DDMMYY
+    #[serde(with = "json_date_time_utc")]
+    pub timestamp: Option<DateTime<Utc>>,
+
+    /// Latitude in degrees
+    pub latitude: Option<f64>,
+
+    /// Longitude in degrees
+    pub longitude: Option<f64>,
+
+    /// Satellite elevation angle in degrees
+    pub elevation_angle: Option<f64>,
+
+    /// Number of iterations
+    pub iterations: Option<u32>,
+
+    /// Number of Doppler intervals
+    pub doppler_intervals: Option<u32>,
+
+    /// Update distance in nautical miles
+    pub update_distance: Option<f64>,
+
+    /// Satellite ID
+    pub satellite_id: Option<u32>,
+
+    /// Data validity: true = valid, false = invalid
+    pub data_valid: Option<bool>,
+}
+
+impl LatLon for TrfData {
+    fn latitude(&self) -> Option<f64> {
+        self.latitude
+    }
+
+    fn longitude(&self) -> Option<f64> {
+        self.longitude
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// xxTRF: Transit fix data
+pub(crate) fn handle(
+    sentence: &str,
+    nav_system: NavigationSystem,
+) -> Result<ParsedMessage, ParseError> {
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    Ok(ParsedMessage::Trf(TrfData {
+        source: nav_system,
+        timestamp: parse_yymmdd_hhmmss(split.get(2).unwrap_or(&""), split.get(1).unwrap_or(&""))
+            .ok(),
+        latitude: parse_latitude_ddmm_mmm(
+            split.get(3).unwrap_or(&""),
+            split.get(4).unwrap_or(&""),
+        )?,
+        longitude: parse_longitude_dddmm_mmm(
+            split.get(5).unwrap_or(&""),
+            split.get(6).unwrap_or(&""),
+        )?,
+        elevation_angle: pick_number_field(&split, 7)?,
+        iterations: pick_number_field(&split, 8)?,
+        doppler_intervals: pick_number_field(&split, 9)?,
+        update_distance: pick_number_field(&split, 10)?,
+        satellite_id: pick_number_field(&split, 11)?,
+        data_valid: {
+            let s = split.get(12).unwrap_or(&"");
+            match *s {
+                "A" => Some(true),
+                "V" => Some(false),
+                "" => None,
+                _ => {
+                    return Err(format!("Invalid TRF data validity: {}", s).into());
+                }
+            }
+        },
+    }))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_gptrf() {
+        match NmeaParser::new()
+            .parse_sentence("$GPTRF,121314.00,010120,6011.552,N,02501.941,E,34.5,3,12,1.2,014,A*3F")
+        {
+            Ok(ps) => match ps {
+                ParsedMessage::Trf(trf) => {
+                    assert_eq!(trf.source, NavigationSystem::Gps);
+                    assert_eq!(
+                        trf.timestamp,
+                        Utc.with_ymd_and_hms(2020, 1, 1, 12, 13, 14).single()
+                    );
+                    assert::close(trf.latitude.unwrap_or(0.0), 60.193, 0.001);
+                    assert::close(trf.longitude.unwrap_or(0.0), 25.032, 0.001);
+                    assert_eq!(trf.elevation_angle, Some(34.5));
+                    assert_eq!(trf.iterations, Some(3));
+                    assert_eq!(trf.doppler_intervals, Some(12));
+                    assert_eq!(trf.update_distance, Some(1.2));
+                    assert_eq!(trf.satellite_id, Some(14));
+                    assert_eq!(trf.data_valid, Some(true));
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+}
@@ -0,0 +1,184 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+/// TXT - Text transmission, possibly assembled from several sentences
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct TextMessage {
+    /// Navigation system
+    pub source: NavigationSystem,
+
+    /// Text identifier (0-99)
+    pub text_id: Option<u8>,
+
+    /// Severity derived from the text identifier
+    pub severity: TextSeverity,
+
+    /// Text of all the sentences concatenated
+    pub text: String,
+}
+
+/// Severity of TXT message as used by u-blox and most other GNSS receivers
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub enum TextSeverity {
+    /// Error message (00)
+    Error,
+
+    /// Warning message (01)
+    Warning,
+
+    /// Notice message (02)
+    Notice,
+
+    /// User message (07)
+    User,
+
+    /// Unclassified text identifier
+    Other,
+}
+
+impl TextSeverity {
+    pub fn new(text_id: Option<u8>) -> TextSeverity {
+        match text_id {
+            Some(0) => TextSeverity::Error,
+            Some(1) => TextSeverity::Warning,
+            Some(2) => TextSeverity::Notice,
+            Some(7) => TextSeverity::User,
+            _ => TextSeverity::Other,
+        }
+    }
+}
+
+impl core::fmt::Display for TextSeverity {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            TextSeverity::Error => write!(f, "error"),
+            TextSeverity::Warning => write!(f, "warning"),
+            TextSeverity::Notice => write!(f, "notice"),
+            TextSeverity::User => write!(f, "user"),
+            TextSeverity::Other => write!(f, "other"),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// xxTXT: Text transmission
+pub(crate) fn handle(
+    sentence: &str,
+    nav_system: NavigationSystem,
+    store: &mut NmeaParser,
+) -> Result<ParsedMessage, ParseError> {
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    let msg_type = split.first().unwrap_or(&"");
+    let msg_count = pick_number_field(&split, 1)?.unwrap_or(1);
+    let msg_num = pick_number_field(&split, 2)?.unwrap_or(1);
+    let text_id: Option<u8> = pick_number_field(&split, 3)?;
+    let mut text = split.get(4..).unwrap_or(&[]).join(",");
+
+    if msg_count > 1 {
+        store.push_string(make_txt_key(msg_type, text_id, msg_count, msg_num), text);
+        for i in 1..=msg_count {
+            if !store.contains_key(make_txt_key(msg_type, text_id, msg_count, i)) {
+                return Ok(ParsedMessage::Incomplete);
+            }
+        }
+        text = String::new();
+        for i in 1..=msg_count {
+            if let Some(s) = store.pull_string(make_txt_key(msg_type, text_id, msg_count, i)) {
+                text.push_str(&s);
+            }
+        }
+    }
+
+    Ok(ParsedMessage::Txt(TextMessage {
+        source: nav_system,
+        text_id,
+        severity: TextSeverity::new(text_id),
+        text,
+    }))
+}
+
+/// Make key for store
+fn make_txt_key(sentence_type: &str, text_id: Option<u8>, msg_count: u8, msg_num: u8) -> String {
+    format!(
+        "{},{},{},{}",
+        sentence_type,
+        text_id.unwrap_or(0),
+        msg_count,
+        msg_num
+    )
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_txt() {
+        // Single sentence
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$GPTXT,01,01,02,ANTSTATUS=OK*3B") {
+            Ok(ps) => match ps {
+                ParsedMessage::Txt(txt) => {
+                    assert_eq!(txt.source, NavigationSystem::Gps);
+                    assert_eq!(txt.text_id, Some(2));
+                    assert_eq!(txt.severity, TextSeverity::Notice);
+                    assert_eq!(txt.text, "ANTSTATUS=OK");
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+
+        // Multi-sentence group
+        assert_eq!(
+            p.parse_sentence("$GNTXT,02,01,01,PF=3FF HW UBX-M8030 0*60"),
+            Ok(ParsedMessage::Incomplete)
+        );
+        assert_eq!(p.strings_count(), 1);
+        match p.parse_sentence("$GNTXT,02,02,01,0180000 FW FLASH*28") {
+            Ok(ps) => match ps {
+                ParsedMessage::Txt(txt) => {
+                    assert_eq!(txt.source, NavigationSystem::Combination);
+                    assert_eq!(txt.severity, TextSeverity::Warning);
+                    assert_eq!(txt.text, "PF=3FF HW UBX-M8030 00180000 FW FLASH");
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+        assert_eq!(p.strings_count(), 0);
+
+        // Maximum sentence count
+        assert_eq!(
+            p.parse_sentence("$GPTXT,255,01,01,hi"),
+            Ok(ParsedMessage::Incomplete)
+        );
+    }
+}
@@ -33,10 +33,19 @@ pub struct VtgData {
     /// Speed over ground (SoG), km/h
     pub sog_kph: Option<f64>,
 
-    /// FAA mode indicator
+    /// FAA mode indicator (NMEA 2.3 and later). Course and speed should not be trusted when the
+    /// mode is estimated or not valid.
     pub faa_mode: Option<FaaMode>,
 }
 
+impl VtgData {
+    /// Return true if the course and speed are based on valid satellite measurements. Sentences
+    /// older than NMEA 2.3 don't carry the mode and are assumed to be valid.
+    pub fn is_valid(&self) -> bool {
+        self.faa_mode.map(|m| m.is_valid()).unwrap_or(true)
+    }
+}
+
 // -------------------------------------------------------------------------------------------------
 
 /// xxVTG: Track Made Good and Ground Speed
@@ -87,4 +96,37 @@ mod test {
             }
         }
     }
+    #[test]
+    fn test_parse_vtg_faa_mode() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$GPVTG,054.7,T,034.4,M,005.5,N,010.2,K,E*21") {
+            Ok(ps) => match ps {
+                ParsedMessage::Vtg(vtg) => {
+                    assert_eq!(vtg.faa_mode, Some(FaaMode::Estimated));
+                    assert!(!vtg.is_valid());
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+
+        match p.parse_sentence("$GPVTG,054.7,T,034.4,M,005.5,N,010.2,K*48") {
+            Ok(ps) => match ps {
+                ParsedMessage::Vtg(vtg) => {
+                    assert_eq!(vtg.faa_mode, None);
+                    assert!(vtg.is_valid());
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
 }
@@ -0,0 +1,98 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+/// WPL - Waypoint location
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct WplData {
+    /// Navigation system
+    pub source: NavigationSystem,
+
+    /// Latitude in degrees
+    pub latitude: Option<f64>,
+
+    /// Longitude in degrees
+    pub longitude: Option<f64>,
+
+    /// Waypoint identifier
+    pub waypoint_id: Option<String>,
+}
+
+impl LatLon for WplData {
+    fn latitude(&self) -> Option<f64> {
+        self.latitude
+    }
+
+    fn longitude(&self) -> Option<f64> {
+        self.longitude
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// xxWPL: Waypoint location
+pub(crate) fn handle(
+    sentence: &str,
+    nav_system: NavigationSystem,
+    store: &mut NmeaParser,
+) -> Result<ParsedMessage, ParseError> {
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    let wpl = WplData {
+        source: nav_system,
+        latitude: parse_latitude_ddmm_mmm(
+            split.get(1).unwrap_or(&""),
+            split.get(2).unwrap_or(&""),
+        )?,
+        longitude: parse_longitude_dddmm_mmm(
+            split.get(3).unwrap_or(&""),
+            split.get(4).unwrap_or(&""),
+        )?,
+        waypoint_id: pick_string_field(&split, 5),
+    };
+    if let Some(id) = &wpl.waypoint_id {
+        store.push_waypoint(id.clone(), wpl.clone());
+    }
+    Ok(ParsedMessage::Wpl(wpl))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_wpl() {
+        match NmeaParser::new().parse_sentence("$GPWPL,4917.16,N,12310.64,W,003*65") {
+            Ok(ps) => match ps {
+                ParsedMessage::Wpl(wpl) => {
+                    assert_eq!(wpl.source, NavigationSystem::Gps);
+                    assert::close(wpl.latitude.unwrap_or(0.0), 49.286, 0.001);
+                    assert::close(wpl.longitude.unwrap_or(0.0), -123.177, 0.001);
+                    assert_eq!(wpl.waypoint_id, Some("003".into()));
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+}
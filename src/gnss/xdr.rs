@@ -0,0 +1,152 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+/// XDR - Transducer measurements
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct XdrData {
+    /// Transducer measurements in the order of the sentence
+    pub measurements: Vec<TransducerMeasurement>,
+}
+
+impl XdrData {
+    /// Find measurement by transducer type and name (e.g. "A" and "PTCH").
+    pub fn find(&self, transducer_type: &str, name: &str) -> Option<&TransducerMeasurement> {
+        self.measurements
+            .iter()
+            .find(|m| m.transducer_type == transducer_type && m.name.as_deref() == Some(name))
+    }
+
+    /// Pitch in degrees, bow up positive. Reported by attitude sensors such as Airmar
+    /// weather stations under the name "PTCH".
+    pub fn pitch(&self) -> Option<f64> {
+        self.find("A", "PTCH").and_then(|m| m.value)
+    }
+
+    /// Roll in degrees, starboard down positive. Reported under the name "ROLL".
+    pub fn roll(&self) -> Option<f64> {
+        self.find("A", "ROLL").and_then(|m| m.value)
+    }
+
+    /// Dry air density in kg/m³ calculated from the first air temperature and barometric
+    /// pressure measurements of the sentence.
+    pub fn air_density(&self) -> Option<f64> {
+        let temperature = self
+            .measurements
+            .iter()
+            .find(|m| m.transducer_type == "C" && m.unit.as_deref() == Some("C"))?
+            .value?;
+        let pressure = self
+            .measurements
+            .iter()
+            .find(|m| m.transducer_type == "P")
+            .and_then(|m| match (m.value, m.unit.as_deref()) {
+                (Some(v), Some("B")) => Some(v * 100000.0),
+                (Some(v), Some("P")) => Some(v),
+                _ => None,
+            })?;
+        // Specific gas constant for dry air, J/(kg·K)
+        Some(pressure / (287.058 * (temperature + 273.15)))
+    }
+}
+
+/// Single transducer measurement
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct TransducerMeasurement {
+    /// Transducer type (e.g. "A" = angular displacement, "C" = temperature, "P" = pressure,
+    /// "H" = humidity)
+    pub transducer_type: String,
+
+    /// Measurement value
+    pub value: Option<f64>,
+
+    /// Unit of measurement (e.g. "D" = degrees, "C" = Celsius, "B" = bar, "P" = pascal or
+    /// percent)
+    pub unit: Option<String>,
+
+    /// Transducer name
+    pub name: Option<String>,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// xxXDR: Transducer measurements
+pub(crate) fn handle(sentence: &str) -> Result<ParsedMessage, ParseError> {
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    let mut measurements = Vec::new();
+    let mut i = 1;
+    while i < split.len() {
+        if let Some(transducer_type) = pick_string_field(&split, i) {
+            measurements.push(TransducerMeasurement {
+                transducer_type,
+                value: pick_number_field(&split, i + 1)?,
+                unit: pick_string_field(&split, i + 2),
+                name: pick_string_field(&split, i + 3),
+            });
+        }
+        i += 4;
+    }
+
+    Ok(ParsedMessage::Xdr(XdrData { measurements }))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_xdr() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$YXXDR,A,-1.2,D,PTCH,A,0.8,D,ROLL*7B") {
+            Ok(ps) => match ps {
+                ParsedMessage::Xdr(xdr) => {
+                    assert_eq!(xdr.measurements.len(), 2);
+                    assert_eq!(xdr.pitch(), Some(-1.2));
+                    assert_eq!(xdr.roll(), Some(0.8));
+                    assert_eq!(xdr.air_density(), None);
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+
+        match p.parse_sentence("$WIXDR,C,15.0,C,1,P,1.01325,B,1,H,45.0,P,1*44") {
+            Ok(ps) => match ps {
+                ParsedMessage::Xdr(xdr) => {
+                    assert_eq!(xdr.measurements.len(), 3);
+                    assert_eq!(xdr.measurements[2].transducer_type, "H");
+                    assert_eq!(xdr.measurements[2].value, Some(45.0));
+                    assert_eq!(xdr.pitch(), None);
+                    assert::close(xdr.air_density().unwrap_or(0.0), 1.225, 0.001);
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+}
@@ -26,27 +26,40 @@ pub struct ZdaData {
     #[serde(with = "json_date_time_utc")]
     pub timestamp_utc: Option<DateTime<Utc>>,
 
-    /// Local time zone offset
+    /// Local time zone offset from UTC, built from the local zone hours and minutes fields
     #[serde(with = "json_fixed_offset")]
     pub timezone_local: Option<FixedOffset>,
 }
 
+impl ZdaData {
+    /// Return the UTC time converted to the receiver's local time zone
+    pub fn local_time(&self) -> Option<DateTime<FixedOffset>> {
+        Some(self.timestamp_utc?.with_timezone(&self.timezone_local?))
+    }
+}
+
 // -------------------------------------------------------------------------------------------------
 
-/// xxZDA: MSK Receiver Signal
+/// xxZDA: Time and date
 pub(crate) fn handle(
     sentence: &str,
     nav_system: NavigationSystem,
+    store: &mut NmeaParser,
 ) -> Result<ParsedMessage, ParseError> {
     let split: Vec<&str> = sentence.split(',').collect();
+    let timestamp_utc = parse_hhmmss_ss(
+        split.get(1).unwrap_or(&""),
+        pick_date_with_fields(&split, 4, 3, 2, 0, 0, 0, 0)?,
+    )
+    .ok()
+    .map(|t| store.correct_date(t));
+    if let Some(timestamp) = timestamp_utc {
+        store.push_date_time(timestamp);
+    }
 
     Ok(ParsedMessage::Zda(ZdaData {
         source: nav_system,
-        timestamp_utc: parse_hhmmss_ss(
-            split.get(1).unwrap_or(&""),
-            pick_date_with_fields(&split, 4, 3, 2, 0, 0, 0, 0)?,
-        )
-        .ok(),
+        timestamp_utc,
         timezone_local: pick_timezone_with_fields(&split, 5, 6).ok(),
     }))
 }
@@ -68,6 +81,10 @@ mod test {
                         Utc.with_ymd_and_hms(2018, 5, 31, 7, 29, 14).single()
                     );
                     assert_eq!(zda.timezone_local, FixedOffset::east_opt(-3 * 3600));
+                    assert_eq!(
+                        zda.local_time().map(|t| t.naive_local()),
+                        NaiveDate::from_ymd_opt(2018, 5, 31).and_then(|d| d.and_hms_opt(4, 29, 14))
+                    );
                 }
                 ParsedMessage::Incomplete => {
                     assert!(false);
@@ -81,4 +98,55 @@ mod test {
             }
         }
     }
+    #[test]
+    fn test_parse_zda_half_hour_zones() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$GPZDA,201530.00,04,07,2002,-00,30*4E") {
+            Ok(ParsedMessage::Zda(zda)) => {
+                assert_eq!(zda.timezone_local, FixedOffset::east_opt(-30 * 60));
+            }
+            ps => {
+                panic!("Unexpected result: {:?}", ps);
+            }
+        }
+        match p.parse_sentence("$GPZDA,201530.00,04,07,2002,05,30*66") {
+            Ok(ParsedMessage::Zda(zda)) => {
+                assert_eq!(
+                    zda.timezone_local,
+                    FixedOffset::east_opt(5 * 3600 + 30 * 60)
+                );
+                assert_eq!(
+                    zda.local_time().map(|t| t.naive_local()),
+                    NaiveDate::from_ymd_opt(2002, 7, 5).and_then(|d| d.and_hms_opt(1, 45, 30))
+                );
+            }
+            ps => {
+                panic!("Unexpected result: {:?}", ps);
+            }
+        }
+    }
+    #[test]
+    fn test_parse_zda_leap_second() {
+        match NmeaParser::new().parse_sentence("$GPZDA,235960.00,31,12,2016,00,00*69") {
+            Ok(ParsedMessage::Zda(zda)) => {
+                let leap = NaiveDate::from_ymd_opt(2016, 12, 31)
+                    .and_then(|d| d.and_hms_nano_opt(23, 59, 59, 1_000_000_000))
+                    .map(|t| t.and_utc());
+                assert_eq!(zda.timestamp_utc, leap);
+            }
+            ps => {
+                panic!("Unexpected result: {:?}", ps);
+            }
+        }
+
+        // Leap second with fractions overflowing a second
+        match NmeaParser::new().parse_sentence("$GPZDA,235960.5e9,04,07,2002,00,00") {
+            Ok(ParsedMessage::Zda(zda)) => {
+                assert_eq!(zda.timestamp_utc, None);
+            }
+            ps => {
+                panic!("Unexpected result: {:?}", ps);
+            }
+        }
+    }
 }
@@ -20,11 +20,13 @@ limitations under the License.
 //! NMEA 0183 standard. The parser supports AIS class A and B types. It also identifies GPS,
 //! GLONASS, Galileo, BeiDou, NavIC and QZSS satellite systems.
 //!
-//! Usage in a `#[no_std]` environment is also possible though an allocator is required
+//! Usage in a `#[no_std]` environment is also possible though an allocator is required. The
+//! `std` feature enables reading sentences from `std::io::BufRead` sources and the `async`
+//! feature from `tokio::io::AsyncBufRead` sources as a `futures_core::Stream`.
 
 #![forbid(unsafe_code)]
 #![allow(dead_code)]
-#![cfg_attr(not(test), no_std)]
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
 
 #[macro_use]
 extern crate log;
@@ -34,27 +36,58 @@ extern crate num_traits;
 #[macro_use]
 extern crate alloc;
 
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
 use alloc::string::{String, ToString};
+use alloc::sync::Arc;
 use alloc::vec::Vec;
 use bitvec::prelude::*;
 pub use chrono;
 use chrono::prelude::*;
 use chrono::{DateTime, TimeZone};
 use hashbrown::HashMap;
-use core::cmp::max;
+use core::cmp::min;
 use core::str::FromStr;
 
 #[cfg(not(test))]
 use num_traits::float::FloatCore;
 
 pub mod ais;
+pub mod alert;
+mod builder;
+mod custom;
+mod dispatcher;
+pub mod dsc;
 mod error;
 pub mod gnss;
+mod message_ref;
+mod peek;
+pub mod proprietary;
+mod reader;
+#[cfg(feature = "async")]
+mod stream;
+mod tag_block;
+mod talker;
 mod util;
+mod validate;
 mod json_date_time_utc;
 mod json_fixed_offset;
 
+pub use builder::NmeaParserBuilder;
+pub use custom::{CustomMessage, SentenceHandler};
+pub use dispatcher::Dispatcher;
 pub use error::ParseError;
+pub use message_ref::{ParsedMessageRef, VesselStaticDataRef};
+pub use peek::{peek_sentence_kind, SentenceKind};
+#[cfg(feature = "std")]
+pub use reader::ParsedLines;
+pub use reader::SentenceReader;
+#[cfg(feature = "async")]
+pub use stream::ParsedMessageStream;
+pub use tag_block::{TagBlock, TagGroup};
+pub use talker::Talker;
+pub use validate::validate_sentence;
+use message_ref::TextField;
 use util::*;
 
 // -------------------------------------------------------------------------------------------------
@@ -67,6 +100,15 @@ pub enum ParsedMessage {
     /// create the actual result. State is stored in `NmeaParser` object.
     Incomplete,
 
+    /// The AIS message has already been received within the deduplication window, typically on
+    /// the other radio channel. Returned only when enabled with
+    /// `NmeaParser::set_dedup_window()`.
+    Duplicate,
+
+    /// The sentence type is excluded by the filter set with `NmeaParser::set_sentence_filter()`
+    /// and the sentence wasn't parsed.
+    Skipped,
+
     /// AIS VDM/VDO t1, t2, t3, t18 and t27
     VesselDynamicData(ais::VesselDynamicData),
 
@@ -78,12 +120,12 @@ pub enum ParsedMessage {
 
     /// AIS VDM/VDO type 6
     BinaryAddressedMessage(ais::BinaryAddressedMessage),
-    //
-    //    /// AIS VDM/VDO type 7
-    //    BinaryAcknowledge(ais::BinaryAcknowledge),
-    //
-    //    /// AIS VDM/VDO type 8
-    //    BinaryBroadcastMessage(ais::BinaryBroadcastMessage),
+
+    /// AIS VDM/VDO type 7
+    BinaryAcknowledge(ais::BinaryAcknowledge),
+
+    /// AIS VDM/VDO type 8
+    BinaryBroadcastMessage(ais::BinaryBroadcastMessage),
 
     // AIS VDM/VDO type 9
     StandardSarAircraftPositionReport(ais::StandardSarAircraftPositionReport),
@@ -130,6 +172,21 @@ pub enum ParsedMessage {
     // AIS VDM/VDO type 26
     MultipleSlotBinaryMessage(ais::MultipleSlotBinaryMessage),
 
+    /// AIS VDM/VDO message of a type not supported by the crate
+    UnsupportedAisMessage(ais::UnsupportedAisMessage),
+
+    // AIS ABM transmit request
+    AddressedBinaryMessageRequest(ais::AddressedBinaryMessageRequest),
+
+    // AIS BBM transmit request
+    BroadcastBinaryMessageRequest(ais::BroadcastBinaryMessageRequest),
+
+    // AIS ACA regional channel assignment
+    RegionalChannelAssignment(ais::RegionalChannelAssignment),
+
+    // AIS ACS channel management information source
+    ChannelManagementSource(ais::ChannelManagementSource),
+
     /// GGA
     Gga(gnss::GgaData),
 
@@ -139,12 +196,27 @@ pub enum ParsedMessage {
     /// GNS
     Gns(gnss::GnsData),
 
+    /// GMP
+    Gmp(gnss::GmpData),
+
     /// GSA
     Gsa(gnss::GsaData),
 
     /// GSV
     Gsv(Vec<gnss::GsvData>),
 
+    /// GST
+    Gst(gnss::GstData),
+
+    /// RTE whose waypoint locations aren't all known yet
+    Rte(gnss::RteData),
+
+    /// Route resolved from RTE and WPL
+    Route(gnss::Route),
+
+    /// WPL
+    Wpl(gnss::WplData),
+
     /// VTG
     Vtg(gnss::VtgData),
 
@@ -163,6 +235,9 @@ pub enum ParsedMessage {
     /// STN
     Stn(gnss::StnData),
 
+    /// TRF
+    Trf(gnss::TrfData),
+
     /// VBW
     Vbw(gnss::VbwData),
 
@@ -186,6 +261,87 @@ pub enum ParsedMessage {
 
     /// MWV
     Mwv(gnss::MwvData),
+
+    /// RSD
+    Rsd(gnss::RsdData),
+
+    /// ALR
+    Alr(gnss::AlrData),
+
+    /// ACK
+    Ack(gnss::AckData),
+
+    /// ALF
+    Alf(alert::AlfData),
+
+    /// ALC
+    Alc(alert::AlcData),
+
+    /// ACN
+    Acn(alert::AcnData),
+
+    /// TXT
+    Txt(gnss::TextMessage),
+
+    /// ZTG
+    Ztg(gnss::ZtgData),
+
+    /// ZFO
+    Zfo(gnss::ZfoData),
+
+    /// HBT
+    Hbt(gnss::HbtData),
+
+    /// TLB
+    Tlb(gnss::TlbData),
+
+    /// XDR
+    Xdr(gnss::XdrData),
+
+    /// DSC
+    Dsc(dsc::DscData),
+
+    /// DSE
+    Dse(dsc::DseData),
+
+    /// Garmin PGRME
+    GarminEstimatedError(proprietary::garmin::GarminEstimatedError),
+
+    /// Garmin PGRMZ
+    GarminAltitude(proprietary::garmin::GarminAltitude),
+
+    /// Garmin PGRMM
+    GarminMapDatum(proprietary::garmin::GarminMapDatum),
+
+    /// u-blox PUBX,00
+    UbxPosition(proprietary::ublox::UbxPositionData),
+
+    /// MediaTek PMTK001
+    MtkAck(proprietary::mediatek::MtkAck),
+
+    /// MediaTek PMTK010
+    MtkSystemMessage(proprietary::mediatek::MtkSystemMessage),
+
+    /// MediaTek PMTK705
+    MtkFirmwareRelease(proprietary::mediatek::MtkFirmwareRelease),
+
+    /// SiRF PSRF150
+    SirfOkToSend(proprietary::sirf::SirfOkToSend),
+
+    /// SiRF PSRF151
+    SirfEphemerisRequest(proprietary::sirf::SirfEphemerisRequest),
+
+    /// SiRF PSRF161
+    SirfErrorReport(proprietary::sirf::SirfErrorReport),
+
+    /// Furuno PFEC,GPatt
+    FurunoAttitude(proprietary::furuno::FurunoAttitude),
+
+    /// Furuno PFEC,GPhve
+    FurunoHeave(proprietary::furuno::FurunoHeave),
+
+    /// Result of a user-registered sentence handler
+    Custom(CustomMessage),
 }
 
 // -------------------------------------------------------------------------------------------------
@@ -203,13 +359,130 @@ pub trait LatLon {
 
 // -------------------------------------------------------------------------------------------------
 
+/// Policy for verifying the checksums of the sentences given to `NmeaParser`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ChecksumPolicy {
+    /// Sentences without a checksum are rejected and the checksums are verified
+    Require,
+
+    /// The checksum is verified if the sentence has one
+    #[default]
+    VerifyIfPresent,
+
+    /// The checksums are neither required nor verified
+    Ignore,
+}
+
+/// Filter selecting the sentence types `NmeaParser` parses. Sentence types are given without
+/// the talker ID (e.g. "!VDM" or "$RMC"), except proprietary sentences which are given in full
+/// (e.g. "$PGRME").
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum SentenceFilter {
+    /// All sentence types are parsed
+    #[default]
+    All,
+
+    /// Only the listed sentence types are parsed
+    Allow(Vec<String>),
+
+    /// All but the listed sentence types are parsed
+    Deny(Vec<String>),
+}
+
+impl SentenceFilter {
+    /// Return true if the sentence with the given address field (e.g. "$GPRMC") passes the
+    /// filter.
+    pub fn accepts(&self, address: &str) -> bool {
+        let matches = |sentence_type: &String| {
+            sentence_type == address
+                || (address.len() == 6
+                    && !address.starts_with("$P")
+                    && sentence_type.len() == 4
+                    && sentence_type.as_bytes()[0] == address.as_bytes()[0]
+                    && sentence_type.as_bytes()[1..] == address.as_bytes()[3..])
+        };
+        match self {
+            SentenceFilter::All => true,
+            SentenceFilter::Allow(types) => types.iter().any(matches),
+            SentenceFilter::Deny(types) => !types.iter().any(matches),
+        }
+    }
+}
+
+/// Remove the least recently stored entries until there is room for a new one within the given
+/// capacity. Returns the number of removed entries.
+fn evict_oldest<K: Clone + Eq + core::hash::Hash, V>(
+    map: &mut HashMap<K, (V, u64)>,
+    capacity: Option<usize>,
+) -> u64 {
+    let capacity = match capacity {
+        Some(capacity) => capacity.max(1),
+        None => return 0,
+    };
+    let mut evicted = 0;
+    while map.len() >= capacity {
+        let oldest = map
+            .iter()
+            .min_by_key(|(_, (_, stored))| *stored)
+            .map(|(k, _)| k.clone());
+        match oldest {
+            Some(k) => {
+                map.remove(&k);
+                evicted += 1;
+            }
+            None => break,
+        }
+    }
+    evicted
+}
+
+/// FNV-1a hash of an AIS payload
+fn payload_hash(bv: &BitVec) -> u64 {
+    (0..bv.len())
+        .step_by(8)
+        .map(|i| pick_u64(bv, i, min(8, bv.len() - i)))
+        .fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+            (hash ^ byte).wrapping_mul(0x0100_0000_01b3)
+        })
+        ^ bv.len() as u64
+}
+
+/// Maximum number of recent AIS messages kept for duplicate suppression
+const DEDUP_CAPACITY: usize = 1024;
+
 /// NMEA sentence parser which keeps multi-sentence state between `parse_sentence` calls.
 /// The parser tries to be as permissible as possible about the field formats because some NMEA
 /// encoders don't follow the standards strictly.
 #[derive(Clone)]
 pub struct NmeaParser {
-    saved_fragments: HashMap<String, String>,
-    saved_vsds: HashMap<u32, ais::VesselStaticData>,
+    saved_fragments: HashMap<String, (String, u64)>,
+    saved_vsds: HashMap<u32, (ais::VesselStaticData, u64)>,
+    saved_gsas: HashMap<gnss::NavigationSystem, (gnss::GsaData, u64)>,
+    saved_gsvs: HashMap<(gnss::NavigationSystem, Option<u8>), (Vec<gnss::GsvData>, u64)>,
+    latest_date_time: Option<DateTime<Utc>>,
+    latest_accuracy: Option<gnss::PositionAccuracy>,
+    sentence_count: u64,
+    fragment_max_age: Option<u64>,
+    store_capacity: Option<usize>,
+    max_fragment_count: Option<u8>,
+    checksum_policy: ChecksumPolicy,
+    sentence_filter: SentenceFilter,
+    strict_fields: bool,
+    evicted_fragments: u64,
+    evicted_vsds: u64,
+    evicted_navigation_entries: u64,
+    saved_waypoints: HashMap<String, (gnss::WplData, u64)>,
+    saved_routes: HashMap<String, (gnss::RteData, u64)>,
+    infer_dates: bool,
+    correct_week_rollover: bool,
+    merge_type24: bool,
+    dedup_window: Option<chrono::Duration>,
+    reception_time: Option<DateTime<Utc>>,
+    recent_payloads: VecDeque<(u32, u8, u64, BitVec, DateTime<Utc>)>,
+    custom_handlers: HashMap<String, Arc<dyn SentenceHandler>>,
+    asm_decoders: ais::asm::AsmDecoders,
+    text_buffer: Option<String>,
+    text_ranges: message_ref::TextRanges,
 }
 
 impl Default for NmeaParser {
@@ -224,24 +497,118 @@ impl NmeaParser {
         NmeaParser {
             saved_fragments: HashMap::new(),
             saved_vsds: HashMap::new(),
+            saved_gsas: HashMap::new(),
+            saved_gsvs: HashMap::new(),
+            latest_date_time: None,
+            latest_accuracy: None,
+            sentence_count: 0,
+            fragment_max_age: None,
+            store_capacity: None,
+            max_fragment_count: None,
+            checksum_policy: ChecksumPolicy::VerifyIfPresent,
+            sentence_filter: SentenceFilter::All,
+            strict_fields: false,
+            evicted_fragments: 0,
+            evicted_vsds: 0,
+            evicted_navigation_entries: 0,
+            saved_waypoints: HashMap::new(),
+            saved_routes: HashMap::new(),
+            infer_dates: false,
+            correct_week_rollover: false,
+            merge_type24: true,
+            dedup_window: None,
+            reception_time: None,
+            recent_payloads: VecDeque::new(),
+            custom_handlers: HashMap::new(),
+            asm_decoders: HashMap::new(),
+            text_buffer: None,
+            text_ranges: message_ref::TextRanges::default(),
         }
     }
 
+    /// Construct a builder for configuring a parser.
+    pub fn builder() -> NmeaParserBuilder {
+        NmeaParserBuilder::new()
+    }
+
     /// Clear internal state of the parser. Multi-sentence state is lost when this function
-    /// is called.
+    /// is called. Registered sentence handlers and ASM decoders are kept.
     pub fn reset(&mut self) {
         self.saved_fragments.clear();
         self.saved_vsds.clear();
+        self.saved_gsas.clear();
+        self.saved_gsvs.clear();
+        self.latest_date_time = None;
+        self.latest_accuracy = None;
+        self.saved_waypoints.clear();
+        self.saved_routes.clear();
+        self.recent_payloads.clear();
+    }
+
+    /// Register a handler for sentences with the given identifier. The identifier is the first
+    /// field of the sentence without the `$` or `!` delimiter (e.g. "PXYZ" or "GPXYZ"). A three
+    /// character identifier (e.g. "XYZ") matches the sentence with any talker ID. Registered
+    /// handlers take precedence over the built-in ones.
+    pub fn register_handler(&mut self, sentence_type: &str, handler: Box<dyn SentenceHandler>) {
+        self.custom_handlers
+            .insert(sentence_type.into(), Arc::from(handler));
+    }
+
+    /// Remove the handler registered with the given identifier.
+    pub fn unregister_handler(&mut self, sentence_type: &str) {
+        self.custom_handlers.remove(sentence_type);
+    }
+
+    /// Register a decoder for AIS application specific messages with the given designated area
+    /// code (DAC) and function identifier (FI). The decoder is used for both addressed (type 6)
+    /// and broadcast (type 8) binary messages and it takes precedence over the built-in one.
+    pub fn register_asm_decoder(&mut self, dac: u16, fi: u8, decoder: Box<dyn ais::AsmDecoder>) {
+        self.asm_decoders.insert((dac, fi), Arc::from(decoder));
+    }
+
+    /// Remove the decoder registered with the given DAC and FI.
+    pub fn unregister_asm_decoder(&mut self, dac: u16, fi: u8) {
+        self.asm_decoders.remove(&(dac, fi));
     }
 
-    /// Push string-to-string mapping to store.
+    /// Find registered handler for the given sentence type (e.g. "$PXYZ").
+    fn custom_handler(&self, sentence_type: &str) -> Option<Arc<dyn SentenceHandler>> {
+        if self.custom_handlers.is_empty() {
+            return None;
+        }
+        let id = sentence_type.get(1..).unwrap_or("");
+        self.custom_handlers
+            .get(id)
+            .or_else(|| {
+                if id.len() == 5 && !id.starts_with('P') {
+                    self.custom_handlers.get(&id[2..])
+                } else {
+                    None
+                }
+            })
+            .cloned()
+    }
+
+    /// Push string-to-string mapping to store. Mappings older than the maximum fragment age
+    /// are discarded at the same time.
     fn push_string(&mut self, key: String, value: String) {
-        self.saved_fragments.insert(key, value);
+        if let Some(max_age) = self.fragment_max_age {
+            let now = self.sentence_count;
+            let count = self.saved_fragments.len();
+            self.saved_fragments
+                .retain(|_, (_, stored)| now - *stored <= max_age);
+            self.evicted_fragments += (count - self.saved_fragments.len()) as u64;
+        }
+        if !self.saved_fragments.contains_key(&key) {
+            self.evicted_fragments += evict_oldest(&mut self.saved_fragments, self.store_capacity);
+        }
+        self.saved_fragments
+            .insert(key, (value, self.sentence_count));
     }
 
     /// Pull string-to-string mapping by key from store.
     fn pull_string(&mut self, key: String) -> Option<String> {
-        self.saved_fragments.remove(&key)
+        self.saved_fragments.remove(&key).map(|(value, _)| value)
     }
 
     /// Tests whether the given string-to-string mapping exists in the store.
@@ -249,6 +616,39 @@ impl NmeaParser {
         self.saved_fragments.contains_key(&key)
     }
 
+    /// Store a fragment of a multi-sentence message with its fill bits, as only those of the
+    /// last fragment are used and the fragments may arrive in any order. Returns the combined
+    /// payload and the fill bits of the last fragment when all the fragments have been
+    /// received.
+    pub(crate) fn assemble_fragments<K: Fn(u8) -> String>(
+        &mut self,
+        key: K,
+        count: u8,
+        number: u8,
+        payload: &str,
+        fill_bits: usize,
+    ) -> Option<(String, usize)> {
+        self.push_string(key(number), format!("{},{}", payload, fill_bits));
+        if !(1..=count).all(|i| self.contains_key(key(i))) {
+            return None;
+        }
+        let mut combined = String::new();
+        let mut combined_fill_bits = 0;
+        for i in 1..=count {
+            if let Some(s) = self.pull_string(key(i)) {
+                let (fragment, fill) = s.split_once(',').unwrap_or((&s, "0"));
+                combined.push_str(fragment);
+                combined_fill_bits = fill.parse().unwrap_or(0);
+            }
+        }
+        Some((combined, combined_fill_bits))
+    }
+
+    /// Retain only the string-to-string mappings whose key satisfies the predicate.
+    fn retain_strings<F: FnMut(&str) -> bool>(&mut self, mut f: F) {
+        self.saved_fragments.retain(|k, _| f(k));
+    }
+
     /// Return number of string-to-string mappings stored.
     fn strings_count(&self) -> usize {
         self.saved_fragments.len()
@@ -256,12 +656,15 @@ impl NmeaParser {
 
     /// Push MMSI-to-VesselStaticData mapping to store.
     fn push_vsd(&mut self, mmsi: u32, vsd: ais::VesselStaticData) {
-        self.saved_vsds.insert(mmsi, vsd);
+        if !self.saved_vsds.contains_key(&mmsi) {
+            self.evicted_vsds += evict_oldest(&mut self.saved_vsds, self.store_capacity);
+        }
+        self.saved_vsds.insert(mmsi, (vsd, self.sentence_count));
     }
 
     /// Pull MMSI-to-VesselStaticData mapping from store.
     fn pull_vsd(&mut self, mmsi: u32) -> Option<ais::VesselStaticData> {
-        self.saved_vsds.remove(&mmsi)
+        self.saved_vsds.remove(&mmsi).map(|(vsd, _)| vsd)
     }
 
     /// Return number of MMSI-to-VesselStaticData mappings in store.
@@ -269,11 +672,338 @@ impl NmeaParser {
         self.saved_vsds.len()
     }
 
+    /// Push the latest GSA of the given satellite system to store.
+    fn push_gsa(&mut self, system: gnss::NavigationSystem, gsa: gnss::GsaData) {
+        if !self.saved_gsas.contains_key(&system) {
+            self.evicted_navigation_entries +=
+                evict_oldest(&mut self.saved_gsas, self.store_capacity);
+        }
+        self.saved_gsas.insert(system, (gsa, self.sentence_count));
+    }
+
+    /// Return the latest GSA received for the given satellite system. Multi-constellation
+    /// receivers send one GSA per system and each of them is kept separately.
+    pub fn latest_gsa(&self, system: gnss::NavigationSystem) -> Option<&gnss::GsaData> {
+        self.saved_gsas.get(&system).map(|(gsa, _)| gsa)
+    }
+
+    /// Enable or disable date inference. When enabled, time-only sentences (GGA, GLL, GMP, GNS
+    /// and GST) get their date from the latest RMC or ZDA sentence instead of 2000-01-01. The
+    /// date is rolled over when the time is past midnight compared to the RMC or ZDA time.
+    /// Disabled by default.
+    pub fn set_date_inference(&mut self, enabled: bool) {
+        self.infer_dates = enabled;
+    }
+
+    /// Enable or disable GPS week number rollover correction. Old receivers not aware of the
+    /// April 2019 rollover report dates 1024 weeks in the past. When enabled, RMC and ZDA dates
+    /// before the rollover are moved 1024 weeks forward. Don't enable this when parsing
+    /// recordings made before April 2019. Disabled by default.
+    pub fn set_week_rollover_correction(&mut self, enabled: bool) {
+        self.correct_week_rollover = enabled;
+    }
+
+    /// Enable or disable merging of AIS type 24 part A and B messages. When enabled, the parts
+    /// are kept until both of them have been received and a merged `VesselStaticData` is
+    /// returned. When disabled, each part is returned as such and they can be combined with
+    /// `VesselStaticData::merge()`. Enabled by default.
+    pub fn set_type24_merging(&mut self, enabled: bool) {
+        self.merge_type24 = enabled;
+    }
+
+    /// Enable or disable suppression of duplicate AIS messages. A received (VDM) message is a
+    /// duplicate when a message with the same MMSI, message type and payload has been received
+    /// within the given window. The reception time is taken from the TAG block (`c:`) or from
+    /// `set_reception_time()`; messages without a reception time are never suppressed.
+    /// Duplicates are returned as `ParsedMessage::Duplicate`. Disabled by default.
+    pub fn set_dedup_window(&mut self, window: Option<chrono::Duration>) {
+        self.dedup_window = window;
+        self.recent_payloads.clear();
+    }
+
+    /// Set the reception time of the following sentences, used for duplicate suppression when
+    /// a sentence has no TAG block time. Not set by default.
+    pub fn set_reception_time(&mut self, time: Option<DateTime<Utc>>) {
+        self.reception_time = time;
+    }
+
+    /// Set the maximum age of stored sentence fragments. The age is the number of sentences
+    /// parsed after the fragment, as the parser has no clock of its own. Fragments whose
+    /// counterparts haven't arrived within the age are discarded when the next fragment is
+    /// stored. Unlimited by default.
+    pub fn set_fragment_max_age(&mut self, max_age: Option<u64>) {
+        self.fragment_max_age = max_age;
+    }
+
+    /// Limit the number of stored sentence fragments, AIS type 24 parts waiting for their
+    /// counterparts, GSA and GSV data, waypoints and routes. Each of them is limited separately,
+    /// and the least recently stored entry is evicted when the store is full. Unlimited by
+    /// default.
+    pub fn set_store_capacity(&mut self, capacity: Option<usize>) {
+        self.store_capacity = capacity;
+    }
+
+    /// Return the number of sentence fragments evicted because of their age or the store
+    /// capacity since the parser was constructed.
+    pub fn evicted_fragments(&self) -> u64 {
+        self.evicted_fragments
+    }
+
+    /// Return the number of AIS type 24 parts evicted because of the store capacity since the
+    /// parser was constructed.
+    pub fn evicted_type24_parts(&self) -> u64 {
+        self.evicted_vsds
+    }
+
+    /// Return the number of stored GSA and GSV data, waypoints and routes evicted because of
+    /// the store capacity since the parser was constructed.
+    pub fn evicted_navigation_entries(&self) -> u64 {
+        self.evicted_navigation_entries
+    }
+
+    /// Set how sentence checksums are treated. Checksums are verified if present by default.
+    pub fn set_checksum_policy(&mut self, policy: ChecksumPolicy) {
+        self.checksum_policy = policy;
+    }
+
+    /// Select the sentence types to parse. Sentences excluded by the filter are returned as
+    /// `ParsedMessage::Skipped` without verifying or parsing them. All sentences are parsed by
+    /// default.
+    pub fn set_sentence_filter(&mut self, filter: SentenceFilter) {
+        self.sentence_filter = filter;
+    }
+
+    /// Limit the number of sentences an AIS message may span. Messages with more fragments are
+    /// rejected with `ParseError::InvalidField`. Unlimited by default.
+    pub fn set_max_fragment_count(&mut self, max_count: Option<u8>) {
+        self.max_fragment_count = max_count;
+    }
+
+    /// Enable or disable strict checking of AIS sentence fields. When enabled, a missing or
+    /// unparseable sequential message ID of a multi-sentence message, or a fragment number
+    /// outside of the fragment count is an error.
+    /// When disabled, such sentences are only logged and `ParsedMessage::Incomplete` is
+    /// returned. Disabled by default.
+    pub fn set_strict_fields(&mut self, enabled: bool) {
+        self.strict_fields = enabled;
+    }
+
+    /// Check whether the message received at the given time is a duplicate of a recently
+    /// received one and remember it.
+    fn is_duplicate(&mut self, bv: &BitVec, received: Option<DateTime<Utc>>) -> bool {
+        let (window, now) = match (self.dedup_window, received) {
+            (Some(window), Some(now)) => (window, now),
+            _ => return false,
+        };
+        let mmsi = pick_u64(bv, 8, 30) as u32;
+        let message_type = pick_u64(bv, 0, 6) as u8;
+        let hash = payload_hash(bv);
+
+        self.recent_payloads
+            .retain(|(_, _, _, _, t)| (now - *t).abs() <= window);
+
+        // The payloads are compared in case of a hash collision
+        if self
+            .recent_payloads
+            .iter()
+            .any(|(m, t, h, b, _)| *m == mmsi && *t == message_type && *h == hash && b == bv)
+        {
+            return true;
+        }
+        if self.recent_payloads.len() >= DEDUP_CAPACITY {
+            self.recent_payloads.pop_front();
+        }
+        self.recent_payloads
+            .push_back((mmsi, message_type, hash, bv.clone(), now));
+        false
+    }
+
+    /// Apply GPS week number rollover correction to the given timestamp if enabled.
+    fn correct_date(&self, timestamp: DateTime<Utc>) -> DateTime<Utc> {
+        let rollover = Utc.with_ymd_and_hms(2019, 4, 7, 0, 0, 0).unwrap();
+        if self.correct_week_rollover && timestamp < rollover {
+            timestamp + chrono::Duration::weeks(1024)
+        } else {
+            timestamp
+        }
+    }
+
+    /// Push the timestamp of the latest sentence having both date and time to store.
+    fn push_date_time(&mut self, timestamp: DateTime<Utc>) {
+        self.latest_date_time = Some(timestamp);
+    }
+
+    /// Replace the date of a time-only timestamp with the date of the latest RMC or ZDA when
+    /// date inference is enabled. The closest day within twelve hours is chosen to handle
+    /// midnight rollover.
+    fn infer_date(&self, timestamp: Option<DateTime<Utc>>) -> Option<DateTime<Utc>> {
+        let (timestamp, latest) = match (timestamp, self.latest_date_time) {
+            (Some(timestamp), Some(latest)) if self.infer_dates => (timestamp, latest),
+            _ => return timestamp,
+        };
+        let candidate = latest.date_naive().and_time(timestamp.time()).and_utc();
+        let diff = candidate - latest;
+        if diff < chrono::Duration::hours(-12) {
+            Some(candidate + chrono::Duration::days(1))
+        } else if diff > chrono::Duration::hours(12) {
+            Some(candidate - chrono::Duration::days(1))
+        } else {
+            Some(candidate)
+        }
+    }
+
+    /// Push the latest position accuracy estimate to store.
+    fn push_accuracy(&mut self, accuracy: gnss::PositionAccuracy) {
+        self.latest_accuracy = Some(accuracy);
+    }
+
+    /// Return the latest position accuracy estimate received in GST. It can be attached to the
+    /// position fixes following it.
+    pub fn latest_accuracy(&self) -> Option<&gnss::PositionAccuracy> {
+        self.latest_accuracy.as_ref()
+    }
+
+    /// Push waypoint location to store.
+    fn push_waypoint(&mut self, waypoint_id: String, wpl: gnss::WplData) {
+        if !self.saved_waypoints.contains_key(&waypoint_id) {
+            self.evicted_navigation_entries +=
+                evict_oldest(&mut self.saved_waypoints, self.store_capacity);
+        }
+        self.saved_waypoints
+            .insert(waypoint_id, (wpl, self.sentence_count));
+    }
+
+    /// Push the latest complete route to store.
+    fn push_route(&mut self, rte: gnss::RteData) {
+        let route_id = rte.route_id.clone().unwrap_or_default();
+        if !self.saved_routes.contains_key(&route_id) {
+            self.evicted_navigation_entries +=
+                evict_oldest(&mut self.saved_routes, self.store_capacity);
+        }
+        self.saved_routes
+            .insert(route_id, (rte, self.sentence_count));
+    }
+
+    /// Return the latest route with the given identifier resolved with the waypoint locations
+    /// received in WPL sentences. `None` is returned if the route or some of its waypoints are
+    /// unknown.
+    pub fn route(&self, route_id: &str) -> Option<gnss::Route> {
+        let (rte, _) = self.saved_routes.get(route_id)?;
+        gnss::Route::resolve(rte, |id| self.saved_waypoints.get(id).map(|(wpl, _)| wpl))
+    }
+
+    /// Push the latest complete GSV satellite list of the given satellite system and signal to
+    /// store.
+    fn push_gsv(
+        &mut self,
+        system: gnss::NavigationSystem,
+        signal_id: Option<u8>,
+        satellites: Vec<gnss::GsvData>,
+    ) {
+        let key = (system, signal_id);
+        if !self.saved_gsvs.contains_key(&key) {
+            self.evicted_navigation_entries +=
+                evict_oldest(&mut self.saved_gsvs, self.store_capacity);
+        }
+        self.saved_gsvs
+            .insert(key, (satellites, self.sentence_count));
+    }
+
+    /// Return the satellites in view of all satellite systems combined from the latest GSV
+    /// sentences. Satellites listed in the latest GSA of their system are flagged as used in
+    /// the fix.
+    pub fn satellites_in_view(&self) -> gnss::SatellitesInView {
+        gnss::SatellitesInView::new(
+            self.saved_gsvs.values().flat_map(|(gsvs, _)| gsvs),
+            |system| self.latest_gsa(system),
+        )
+    }
+
+    /// Parse NMEA sentence like `parse_sentence` and return also the talker that originated
+    /// it. This is useful for separating the data of several instruments sharing the same bus.
+    ///
+    /// The talker isn't stored in the parsed results themselves. GNSS results carry only the
+    /// navigation system derived from it in their `source` field.
+    pub fn parse_sentence_with_talker(
+        &mut self,
+        sentence: &str,
+    ) -> Result<(Talker, ParsedMessage), ParseError> {
+        let talker = Talker::from_sentence(sentence)?;
+        Ok((talker, self.parse_sentence(sentence)?))
+    }
+
+    /// Parse NMEA sentence like `parse_sentence` and return also the NMEA 4.0 TAG block
+    /// preceding it, if any.
+    pub fn parse_sentence_with_tag_block(
+        &mut self,
+        sentence: &str,
+    ) -> Result<(Option<TagBlock>, ParsedMessage), ParseError> {
+        let (_, sentence) = split_prefix(sentence);
+        let verify = self.checksum_policy != ChecksumPolicy::Ignore;
+        let (tag_block, sentence) = tag_block::split_tag_block(sentence, verify)?;
+        let message = self.parse_untagged_sentence(sentence, tag_block.as_ref())?;
+        Ok((tag_block, message))
+    }
+
+    /// Parse NMEA sentence like `parse_sentence` and return also the prefix added in front of
+    /// it by a network gateway or a router (e.g. "UdPbC\0"). The prefix is empty if there is
+    /// none.
+    pub fn parse_sentence_with_prefix<'a>(
+        &mut self,
+        sentence: &'a str,
+    ) -> Result<(&'a str, ParsedMessage), ParseError> {
+        let (prefix, sentence) = split_prefix(sentence);
+        Ok((prefix, self.parse_sentence(sentence)?))
+    }
+
     /// Parse NMEA sentence into `ParsedMessage` enum. If the given sentence is part of
     /// a multipart message the related state is saved into the parser and
     /// `ParsedMessage::Incomplete` is returned. The actual result is returned when all the parts
-    /// have been sent to the parser.
+    /// have been sent to the parser. A TAG block preceding the sentence is verified and its
+    /// sentence grouping is used for multipart messages without a sequential message ID.
     pub fn parse_sentence(&mut self, sentence: &str) -> Result<ParsedMessage, ParseError> {
+        Ok(self.parse_sentence_with_tag_block(sentence)?.1)
+    }
+
+    /// Parse NMEA sentence given as bytes, e.g. directly from a serial port or UDP buffer.
+    /// Invalid UTF-8 in front of the sentence (binary gateway prefixes or line noise) is
+    /// skipped. Invalid UTF-8 after the sentence start results `ParseError::InvalidEncoding`
+    /// with the position of the offending byte.
+    pub fn parse_bytes(&mut self, bytes: &[u8]) -> Result<ParsedMessage, ParseError> {
+        let mut start = 0;
+        let sentence = loop {
+            match core::str::from_utf8(&bytes[start..]) {
+                Ok(sentence) => break sentence,
+                Err(e) => {
+                    let pos = start + e.valid_up_to();
+                    if bytes[..pos]
+                        .iter()
+                        .any(|b| matches!(b, b'$' | b'!' | b'\\'))
+                    {
+                        return Err(ParseError::InvalidEncoding(pos));
+                    }
+                    start = pos + e.error_len().unwrap_or(bytes.len() - pos);
+                }
+            }
+        };
+        self.parse_sentence(sentence)
+    }
+
+    /// Return an iterator parsing the lines read from the given reader. Incomplete multipart
+    /// messages, filtered sentences and empty lines are skipped, and I/O errors are returned as `ParseError::Io`.
+    #[cfg(feature = "std")]
+    pub fn parse_lines<R: std::io::BufRead>(&mut self, reader: R) -> ParsedLines<'_, R> {
+        ParsedLines::new(self, reader)
+    }
+
+    /// Parse NMEA sentence whose TAG block has already been removed.
+    fn parse_untagged_sentence(
+        &mut self,
+        sentence: &str,
+        tag_block: Option<&TagBlock>,
+    ) -> Result<ParsedMessage, ParseError> {
+        self.sentence_count += 1;
+
         // Shed characters prefixing the message if they exist
         let sentence = {
             if let Some(start_idx) = sentence.find(['$', '!']) {
@@ -286,16 +1016,22 @@ impl NmeaParser {
             }
         };
 
+        // Skip filtered sentence types before doing any other work
+        if self.sentence_filter != SentenceFilter::All {
+            let address = sentence.split([',', '*']).next().unwrap_or_default();
+            if !self.sentence_filter.accepts(address) {
+                return Ok(ParsedMessage::Skipped);
+            }
+        }
+
         // Calculate NMEA checksum and compare it to the given one. Also, remove the checksum part
         // from the sentence to simplify next processing steps.
         let mut checksum = 0;
         let (sentence, checksum_hex_given) = {
             if let Some(pos) = sentence.rfind('*') {
-                if pos + 3 <= sentence.len() {
-                    (
-                        sentence[0..pos].to_string(),
-                        sentence[(pos + 1)..(pos + 3)].to_string(),
-                    )
+                // The checksum may be followed by non-ASCII garbage, so slice it carefully
+                if let Some(checksum_hex) = sentence.get((pos + 1)..(pos + 3)) {
+                    (sentence[0..pos].to_string(), checksum_hex.to_string())
                 } else {
                     debug!("Invalid checksum found for sentence: {}", sentence);
                     (sentence[0..pos].to_string(), "".to_string())
@@ -305,15 +1041,25 @@ impl NmeaParser {
                 (sentence.to_string(), "".to_string())
             }
         };
-        for c in sentence.as_str().chars().skip(1) {
-            checksum ^= c as u8;
-        }
-        let checksum_hex_calculated = format!("{:02X?}", checksum);
-        if checksum_hex_calculated != checksum_hex_given && !checksum_hex_given.is_empty() {
-            return Err(ParseError::CorruptedSentence(format!(
-                "Corrupted NMEA sentence: {:02X?} != {:02X?}",
-                checksum_hex_calculated, checksum_hex_given
-            )));
+        if checksum_hex_given.is_empty() {
+            if self.checksum_policy == ChecksumPolicy::Require {
+                return Err(ParseError::CorruptedSentence(format!(
+                    "Missing checksum: {}",
+                    sentence
+                )));
+            }
+        } else if self.checksum_policy != ChecksumPolicy::Ignore {
+            for c in sentence.as_str().chars().skip(1) {
+                checksum ^= c as u8;
+            }
+            // Some encoders write the checksum with lowercase hex digits
+            let checksum_hex_calculated = format!("{:02X?}", checksum);
+            if !checksum_hex_calculated.eq_ignore_ascii_case(&checksum_hex_given) {
+                return Err(ParseError::ChecksumMismatch {
+                    calculated: checksum_hex_calculated,
+                    given: checksum_hex_given,
+                });
+            }
         }
 
         // Pick sentence type
@@ -339,6 +1085,11 @@ impl NmeaParser {
             )));
         }
 
+        // Let registered handlers take the sentence before the built-in ones
+        if let Some(handler) = self.custom_handler(sentence_type) {
+            return handler.handle(sentence.as_str());
+        }
+
         let (nav_system, station, sentence_type) = if sentence_type.starts_with('$') {
             // Identify GNSS system by talker ID.
             let nav_system = gnss::NavigationSystem::from_str(
@@ -346,7 +1097,7 @@ impl NmeaParser {
                     .get(1..)
                     .ok_or(ParseError::CorruptedSentence("Empty String".to_string()))?,
             )?;
-            let sentence_type = if !sentence_type.starts_with('P') && sentence_type.len() == 6 {
+            let sentence_type = if !sentence_type.starts_with("$P") && sentence_type.len() == 6 {
                 format!(
                     "${}",
                     sentence_type
@@ -390,19 +1141,27 @@ impl NmeaParser {
         // Handle sentence types
         match sentence_type.as_str() {
             // $xxGGA - Global Positioning System Fix Data
-            "$GGA" => gnss::gga::handle(sentence.as_str(), nav_system),
+            "$GGA" => gnss::gga::handle(sentence.as_str(), nav_system, self),
             // $xxRMC - Recommended minimum specific GPS/Transit data
-            "$RMC" => gnss::rmc::handle(sentence.as_str(), nav_system),
+            "$RMC" => gnss::rmc::handle(sentence.as_str(), nav_system, self),
             // $xxGNS - GNSS fix data
-            "$GNS" => gnss::gns::handle(sentence.as_str(), nav_system),
+            "$GNS" => gnss::gns::handle(sentence.as_str(), nav_system, self),
+            // $xxGMP - GNSS map projection fix data
+            "$GMP" => gnss::gmp::handle(sentence.as_str(), nav_system, self),
             // $xxGSA - GPS DOP and active satellites
-            "$GSA" => gnss::gsa::handle(sentence.as_str(), nav_system),
+            "$GSA" => gnss::gsa::handle(sentence.as_str(), nav_system, self),
             // $xxGSV - GPS Satellites in view
             "$GSV" => gnss::gsv::handle(sentence.as_str(), nav_system, self),
+            // $xxGST - GNSS pseudorange error statistics
+            "$GST" => gnss::gst::handle(sentence.as_str(), nav_system, self),
+            // $xxRTE - Routes
+            "$RTE" => gnss::rte::handle(sentence.as_str(), nav_system, self),
+            // $xxWPL - Waypoint location
+            "$WPL" => gnss::wpl::handle(sentence.as_str(), nav_system, self),
             // $xxVTG - Track made good and ground speed
             "$VTG" => gnss::vtg::handle(sentence.as_str(), nav_system),
             // $xxGLL - Geographic position, latitude / longitude
-            "$GLL" => gnss::gll::handle(sentence.as_str(), nav_system),
+            "$GLL" => gnss::gll::handle(sentence.as_str(), nav_system, self),
             // $xxALM - Almanac Data
             "$ALM" => gnss::alm::handle(sentence.as_str(), nav_system),
             // $xxDTM - Datum reference
@@ -411,10 +1170,18 @@ impl NmeaParser {
             "$MSS" => gnss::mss::handle(sentence.as_str(), nav_system),
             // $xxSTN - Multiple Data ID
             "$STN" => gnss::stn::handle(sentence.as_str(), nav_system),
+            // $xxTRF - Transit fix data
+            "$TRF" => gnss::trf::handle(sentence.as_str(), nav_system),
             // $xxVBW - MSK Receiver Signal
             "$VBW" => gnss::vbw::handle(sentence.as_str(), nav_system),
             // $xxZDA - Date and time
-            "$ZDA" => gnss::zda::handle(sentence.as_str(), nav_system),
+            "$ZDA" => gnss::zda::handle(sentence.as_str(), nav_system, self),
+            // $xxTXT - Text transmission
+            "$TXT" => gnss::txt::handle(sentence.as_str(), nav_system, self),
+            // $xxZTG - UTC and time to destination waypoint
+            "$ZTG" => gnss::ztg::handle(sentence.as_str(), nav_system),
+            // $xxZFO - UTC and time from origin waypoint
+            "$ZFO" => gnss::zfo::handle(sentence.as_str(), nav_system),
 
             // Received AIS data from other or own vessel
             "!VDM" | "!VDO" => {
@@ -424,6 +1191,7 @@ impl NmeaParser {
                 let mut message_id = None;
                 let mut radio_channel_code = None;
                 let mut payload_string: String = "".into();
+                let mut fill_bits = 0;
                 for (num, s) in sentence.split(',').enumerate() {
                     match num {
                         1 => {
@@ -432,10 +1200,10 @@ impl NmeaParser {
                                     fragment_count = i;
                                 }
                                 Err(_) => {
-                                    return Err(ParseError::InvalidSentence(format!(
-                                        "Failed to parse fragment count: {}",
-                                        s
-                                    )));
+                                    return Err(ParseError::InvalidField {
+                                        name: "fragment count".into(),
+                                        value: s.into(),
+                                    });
                                 }
                             };
                         }
@@ -445,15 +1213,21 @@ impl NmeaParser {
                                     fragment_number = i;
                                 }
                                 Err(_) => {
-                                    return Err(ParseError::InvalidSentence(format!(
-                                        "Failed to parse fragment count: {}",
-                                        s
-                                    )));
+                                    return Err(ParseError::InvalidField {
+                                        name: "fragment number".into(),
+                                        value: s.into(),
+                                    });
                                 }
                             };
                         }
                         3 => {
                             message_id = s.parse::<u64>().ok();
+                            if message_id.is_none() && !s.is_empty() && self.strict_fields {
+                                return Err(ParseError::InvalidField {
+                                    name: "message id".into(),
+                                    value: s.into(),
+                                });
+                            }
                         }
                         4 => {
                             // Radio channel code
@@ -463,100 +1237,97 @@ impl NmeaParser {
                             payload_string = s.to_string();
                         }
                         6 => {
-                            // fill bits
+                            fill_bits = s.parse::<usize>().unwrap_or(0);
+                            if fill_bits > 5 {
+                                return Err(ParseError::InvalidField {
+                                    name: "fill bits".into(),
+                                    value: s.into(),
+                                });
+                            }
                         }
                         _ => {}
                     }
                 }
 
+                // TAG block grouping stands in for a missing sequential message ID
+                if message_id.is_none() && fragment_count > 1 {
+                    message_id = tag_block.and_then(|t| t.group).map(|g| g.group_id as u64);
+                }
+                if matches!(self.max_fragment_count, Some(max) if fragment_count > max) {
+                    return Err(ParseError::InvalidField {
+                        name: "fragment count".into(),
+                        value: fragment_count.to_string(),
+                    });
+                }
+                if self.strict_fields && (fragment_number == 0 || fragment_number > fragment_count)
+                {
+                    return Err(ParseError::InvalidField {
+                        name: "fragment number".into(),
+                        value: fragment_number.to_string(),
+                    });
+                }
+
                 // Try parse the payload
                 let mut bv: Option<BitVec> = None;
-                match fragment_count {
-                    1 => bv = parse_payload(&payload_string).ok(),
-                    2 => {
-                        if let Some(msg_id) = message_id {
-                            let key1 = make_fragment_key(
-                                &sentence_type.to_string(),
-                                msg_id,
-                                fragment_count,
-                                1,
-                                radio_channel_code.unwrap_or(""),
-                            );
-                            let key2 = make_fragment_key(
-                                &sentence_type.to_string(),
-                                msg_id,
-                                fragment_count,
-                                2,
-                                radio_channel_code.unwrap_or(""),
-                            );
-                            match fragment_number {
-                                1 => {
-                                    if let Some(p) = self.pull_string(key2) {
-                                        let mut payload_string_combined = payload_string;
-                                        payload_string_combined.push_str(p.as_str());
-                                        bv = parse_payload(&payload_string_combined).ok();
-                                    } else {
-                                        self.push_string(key1, payload_string);
-                                    }
-                                }
-                                2 => {
-                                    if let Some(p) = self.pull_string(key1) {
-                                        let mut payload_string_combined = p;
-                                        payload_string_combined.push_str(payload_string.as_str());
-                                        bv = parse_payload(&payload_string_combined).ok();
-                                    } else {
-                                        self.push_string(key2, payload_string);
-                                    }
-                                }
-                                _ => {
-                                    warn!(
-                                        "Unexpected NMEA fragment number: {}/{}",
-                                        fragment_number, fragment_count
-                                    );
-                                }
-                            }
-                        } else {
-                            warn!(
-                                "NMEA message_id missing from {} than supported 2",
-                                sentence_type
-                            );
-                        }
-                    }
-                    _ => {
-                        warn!(
-                            "NMEA sentence fragment count greater ({}) than supported 2",
-                            fragment_count
-                        );
+                if fragment_count == 1 {
+                    bv = Some(parse_payload(&payload_string)?);
+                } else if fragment_number == 0 || fragment_number > fragment_count {
+                    warn!(
+                        "Unexpected NMEA fragment number: {}/{}",
+                        fragment_number, fragment_count
+                    );
+                } else if let Some(msg_id) = message_id {
+                    let channel = radio_channel_code.unwrap_or("");
+                    let key =
+                        |i| make_fragment_key(&sentence_type, msg_id, fragment_count, i, channel);
+                    if let Some((payload_string_combined, last_fill_bits)) = self
+                        .assemble_fragments(
+                            key,
+                            fragment_count,
+                            fragment_number,
+                            &payload_string,
+                            fill_bits,
+                        )
+                    {
+                        fill_bits = last_fill_bits;
+                        bv = Some(parse_payload(&payload_string_combined)?);
                     }
+                } else if self.strict_fields {
+                    return Err(ParseError::InvalidField {
+                        name: "message id".into(),
+                        value: "".into(),
+                    });
+                } else {
+                    warn!(
+                        "NMEA message_id missing from {} with {} fragments",
+                        sentence_type, fragment_count
+                    );
                 }
 
-                if let Some(bv) = bv {
+                if let Some(mut bv) = bv {
+                    // Strip the fill bits and check that the message isn't truncated
+                    bv.truncate(bv.len().saturating_sub(fill_bits));
                     let message_type = pick_u64(&bv, 0, 6);
+                    ais::check_payload_length(message_type, bv.len())?;
+                    let received = tag_block
+                        .and_then(|t| t.timestamp())
+                        .or(self.reception_time);
+                    if !own_vessel && self.is_duplicate(&bv, received) {
+                        return Ok(ParsedMessage::Duplicate);
+                    }
                     match message_type {
                         // Position report with SOTDMA/ITDMA
                         1..=3 => ais::vdm_t1t2t3::handle(&bv, station, own_vessel),
                         // Base station report
                         4 => ais::vdm_t4::handle(&bv, station, own_vessel),
                         // Ship static voyage related data
-                        5 => ais::vdm_t5::handle(&bv, station, own_vessel),
+                        5 => ais::vdm_t5::handle(&bv, station, self, own_vessel),
                         // Addressed binary message
-                        6 => ais::vdm_t6::handle(&bv, station, own_vessel),
+                        6 => ais::vdm_t6::handle(&bv, station, own_vessel, &self.asm_decoders),
                         // Binary acknowledge
-                        7 => {
-                            // TODO: implementation
-                            Err(ParseError::UnsupportedSentenceType(format!(
-                                "Unsupported {} message type: {}",
-                                sentence_type, message_type
-                            )))
-                        }
+                        7 => ais::vdm_t7::handle(&bv, station, own_vessel),
                         // Binary broadcast message
-                        8 => {
-                            // TODO: implementation
-                            Err(ParseError::UnsupportedSentenceType(format!(
-                                "Unsupported {} message type: {}",
-                                sentence_type, message_type
-                            )))
-                        }
+                        8 => ais::vdm_t8::handle(&bv, station, own_vessel, &self.asm_decoders),
                         // Standard SAR aircraft position report
                         9 => ais::vdm_t9::handle(&bv, station, own_vessel),
                         // UTC and Date inquiry
@@ -595,21 +1366,59 @@ impl NmeaParser {
                         26 => ais::vdm_t26::handle(&bv, station, own_vessel),
                         // Long range AIS broadcast message
                         27 => ais::vdm_t27::handle(&bv, station, own_vessel),
-                        _ => Err(ParseError::UnsupportedSentenceType(format!(
-                            "Unsupported {} message type: {}",
-                            sentence_type, message_type
-                        ))),
+                        _ => Ok(ParsedMessage::UnsupportedAisMessage(
+                            ais::UnsupportedAisMessage {
+                                own_vessel,
+                                station,
+                                message_type: message_type as u8,
+                                mmsi: pick_u64(&bv, 8, 30) as u32,
+                                bits: bv,
+                            },
+                        )),
                     }
                 } else {
                     Ok(ParsedMessage::Incomplete)
                 }
             }
+            // Binary messages given to own AIS transponder for transmission
+            "!ABM" => ais::abm::handle(sentence.as_str(), station, self),
+            "!BBM" => ais::bbm::handle(sentence.as_str(), station, self),
+            // AIS channel management from own transponder
+            "$ACA" => ais::aca::handle(sentence.as_str()),
+            "$ACS" => ais::acs::handle(sentence.as_str()),
             "$DPT" => gnss::dpt::handle(sentence.as_str()),
             "$DBS" => gnss::dbs::handle(sentence.as_str()),
             "$MTW" => gnss::mtw::handle(sentence.as_str()),
             "$VHW" => gnss::vhw::handle(sentence.as_str()),
             "$HDT" => gnss::hdt::handle(sentence.as_str()),
             "$MWV" => gnss::mwv::handle(sentence.as_str()),
+            "$RSD" => gnss::rsd::handle(sentence.as_str()),
+            "$ALR" => gnss::alr::handle(sentence.as_str()),
+            "$ACK" => gnss::ack::handle(sentence.as_str()),
+            "$ALF" => alert::alf::handle(sentence.as_str(), self),
+            "$ALC" => alert::alc::handle(sentence.as_str()),
+            "$ACN" => alert::acn::handle(sentence.as_str()),
+            "$HBT" => gnss::hbt::handle(sentence.as_str()),
+            "$TLB" => gnss::tlb::handle(sentence.as_str()),
+            "$XDR" => gnss::xdr::handle(sentence.as_str()),
+            "$DSC" => dsc::call::handle(sentence.as_str()),
+            "$DSE" => dsc::expansion::handle(sentence.as_str()),
+            // Garmin proprietary sentences
+            "$PGRME" => proprietary::garmin::handle_pgrme(sentence.as_str()),
+            "$PGRMZ" => proprietary::garmin::handle_pgrmz(sentence.as_str()),
+            "$PGRMM" => proprietary::garmin::handle_pgrmm(sentence.as_str()),
+            // u-blox proprietary sentences
+            "$PUBX" => proprietary::ublox::handle(sentence.as_str()),
+            // MediaTek proprietary sentences
+            "$PMTK001" => proprietary::mediatek::handle_ack(sentence.as_str()),
+            "$PMTK010" => proprietary::mediatek::handle_sys_msg(sentence.as_str()),
+            "$PMTK705" => proprietary::mediatek::handle_firmware_release(sentence.as_str()),
+            // SiRF proprietary sentences
+            "$PSRF150" => proprietary::sirf::handle_ok_to_send(sentence.as_str()),
+            "$PSRF151" => proprietary::sirf::handle_ephemeris_request(sentence.as_str()),
+            "$PSRF161" => proprietary::sirf::handle_error_report(sentence.as_str()),
+            // Furuno proprietary sentences
+            "$PFEC" => proprietary::furuno::handle(sentence.as_str()),
             _ => Err(ParseError::UnsupportedSentenceType(format!(
                 "Unsupported sentence type: {}",
                 sentence_type
@@ -638,9 +1447,22 @@ mod test {
         );
         assert_eq!(
             p.parse_sentence("!AIVDM,not,a,valid,nmea,string,0*00"),
-            Err(ParseError::CorruptedSentence(
-                "Corrupted NMEA sentence: \"17\" != \"00\"".to_string()
-            ))
+            Err(ParseError::ChecksumMismatch {
+                calculated: "17".to_string(),
+                given: "00".to_string()
+            })
+        );
+        assert_eq!(
+            p.parse_sentence("$SDDPT,x.5,0.5"),
+            Err(ParseError::InvalidField {
+                name: "field 1".to_string(),
+                value: "x.5".to_string()
+            })
+        );
+        assert_eq!(
+            p.parse_sentence("!AIVDM,1,1,,A,15RTgt0PAso;90TKcjM8h6g208CQ,9")
+                .map_err(|e| e.to_string()),
+            Err("Invalid value for fill bits: 9".to_string())
         );
         assert_eq!(
             p.parse_sentence("!"),
@@ -649,6 +1471,61 @@ mod test {
             ))
         );
     }
+    #[test]
+    fn test_parse_gateway_prefix() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence_with_prefix(
+            "UdPbC\0!router!\\s:2573345,c:1624104769*01\\!AIVDM,1,1,,A,15RTgt0PAso;90TKcjM8h6g208CQ,0*4A",
+        ) {
+            Ok((prefix, ps)) => {
+                assert_eq!(prefix, "UdPbC\0!router!");
+                match ps {
+                    ParsedMessage::VesselDynamicData(vdd) => {
+                        assert_eq!(vdd.mmsi, 371798000);
+                    }
+                    _ => {
+                        panic!("Unexpected result: {:?}", ps);
+                    }
+                }
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+        assert_eq!(
+            p.parse_sentence_with_prefix("$SDDPT,2.4,0.5*54")
+                .map(|(prefix, _)| prefix),
+            Ok("")
+        );
+        assert!(p.parse_sentence("$router$ $SDDPT,2.4,0.5*54").is_ok());
+    }
+
+    #[test]
+    fn test_parse_bytes() {
+        let mut p = NmeaParser::new();
+        match p.parse_bytes(b"\xff\xfe\x00$SDDPT,2.4,0.5*54\r\n") {
+            Ok(ps) => match ps {
+                ParsedMessage::Dpt(dpt) => {
+                    assert_eq!(dpt.depth_relative_to_transducer, Some(2.4));
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+        assert_eq!(
+            p.parse_bytes(b"$SDDPT,2.\xff,0.5*54"),
+            Err(ParseError::InvalidEncoding(9))
+        );
+
+        // Multi-byte characters within the checksum
+        let _ = p.parse_bytes("$GPGGA,1*Aé".as_bytes());
+        let _ = p.parse_bytes("!AIVDM,1,1,,A,1*4é".as_bytes());
+    }
+
     #[test]
     fn test_parse_prefix_chars() {
         // Try a sentence with prefix characters
@@ -669,6 +1546,58 @@ mod test {
             .is_none());
     }
 
+    #[test]
+    fn test_sentence_filter() {
+        let mut p = NmeaParser::new();
+        p.set_sentence_filter(SentenceFilter::Allow(vec!["!VDM".into(), "$RMC".into()]));
+        assert!(matches!(
+            p.parse_sentence("!AIVDM,1,1,,A,15RTgt0PAso;90TKcjM8h6g208CQ,0*4A"),
+            Ok(ParsedMessage::VesselDynamicData(_))
+        ));
+        assert_eq!(
+            p.parse_sentence("$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47"),
+            Ok(ParsedMessage::Skipped)
+        );
+
+        p.set_sentence_filter(SentenceFilter::Deny(vec!["$GGA".into(), "$PGRME".into()]));
+        assert_eq!(
+            p.parse_sentence("$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47"),
+            Ok(ParsedMessage::Skipped)
+        );
+        assert_eq!(
+            p.parse_sentence("$PGRME,15.0,M,45.0,M,25.0,M*1C"),
+            Ok(ParsedMessage::Skipped)
+        );
+        assert!(matches!(
+            p.parse_sentence("$SDDPT,2.4,0.5*54"),
+            Ok(ParsedMessage::Dpt(_))
+        ));
+    }
+
+    #[test]
+    fn test_checksum_policy() {
+        let mut p = NmeaParser::new();
+        assert!(p
+            .parse_sentence("!AIVDM,1,1,,A,15RTgt0PAso;90TKcjM8h6g208CQ,0*4a")
+            .is_ok());
+
+        p.set_checksum_policy(ChecksumPolicy::Require);
+        assert_eq!(
+            p.parse_sentence("!AIVDM,1,1,,A,15RTgt0PAso;90TKcjM8h6g208CQ,0*4"),
+            Err(ParseError::CorruptedSentence(
+                "Missing checksum: !AIVDM,1,1,,A,15RTgt0PAso;90TKcjM8h6g208CQ,0".to_string()
+            ))
+        );
+        assert!(p
+            .parse_sentence("!AIVDM,1,1,,A,15RTgt0PAso;90TKcjM8h6g208CQ,0*4A")
+            .is_ok());
+
+        p.set_checksum_policy(ChecksumPolicy::Ignore);
+        assert!(p
+            .parse_sentence("!AIVDM,1,1,,A,15RTgt0PAso;90TKcjM8h6g208CQ,0*00")
+            .is_ok());
+    }
+
     #[test]
     fn test_parse_missing_checksum() {
         // Try a sentence without checksum
@@ -679,6 +1608,200 @@ mod test {
             .is_some());
     }
 
+    #[test]
+    fn test_parse_three_fragments() {
+        // Fragments arriving out of order, fill bits are given in the last one
+        let mut p = NmeaParser::new();
+        for s in [
+            "!AIVDM,3,3,3,B,V0lU=<TpN0tHH,2*64",
+            "!AIVDM,3,1,3,B,>3KMWh1<D=E8U@F0p5HT,0*13",
+        ] {
+            assert_eq!(p.parse_sentence(s), Ok(ParsedMessage::Incomplete));
+        }
+        assert_eq!(p.strings_count(), 2);
+        match p.parse_sentence("!AIVDM,3,2,3,B,L5@Ttp4j1L58pTpN09Du,0*72") {
+            Ok(ps) => match ps {
+                ParsedMessage::SafetyRelatedBroadcastMessage(srbm) => {
+                    assert_eq!(srbm.mmsi, 230123456);
+                    assert_eq!(srbm.text, "SECURITE NAVIGATIONAL WARNING BUOY MISSING OFF");
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+        assert_eq!(p.strings_count(), 0);
+    }
+
+    #[test]
+    fn test_fragment_max_age() {
+        let mut p = NmeaParser::new();
+        p.set_fragment_max_age(Some(2));
+        p.parse_sentence(
+            "!AIVDM,2,1,1,A,55?MbV02;H;s<HtKR20EHE:0@T4@Dn2222222216L961O5Gf0NSQEp6ClRp8,0*1C",
+        )
+        .ok();
+        assert_eq!(p.strings_count(), 1);
+        for _ in 0..3 {
+            p.parse_sentence("!AIVDM,1,1,,A,15RTgt0PAso;90TKcjM8h6g208CQ,0*4A")
+                .ok();
+        }
+
+        // The orphaned fragment is discarded when the next one is stored
+        p.parse_sentence("!AIVDM,3,1,3,B,>3KMWh1<D=E8U@F0p5HT,0*13")
+            .ok();
+        assert_eq!(p.strings_count(), 1);
+        assert_eq!(
+            p.parse_sentence("!AIVDM,2,2,1,A,88888888880,2*25"),
+            Ok(ParsedMessage::Incomplete)
+        );
+    }
+
+    #[test]
+    fn test_store_capacity() {
+        let mut p = NmeaParser::new();
+        p.set_store_capacity(Some(2));
+        for s in [
+            "!AIVDM,3,1,3,B,>3KMWh1<D=E8U@F0p5HT,0*13",
+            "!AIVDM,3,2,3,B,L5@Ttp4j1L58pTpN09Du,0*72",
+            "!AIVDM,2,1,1,A,55?MbV02;H;s<HtKR20EHE:0@T4@Dn2222222216L961O5Gf0NSQEp6ClRp8,0*1C",
+        ] {
+            assert_eq!(p.parse_sentence(s), Ok(ParsedMessage::Incomplete));
+        }
+        assert_eq!(p.strings_count(), 2);
+        assert_eq!(p.evicted_fragments(), 1);
+
+        // The first fragment of the three-part message was evicted
+        assert_eq!(
+            p.parse_sentence("!AIVDM,3,3,3,B,V0lU=<TpN0tHH,2*64"),
+            Ok(ParsedMessage::Incomplete)
+        );
+        assert_eq!(p.evicted_fragments(), 2);
+
+        // Type 24 parts waiting for their counterparts
+        for s in [
+            "!AIVDM,1,1,,A,H42O55i18tMET00000000000000,2*6D",
+            "!AIVDM,1,1,,A,H>WikQi@Dp@E800000000000000,2*76",
+            "!AIVDM,1,1,,A,H3m=TP1<tlF08t5@000000000000,0*53",
+        ] {
+            p.parse_sentence(s).ok();
+        }
+        assert_eq!(p.vsds_count(), 2);
+        assert_eq!(p.evicted_type24_parts(), 1);
+
+        // Waypoints and routes
+        for s in [
+            "$GPWPL,4917.16,N,12310.64,W,003*65",
+            "$GPWPL,4916.00,N,12311.00,W,004*67",
+            "$GPWPL,4915.50,N,12312.00,W,005*63",
+            "$GPRTE,1,1,c,0,004,005",
+            "$GPRTE,1,1,c,1,004,005",
+        ] {
+            assert!(p.parse_sentence(s).is_ok());
+        }
+        assert_eq!(p.evicted_navigation_entries(), 1);
+        assert!(p.route("1").is_some());
+    }
+
+    #[test]
+    fn test_parse_truncated_payload() {
+        // Type 1 message of 143 bits and one fill bit
+        let mut p = NmeaParser::new();
+        assert_eq!(
+            p.parse_sentence("!AIVDM,1,1,,A,13KMWh000000000000000?vt,1*11"),
+            Err(ParseError::TruncatedPayload(String::from(
+                "message type 1 has 143 bits, at least 168 expected"
+            )))
+        );
+
+        // Invalid payload characters and fill bits
+        assert!(p.parse_sentence("!AIVDM,1,1,,A,1~~,0").is_err());
+        assert!(p
+            .parse_sentence("!AIVDM,1,1,,A,38Id705000rRVJhE7cl9n;160000,6")
+            .is_err());
+    }
+
+    #[test]
+    fn test_parse_unsupported_ais_message() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("!AIVDM,1,1,,A,N3KMWh:g0,4*0B") {
+            Ok(ps) => match ps {
+                ParsedMessage::UnsupportedAisMessage(msg) => {
+                    assert_eq!(msg.message_type, 30);
+                    assert_eq!(msg.mmsi, 230123456);
+                    assert_eq!(msg.bits.len(), 50);
+                    assert_eq!(pick_u64(&msg.bits, 38, 12), 0xABC);
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_dedup() {
+        let s1 = "!AIVDM,1,1,,A,15RTgt0PAso;90TKcjM8h6g208CQ,0";
+        let s2 = "!AIVDM,1,1,,B,15RTgt0PAso;90TKcjM8h6g208CQ,0";
+        let s3 = "!AIVDM,1,1,,B,16SteH0P00Jt63hHaa6SagvJ087r,0";
+
+        // Disabled by default
+        let mut p = NmeaParser::new();
+        assert!(matches!(
+            p.parse_sentence(s1),
+            Ok(ParsedMessage::VesselDynamicData(_))
+        ));
+        assert!(matches!(
+            p.parse_sentence(s2),
+            Ok(ParsedMessage::VesselDynamicData(_))
+        ));
+
+        // Without a reception time nothing is suppressed
+        p.set_dedup_window(Some(chrono::Duration::seconds(10)));
+        p.parse_sentence("$GPZDA,201530.00,04,07,2002,00,00").ok();
+        assert!(p.parse_sentence(s1).is_ok());
+        assert!(matches!(
+            p.parse_sentence(s2),
+            Ok(ParsedMessage::VesselDynamicData(_))
+        ));
+
+        // Same message on the other channel is a duplicate
+        let t = Utc.with_ymd_and_hms(2026, 10, 16, 12, 0, 0).unwrap();
+        p.set_reception_time(Some(t));
+        assert!(matches!(
+            p.parse_sentence(s1),
+            Ok(ParsedMessage::VesselDynamicData(_))
+        ));
+        assert_eq!(p.parse_sentence(s2), Ok(ParsedMessage::Duplicate));
+        assert!(matches!(
+            p.parse_sentence(s3),
+            Ok(ParsedMessage::VesselDynamicData(_))
+        ));
+
+        // Window has passed
+        p.set_reception_time(Some(t + chrono::Duration::seconds(20)));
+        assert!(matches!(
+            p.parse_sentence(s2),
+            Ok(ParsedMessage::VesselDynamicData(_))
+        ));
+
+        // TAG block time takes precedence over the reception time
+        assert!(matches!(
+            p.parse_sentence(&format!("\\c:{}\\{}", t.timestamp() + 60, s1)),
+            Ok(ParsedMessage::VesselDynamicData(_))
+        ));
+        assert_eq!(
+            p.parse_sentence(&format!("\\c:{}\\{}", t.timestamp() + 65, s2)),
+            Ok(ParsedMessage::Duplicate)
+        );
+    }
+
     #[test]
     fn test_parse_invalid_utc() {
         // Try a sentence with invalite utc
@@ -693,23 +1816,21 @@ mod test {
 
     #[test]
     fn test_parse_proprietary() {
-        /* FIXME: The test fails
-                // Try a proprietary sentence
-                let mut p = NmeaParser::new();
-                assert_eq!(
-                    p.parse_sentence("$PGRME,15.0,M,45.0,M,25.0,M*1C"),
-                    Err(ParseError::UnsupportedSentenceType(String::from(
-                        "Unsupported sentence type: $PGRME"
-                    )))
-                );
-                // Try a proprietary sentence with four characters
-                assert_eq!(
-                    p.parse_sentence("$PGRM,00,1,,,*15"),
-                    Err(ParseError::UnsupportedSentenceType(String::from(
-                        "Unsupported sentence type: $PGRM"
-                    )))
-                );
-        */
+        // Try an unknown proprietary sentence
+        let mut p = NmeaParser::new();
+        assert_eq!(
+            p.parse_sentence("$PABCD,1,2*57"),
+            Err(ParseError::UnsupportedSentenceType(String::from(
+                "Unsupported sentence type: $PABCD"
+            )))
+        );
+        // Try a proprietary sentence with four characters
+        assert_eq!(
+            p.parse_sentence("$PGRM,00,1,,,*15"),
+            Err(ParseError::UnsupportedSentenceType(String::from(
+                "Unsupported sentence type: $PGRM"
+            )))
+        );
     }
 
     #[test]
@@ -736,6 +1857,27 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_parse_nmea_411_talkers() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$GBGSA,A,3,05,07,13,,,,,,,,,,1.2,0.8,0.9,4*3A") {
+            Ok(ParsedMessage::Gsa(gsa)) => {
+                assert_eq!(gsa.source, gnss::NavigationSystem::Beidou);
+            }
+            ps => {
+                panic!("Unexpected result: {:?}", ps);
+            }
+        }
+        match p.parse_sentence("$GQGLL,4916.45,N,12311.12,W,225444,A,A*5D") {
+            Ok(ParsedMessage::Gll(gll)) => {
+                assert_eq!(gll.source, gnss::NavigationSystem::Qzss);
+            }
+            ps => {
+                panic!("Unexpected result: {:?}", ps);
+            }
+        }
+    }
+
     #[test]
     fn test_nmea_parser() {
         let mut p = NmeaParser::new();
@@ -0,0 +1,188 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+//! Parse results borrowing their text fields from a caller-owned buffer
+
+use super::*;
+use core::ops::Range;
+
+/// Result from function `NmeaParser::parse_sentence_ref()`. The text fields of AIS static data
+/// reports borrow from the decode buffer given by the caller.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParsedMessageRef<'a> {
+    /// AIS VDM/VDO types 5 and 24 with borrowed text fields
+    VesselStaticData(VesselStaticDataRef<'a>),
+
+    /// Any other result, identical to the one of `NmeaParser::parse_sentence()`
+    Other(ParsedMessage),
+}
+
+/// AIS static data report whose name, call sign and destination borrow from a decode buffer.
+/// The corresponding fields of `data` are `None`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VesselStaticDataRef<'a> {
+    /// Static data without the borrowed text fields
+    pub data: ais::VesselStaticData,
+
+    /// Call sign (7 six-bit characters)
+    pub call_sign: Option<&'a str>,
+
+    /// Vessel name (20 six-bit characters)
+    pub name: Option<&'a str>,
+
+    /// Destination (20 six-bit characters)
+    pub destination: Option<&'a str>,
+}
+
+impl VesselStaticDataRef<'_> {
+    /// Return the report as `VesselStaticData` with owned text fields
+    pub fn to_owned_data(&self) -> ais::VesselStaticData {
+        ais::VesselStaticData {
+            call_sign: self.call_sign.map(String::from),
+            name: self.name.map(String::from),
+            destination: self.destination.map(String::from),
+            ..self.data.clone()
+        }
+    }
+}
+
+/// AIS text fields which can be borrowed from the decode buffer
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum TextField {
+    CallSign,
+    Name,
+    Destination,
+}
+
+/// Locations of the text fields within the decode buffer
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct TextRanges {
+    call_sign: Option<Range<usize>>,
+    name: Option<Range<usize>>,
+    destination: Option<Range<usize>>,
+}
+
+impl NmeaParser {
+    /// Pick an AIS text field. While parsing with `parse_sentence_ref` the text is appended to
+    /// the decode buffer and `None` is returned, otherwise the text is returned if it isn't
+    /// empty.
+    pub(crate) fn pick_text(
+        &mut self,
+        bv: &BitVec,
+        index: usize,
+        char_count: usize,
+        field: TextField,
+    ) -> Option<String> {
+        match &mut self.text_buffer {
+            Some(buffer) => {
+                let range =
+                    Some(pick_string_into(bv, index, char_count, buffer)).filter(|r| !r.is_empty());
+                match field {
+                    TextField::CallSign => self.text_ranges.call_sign = range,
+                    TextField::Name => self.text_ranges.name = range,
+                    TextField::Destination => self.text_ranges.destination = range,
+                }
+                None
+            }
+            None => Some(pick_string(bv, index, char_count)).filter(|s| !s.is_empty()),
+        }
+    }
+
+    /// Parse NMEA sentence like `parse_sentence` but decode the names, call signs and
+    /// destinations of AIS static data reports into the given buffer instead of allocating
+    /// them. The buffer is cleared first and its capacity is reused between calls. Type 24
+    /// parts are returned separately, as merging them would require owned copies.
+    pub fn parse_sentence_ref<'b>(
+        &mut self,
+        sentence: &str,
+        buffer: &'b mut String,
+    ) -> Result<ParsedMessageRef<'b>, ParseError> {
+        buffer.clear();
+        self.text_buffer = Some(core::mem::take(buffer));
+        self.text_ranges = TextRanges::default();
+        let result = self.parse_sentence(sentence);
+        *buffer = self.text_buffer.take().unwrap_or_default();
+        let ranges = core::mem::take(&mut self.text_ranges);
+
+        let buffer: &'b String = buffer;
+        match result? {
+            ParsedMessage::VesselStaticData(data) => {
+                Ok(ParsedMessageRef::VesselStaticData(VesselStaticDataRef {
+                    data,
+                    call_sign: ranges.call_sign.map(|r| &buffer[r]),
+                    name: ranges.name.map(|r| &buffer[r]),
+                    destination: ranges.destination.map(|r| &buffer[r]),
+                }))
+            }
+            message => Ok(ParsedMessageRef::Other(message)),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_sentence_ref() {
+        let mut p = NmeaParser::new();
+        let mut buffer = String::new();
+        assert_eq!(
+            p.parse_sentence_ref(
+                "!AIVDM,2,1,1,A,55?MbV02;H;s<HtKR20EHE:0@T4@Dn2222222216L961O5Gf0NSQEp6ClRp8,0*1C",
+                &mut buffer
+            ),
+            Ok(ParsedMessageRef::Other(ParsedMessage::Incomplete))
+        );
+        match p.parse_sentence_ref("!AIVDM,2,2,1,A,88888888880,2*25", &mut buffer) {
+            Ok(ParsedMessageRef::VesselStaticData(vsd)) => {
+                assert_eq!(vsd.data.mmsi, 351759000);
+                assert_eq!(vsd.data.name, None);
+                assert_eq!(vsd.call_sign, Some("3FOF8"));
+                assert_eq!(vsd.name, Some("EVER DIADEM"));
+                assert_eq!(vsd.destination, Some("NEW YORK"));
+                assert_eq!(vsd.to_owned_data().name, Some("EVER DIADEM".into()));
+            }
+            result => {
+                panic!("Unexpected result: {:?}", result);
+            }
+        }
+
+        // Type 24 parts aren't merged
+        match p.parse_sentence_ref(
+            "!AIVDM,1,1,,A,H42O55i18tMET00000000000000,2*6D",
+            &mut buffer,
+        ) {
+            Ok(ParsedMessageRef::VesselStaticData(vsd)) => {
+                assert_eq!(vsd.name, Some("PROGUY"));
+                assert_eq!(vsd.call_sign, None);
+            }
+            result => {
+                panic!("Unexpected result: {:?}", result);
+            }
+        }
+
+        // Parsing without the buffer is unaffected
+        match p.parse_sentence("!AIVDM,1,1,,A,H42O55i18tMET00000000000000,2*6D") {
+            Ok(ParsedMessage::Incomplete) => {}
+            result => {
+                panic!("Unexpected result: {:?}", result);
+            }
+        }
+    }
+}
@@ -0,0 +1,151 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+//! Sentence classification without full parsing
+
+use super::*;
+
+/// Kind of a sentence identified by `peek_sentence_kind()`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SentenceKind<'a> {
+    /// Talker of the sentence
+    pub talker: Talker,
+
+    /// Sentence type without the delimiter and the talker ID (e.g. "VDM" or "RMC"). Proprietary
+    /// sentence types are given in full (e.g. "PGRME").
+    pub sentence_type: &'a str,
+
+    /// AIS message type, if the sentence is the first fragment of a VDM or VDO message
+    pub ais_message_type: Option<u8>,
+
+    /// MMSI of the AIS message, if the sentence is the first fragment of a VDM or VDO message
+    pub mmsi: Option<u32>,
+}
+
+/// Identify the talker, the sentence type and, for AIS sentences, the message type and MMSI of
+/// the given sentence without parsing it. Only the address field and the first seven payload
+/// characters are decoded, so this is suitable for routing sentences before the actual parsing.
+/// The checksum isn't verified.
+pub fn peek_sentence_kind(sentence: &str) -> Result<SentenceKind<'_>, ParseError> {
+    let sentence = strip_framing(sentence);
+    let invalid = || ParseError::InvalidSentence(format!("Invalid NMEA sentence: {}", sentence));
+    let mut fields = sentence.split([',', '*']);
+    let address = fields.next().unwrap_or_default();
+    if address.len() < 4
+        || !(address.starts_with('$') || address.starts_with('!'))
+        || !address[1..].bytes().all(|b| b.is_ascii_alphanumeric())
+    {
+        return Err(invalid());
+    }
+
+    let talker = Talker::from_str(&address[1..])?;
+    let sentence_type = if talker == Talker::Proprietary {
+        &address[1..]
+    } else {
+        address.get(3..).ok_or_else(invalid)?
+    };
+
+    let mut kind = SentenceKind {
+        talker,
+        sentence_type,
+        ais_message_type: None,
+        mmsi: None,
+    };
+    if sentence_type == "VDM" || sentence_type == "VDO" {
+        // Fragment number is the second field and the payload the fifth one
+        let mut fields = fields.skip(1);
+        if fields.next() == Some("1") {
+            if let Some(payload) = fields.nth(2) {
+                let mut bits: u64 = 0;
+                let mut bit_count = 0;
+                for c in payload.chars().take(7) {
+                    let mut ci = match c {
+                        '0'..='W' | '`'..='w' => (c as u8) - 48,
+                        _ => break,
+                    };
+                    if ci > 40 {
+                        ci -= 8;
+                    }
+                    bits = (bits << 6) | ci as u64;
+                    bit_count += 6;
+                }
+                if bit_count >= 6 {
+                    kind.ais_message_type = Some((bits >> (bit_count - 6)) as u8);
+                }
+                if bit_count >= 38 {
+                    kind.mmsi = Some(((bits >> (bit_count - 38)) & 0x3fff_ffff) as u32);
+                }
+            }
+        }
+    }
+    Ok(kind)
+}
+
+/// Remove the gateway prefix and the TAG block from the beginning of the sentence.
+pub(crate) fn strip_framing(sentence: &str) -> &str {
+    let (_, sentence) = split_prefix(sentence);
+    match sentence.strip_prefix('\\') {
+        Some(rest) => rest.split_once('\\').map(|(_, s)| s).unwrap_or(rest),
+        None => sentence,
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_peek_sentence_kind() {
+        assert_eq!(
+            peek_sentence_kind(
+                "\\s:2573345,c:1624104769*01\\!AIVDM,1,1,,A,15RTgt0PAso;90TKcjM8h6g208CQ,0*4A"
+            ),
+            Ok(SentenceKind {
+                talker: Talker::AisMobileStation,
+                sentence_type: "VDM",
+                ais_message_type: Some(1),
+                mmsi: Some(371798000),
+            })
+        );
+        assert_eq!(
+            peek_sentence_kind(
+                "!AIVDM,2,1,0,A,55?MbV02;H;s<HtKR20EHE:0@T4@Dn2222222216L961O5Gf0NSQEp6ClRp8,0*1D"
+            )
+            .map(|k| (k.ais_message_type, k.mmsi)),
+            Ok((Some(5), Some(351759000)))
+        );
+        assert_eq!(
+            peek_sentence_kind("!AIVDM,2,2,0,A,88888888880,2*24").map(|k| k.mmsi),
+            Ok(None)
+        );
+        assert_eq!(
+            peek_sentence_kind("$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47"),
+            Ok(SentenceKind {
+                talker: Talker::Gps,
+                sentence_type: "GGA",
+                ais_message_type: None,
+                mmsi: None,
+            })
+        );
+        assert_eq!(
+            peek_sentence_kind("$PGRME,15.0,M,45.0,M,25.0,M*1C").map(|k| k.sentence_type),
+            Ok("PGRME")
+        );
+        assert!(peek_sentence_kind("garbage").is_err());
+    }
+}
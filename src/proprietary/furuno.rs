@@ -0,0 +1,124 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+//! Furuno proprietary PFEC sentences
+
+use super::*;
+use serde::Serialize;
+
+/// PFEC,GPatt - Furuno attitude
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct FurunoAttitude {
+    /// Yaw (heading) in degrees from true north
+    pub yaw: Option<f64>,
+
+    /// Pitch in degrees, bow up positive
+    pub pitch: Option<f64>,
+
+    /// Roll in degrees, starboard down positive
+    pub roll: Option<f64>,
+}
+
+/// PFEC,GPhve - Furuno heave
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct FurunoHeave {
+    /// Heave in metres, upwards positive
+    pub heave: Option<f64>,
+
+    /// Status: true = valid, false = invalid
+    pub valid: Option<bool>,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// PFEC: Furuno proprietary sentences
+pub(crate) fn handle(sentence: &str) -> Result<ParsedMessage, ParseError> {
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    match *split.get(1).unwrap_or(&"") {
+        "GPatt" => Ok(ParsedMessage::FurunoAttitude(FurunoAttitude {
+            yaw: pick_number_field(&split, 2)?,
+            pitch: pick_number_field(&split, 3)?,
+            roll: pick_number_field(&split, 4)?,
+        })),
+        "GPhve" => Ok(ParsedMessage::FurunoHeave(FurunoHeave {
+            heave: pick_number_field(&split, 2)?,
+            valid: {
+                let s = split.get(3).unwrap_or(&"");
+                match *s {
+                    "A" => Some(true),
+                    "V" => Some(false),
+                    "" => None,
+                    _ => {
+                        return Err(format!("Invalid PFEC,GPhve status: {}", s).into());
+                    }
+                }
+            },
+        })),
+        id => Err(ParseError::UnsupportedMessageType(format!("$PFEC,{}", id))),
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_pfec_gpatt() {
+        match NmeaParser::new().parse_sentence("$PFEC,GPatt,123.4,+01.5,-02.3*4F") {
+            Ok(ps) => match ps {
+                ParsedMessage::FurunoAttitude(att) => {
+                    assert_eq!(att.yaw, Some(123.4));
+                    assert_eq!(att.pitch, Some(1.5));
+                    assert_eq!(att.roll, Some(-2.3));
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_pfec_gphve() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$PFEC,GPhve,-0.125,A*14") {
+            Ok(ps) => match ps {
+                ParsedMessage::FurunoHeave(hve) => {
+                    assert_eq!(hve.heave, Some(-0.125));
+                    assert_eq!(hve.valid, Some(true));
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+        assert_eq!(
+            p.parse_sentence("$PFEC,GPint,RMC01*29"),
+            Err(ParseError::UnsupportedMessageType(String::from(
+                "$PFEC,GPint"
+            )))
+        );
+    }
+}
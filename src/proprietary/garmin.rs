@@ -0,0 +1,170 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+//! Garmin proprietary sentences
+
+use super::*;
+use serde::Serialize;
+
+/// PGRME - Garmin estimated position error
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct GarminEstimatedError {
+    /// Estimated horizontal position error (HPE) in metres
+    pub horizontal_error: Option<f64>,
+
+    /// Estimated vertical position error (VPE) in metres
+    pub vertical_error: Option<f64>,
+
+    /// Estimated overall spherical position error (EPE) in metres
+    pub spherical_error: Option<f64>,
+}
+
+/// PGRMZ - Garmin altitude
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct GarminAltitude {
+    /// Altitude in metres. Values reported in feet are converted to metres.
+    pub altitude: Option<f64>,
+
+    /// Position fix dimension: 2 = user altitude, 3 = GPS altitude
+    pub fix_dimension: Option<u8>,
+}
+
+/// PGRMM - Garmin map datum
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct GarminMapDatum {
+    /// Name of the currently active map datum (e.g. "WGS 84")
+    pub datum: Option<String>,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// PGRME: Estimated error information
+pub(crate) fn handle_pgrme(sentence: &str) -> Result<ParsedMessage, ParseError> {
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    Ok(ParsedMessage::GarminEstimatedError(GarminEstimatedError {
+        horizontal_error: pick_metres_field(&split, 1)?,
+        vertical_error: pick_metres_field(&split, 3)?,
+        spherical_error: pick_metres_field(&split, 5)?,
+    }))
+}
+
+/// PGRMZ: Altitude information
+pub(crate) fn handle_pgrmz(sentence: &str) -> Result<ParsedMessage, ParseError> {
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    Ok(ParsedMessage::GarminAltitude(GarminAltitude {
+        altitude: pick_metres_field(&split, 1)?,
+        fix_dimension: pick_number_field(&split, 3)?,
+    }))
+}
+
+/// PGRMM: Map datum
+pub(crate) fn handle_pgrmm(sentence: &str) -> Result<ParsedMessage, ParseError> {
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    Ok(ParsedMessage::GarminMapDatum(GarminMapDatum {
+        datum: pick_string_field(&split, 1),
+    }))
+}
+
+/// Pick distance field with the given number and convert it to metres using the unit in the
+/// following field ("M" = metres, "f" = feet).
+fn pick_metres_field(split: &[&str], num: usize) -> Result<Option<f64>, ParseError> {
+    let val: Option<f64> = pick_number_field(split, num)?;
+    match (val, split.get(num + 1).unwrap_or(&"")) {
+        (Some(v), &"M") | (Some(v), &"") => Ok(Some(v)),
+        (Some(v), &"f") => Ok(Some(v * 0.3048)),
+        (Some(_), unit) => Err(format!("Invalid Garmin distance unit: {}", unit).into()),
+        (None, _) => Ok(None),
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_pgrme() {
+        match NmeaParser::new().parse_sentence("$PGRME,15.0,M,45.0,M,25.0,M*1C") {
+            Ok(ps) => match ps {
+                ParsedMessage::GarminEstimatedError(pgrme) => {
+                    assert_eq!(pgrme.horizontal_error, Some(15.0));
+                    assert_eq!(pgrme.vertical_error, Some(45.0));
+                    assert_eq!(pgrme.spherical_error, Some(25.0));
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_pgrmz() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$PGRMZ,246,f,3*1B") {
+            Ok(ps) => match ps {
+                ParsedMessage::GarminAltitude(pgrmz) => {
+                    assert::close(pgrmz.altitude.unwrap_or(0.0), 74.98, 0.01);
+                    assert_eq!(pgrmz.fix_dimension, Some(3));
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+        match p.parse_sentence("$PGRMZ,93,M,2*0B") {
+            Ok(ps) => match ps {
+                ParsedMessage::GarminAltitude(pgrmz) => {
+                    assert_eq!(pgrmz.altitude, Some(93.0));
+                    assert_eq!(pgrmz.fix_dimension, Some(2));
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_pgrmm() {
+        match NmeaParser::new().parse_sentence("$PGRMM,WGS 84*06") {
+            Ok(ps) => match ps {
+                ParsedMessage::GarminMapDatum(pgrmm) => {
+                    assert_eq!(pgrmm.datum, Some("WGS 84".into()));
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+}
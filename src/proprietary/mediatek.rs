@@ -0,0 +1,238 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+//! MediaTek proprietary PMTK sentences
+
+use super::*;
+use serde::Serialize;
+
+/// PMTK001 - Acknowledgement of a PMTK command
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct MtkAck {
+    /// Packet type of the acknowledged command (e.g. 220 for PMTK220)
+    pub command: u16,
+
+    /// Result of the command
+    pub result: MtkAckResult,
+}
+
+impl MtkAck {
+    /// Return true if the command took effect.
+    pub fn is_success(&self) -> bool {
+        self.result == MtkAckResult::Success
+    }
+}
+
+/// Result of a PMTK command
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub enum MtkAckResult {
+    /// Invalid packet
+    Invalid,
+
+    /// Unsupported packet type
+    Unsupported,
+
+    /// Valid packet, but action failed
+    Failed,
+
+    /// Valid packet, action succeeded
+    Success,
+}
+
+impl MtkAckResult {
+    pub fn new(val: u8) -> Result<MtkAckResult, String> {
+        match val {
+            0 => Ok(MtkAckResult::Invalid),
+            1 => Ok(MtkAckResult::Unsupported),
+            2 => Ok(MtkAckResult::Failed),
+            3 => Ok(MtkAckResult::Success),
+            _ => Err(format!("Unrecognized PMTK acknowledgement flag: {}", val)),
+        }
+    }
+}
+
+impl core::fmt::Display for MtkAckResult {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            MtkAckResult::Invalid => write!(f, "invalid packet"),
+            MtkAckResult::Unsupported => write!(f, "unsupported packet type"),
+            MtkAckResult::Failed => write!(f, "action failed"),
+            MtkAckResult::Success => write!(f, "action succeeded"),
+        }
+    }
+}
+
+/// PMTK010 - System message
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub enum MtkSystemMessage {
+    /// Unknown message
+    Unknown,
+
+    /// Receiver started up
+    Startup,
+
+    /// Host aiding (EPO) data is needed
+    ExtendedPredictionOrbitNeeded,
+
+    /// Receiver transitioned to normal mode
+    NormalMode,
+}
+
+impl MtkSystemMessage {
+    pub fn new(val: u8) -> Result<MtkSystemMessage, String> {
+        match val {
+            0 => Ok(MtkSystemMessage::Unknown),
+            1 => Ok(MtkSystemMessage::Startup),
+            2 => Ok(MtkSystemMessage::ExtendedPredictionOrbitNeeded),
+            3 => Ok(MtkSystemMessage::NormalMode),
+            _ => Err(format!("Unrecognized PMTK system message: {}", val)),
+        }
+    }
+}
+
+impl core::fmt::Display for MtkSystemMessage {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            MtkSystemMessage::Unknown => write!(f, "unknown"),
+            MtkSystemMessage::Startup => write!(f, "startup"),
+            MtkSystemMessage::ExtendedPredictionOrbitNeeded => write!(f, "EPO data needed"),
+            MtkSystemMessage::NormalMode => write!(f, "normal mode"),
+        }
+    }
+}
+
+/// PMTK705 - Firmware release information
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct MtkFirmwareRelease {
+    /// Firmware release string (e.g. "AXN_2.31_3339_13101700")
+    pub release: Option<String>,
+
+    /// Build ID
+    pub build_id: Option<String>,
+
+    /// Product model
+    pub model: Option<String>,
+
+    /// SDK version, not reported by all firmwares
+    pub sdk_version: Option<String>,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// PMTK001: Acknowledgement
+pub(crate) fn handle_ack(sentence: &str) -> Result<ParsedMessage, ParseError> {
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    let command = pick_number_field(&split, 1)?
+        .ok_or_else(|| format!("Invalid PMTK001 command field: {}", sentence))?;
+    let flag = pick_number_field(&split, 2)?
+        .ok_or_else(|| format!("Invalid PMTK001 flag field: {}", sentence))?;
+    Ok(ParsedMessage::MtkAck(MtkAck {
+        command,
+        result: MtkAckResult::new(flag)?,
+    }))
+}
+
+/// PMTK010: System message
+pub(crate) fn handle_sys_msg(sentence: &str) -> Result<ParsedMessage, ParseError> {
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    let msg = pick_number_field(&split, 1)?
+        .ok_or_else(|| format!("Invalid PMTK010 message field: {}", sentence))?;
+    Ok(ParsedMessage::MtkSystemMessage(MtkSystemMessage::new(msg)?))
+}
+
+/// PMTK705: Firmware release information
+pub(crate) fn handle_firmware_release(sentence: &str) -> Result<ParsedMessage, ParseError> {
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    Ok(ParsedMessage::MtkFirmwareRelease(MtkFirmwareRelease {
+        release: pick_string_field(&split, 1),
+        build_id: pick_string_field(&split, 2),
+        model: pick_string_field(&split, 3),
+        sdk_version: pick_string_field(&split, 4),
+    }))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_pmtk001() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$PMTK001,604,3*32") {
+            Ok(ps) => match ps {
+                ParsedMessage::MtkAck(ack) => {
+                    assert_eq!(ack.command, 604);
+                    assert_eq!(ack.result, MtkAckResult::Success);
+                    assert!(ack.is_success());
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+        match p.parse_sentence("$PMTK001,220,1*32") {
+            Ok(ps) => match ps {
+                ParsedMessage::MtkAck(ack) => {
+                    assert_eq!(ack.command, 220);
+                    assert_eq!(ack.result, MtkAckResult::Unsupported);
+                    assert!(!ack.is_success());
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_pmtk010() {
+        assert_eq!(
+            NmeaParser::new().parse_sentence("$PMTK010,001*2E"),
+            Ok(ParsedMessage::MtkSystemMessage(MtkSystemMessage::Startup))
+        );
+    }
+
+    #[test]
+    fn test_parse_pmtk705() {
+        match NmeaParser::new().parse_sentence("$PMTK705,AXN_2.31_3339_13101700,5632,PA6H,1.0*6B") {
+            Ok(ps) => match ps {
+                ParsedMessage::MtkFirmwareRelease(fw) => {
+                    assert_eq!(fw.release, Some("AXN_2.31_3339_13101700".into()));
+                    assert_eq!(fw.build_id, Some("5632".into()));
+                    assert_eq!(fw.model, Some("PA6H".into()));
+                    assert_eq!(fw.sdk_version, Some("1.0".into()));
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+}
@@ -0,0 +1,172 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+//! SiRF proprietary PSRF sentences
+
+use super::*;
+use serde::Serialize;
+
+/// PSRF150 - OkToSend
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct SirfOkToSend {
+    /// True when the receiver is ready to receive messages, false when it is about to enter
+    /// power saving mode
+    pub ok_to_send: bool,
+}
+
+/// PSRF151 - Extended ephemeris data request
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct SirfEphemerisRequest {
+    /// True if the GPS week number is valid
+    pub week_valid: bool,
+
+    /// True if the GPS time of week is valid
+    pub time_of_week_valid: bool,
+
+    /// Extended GPS week number
+    pub week: Option<u16>,
+
+    /// GPS time of week in seconds
+    pub time_of_week: Option<f64>,
+
+    /// Bit mask of the satellites whose ephemeris is requested. Bit 0 is PRN 1.
+    pub satellite_mask: Option<u32>,
+}
+
+impl SirfEphemerisRequest {
+    /// Return PRN numbers of the satellites whose ephemeris is requested.
+    pub fn requested_prns(&self) -> Vec<u8> {
+        let mask = self.satellite_mask.unwrap_or(0);
+        (0..32)
+            .filter(|i| mask & (1 << i) != 0)
+            .map(|i| i + 1)
+            .collect()
+    }
+}
+
+/// PSRF161 - Error report
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct SirfErrorReport {
+    /// Error identifier reported by the receiver
+    pub error_id: Option<u16>,
+
+    /// Error specific parameters in the order they were received
+    pub parameters: Vec<String>,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// PSRF150: OkToSend
+pub(crate) fn handle_ok_to_send(sentence: &str) -> Result<ParsedMessage, ParseError> {
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    let ok_to_send = match *split.get(1).unwrap_or(&"") {
+        "1" => true,
+        "0" => false,
+        s => return Err(format!("Invalid PSRF150 OkToSend field: {}", s).into()),
+    };
+    Ok(ParsedMessage::SirfOkToSend(SirfOkToSend { ok_to_send }))
+}
+
+/// PSRF151: Extended ephemeris data request
+pub(crate) fn handle_ephemeris_request(sentence: &str) -> Result<ParsedMessage, ParseError> {
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    let flags: u8 = pick_number_field(&split, 1)?.unwrap_or(0);
+    Ok(ParsedMessage::SirfEphemerisRequest(SirfEphemerisRequest {
+        week_valid: flags & 1 != 0,
+        time_of_week_valid: flags & 2 != 0,
+        week: pick_number_field(&split, 2)?,
+        time_of_week: pick_number_field(&split, 3)?,
+        satellite_mask: match split.get(4).filter(|s| !s.is_empty()) {
+            Some(s) => {
+                let hex = s.trim_start_matches("0x").trim_start_matches("0X");
+                Some(
+                    u32::from_str_radix(hex, 16)
+                        .map_err(|_| format!("Invalid PSRF151 ephemeris mask: {}", s))?,
+                )
+            }
+            None => None,
+        },
+    }))
+}
+
+/// PSRF161: Error report
+pub(crate) fn handle_error_report(sentence: &str) -> Result<ParsedMessage, ParseError> {
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    Ok(ParsedMessage::SirfErrorReport(SirfErrorReport {
+        error_id: pick_number_field(&split, 1)?,
+        parameters: split.iter().skip(2).map(|s| s.to_string()).collect(),
+    }))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_psrf150() {
+        assert_eq!(
+            NmeaParser::new().parse_sentence("$PSRF150,1*3E"),
+            Ok(ParsedMessage::SirfOkToSend(SirfOkToSend {
+                ok_to_send: true
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_psrf151() {
+        match NmeaParser::new().parse_sentence("$PSRF151,3,1400,345600.0,0x00000C00*35") {
+            Ok(ps) => match ps {
+                ParsedMessage::SirfEphemerisRequest(req) => {
+                    assert!(req.week_valid);
+                    assert!(req.time_of_week_valid);
+                    assert_eq!(req.week, Some(1400));
+                    assert_eq!(req.time_of_week, Some(345600.0));
+                    assert_eq!(req.satellite_mask, Some(0xc00));
+                    assert_eq!(req.requested_prns(), vec![11, 12]);
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_psrf161() {
+        match NmeaParser::new().parse_sentence("$PSRF161,2,1,abc*6E") {
+            Ok(ps) => match ps {
+                ParsedMessage::SirfErrorReport(report) => {
+                    assert_eq!(report.error_id, Some(2));
+                    assert_eq!(report.parameters, vec!["1", "abc"]);
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+}
@@ -0,0 +1,335 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+//! u-blox proprietary PUBX sentences
+
+use super::*;
+use serde::Serialize;
+
+/// PUBX,00 - u-blox position data
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct UbxPositionData {
+    /// UTC of position fix
+    #[serde(with = "json_date_time_utc")]
+    pub timestamp: Option<DateTime<Utc>>,
+
+    /// Latitude in degrees
+    pub latitude: Option<f64>,
+
+    /// Longitude in degrees
+    pub longitude: Option<f64>,
+
+    /// Altitude above user datum ellipsoid (metres)
+    pub altitude: Option<f64>,
+
+    /// Navigation status
+    pub nav_status: Option<UbxNavStatus>,
+
+    /// Horizontal accuracy estimate (metres)
+    pub horizontal_accuracy: Option<f64>,
+
+    /// Vertical accuracy estimate (metres)
+    pub vertical_accuracy: Option<f64>,
+
+    /// Speed over ground in knots
+    pub sog_knots: Option<f64>,
+
+    /// Course over ground in degrees (True)
+    pub cog: Option<f64>,
+
+    /// Vertical velocity in metres per second, positive downwards
+    pub vertical_velocity: Option<f64>,
+
+    /// Age of differential corrections in seconds
+    pub age_of_dgps: Option<f64>,
+
+    /// Horizontal dilution of precision
+    pub hdop: Option<f64>,
+
+    /// Vertical dilution of precision
+    pub vdop: Option<f64>,
+
+    /// Time dilution of precision
+    pub tdop: Option<f64>,
+
+    /// Number of satellites used in the navigation solution
+    pub satellite_count: Option<u8>,
+}
+
+impl LatLon for UbxPositionData {
+    fn latitude(&self) -> Option<f64> {
+        self.latitude
+    }
+
+    fn longitude(&self) -> Option<f64> {
+        self.longitude
+    }
+}
+
+/// u-blox navigation status
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub enum UbxNavStatus {
+    /// No fix
+    NoFix,
+
+    /// Dead reckoning only solution
+    DeadReckoning,
+
+    /// Stand alone 2D solution
+    Standalone2D,
+
+    /// Stand alone 3D solution
+    Standalone3D,
+
+    /// Differential 2D solution
+    Differential2D,
+
+    /// Differential 3D solution
+    Differential3D,
+
+    /// Combined GNSS and dead reckoning solution
+    Combined,
+
+    /// Time only solution
+    TimeOnly,
+}
+
+impl UbxNavStatus {
+    pub fn new(val: &str) -> Result<UbxNavStatus, String> {
+        match val {
+            "NF" => Ok(UbxNavStatus::NoFix),
+            "DR" => Ok(UbxNavStatus::DeadReckoning),
+            "G2" => Ok(UbxNavStatus::Standalone2D),
+            "G3" => Ok(UbxNavStatus::Standalone3D),
+            "D2" => Ok(UbxNavStatus::Differential2D),
+            "D3" => Ok(UbxNavStatus::Differential3D),
+            "RK" => Ok(UbxNavStatus::Combined),
+            "TT" => Ok(UbxNavStatus::TimeOnly),
+            _ => Err(format!("Unrecognized PUBX navigation status: {}", val)),
+        }
+    }
+}
+
+impl core::fmt::Display for UbxNavStatus {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            UbxNavStatus::NoFix => write!(f, "no fix"),
+            UbxNavStatus::DeadReckoning => write!(f, "dead reckoning"),
+            UbxNavStatus::Standalone2D => write!(f, "stand alone 2D"),
+            UbxNavStatus::Standalone3D => write!(f, "stand alone 3D"),
+            UbxNavStatus::Differential2D => write!(f, "differential 2D"),
+            UbxNavStatus::Differential3D => write!(f, "differential 3D"),
+            UbxNavStatus::Combined => write!(f, "combined GNSS and dead reckoning"),
+            UbxNavStatus::TimeOnly => write!(f, "time only"),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// PUBX: u-blox proprietary sentences. Satellite status (03) and time of day (04) are returned
+/// as GSV and ZDA results respectively.
+pub(crate) fn handle(sentence: &str) -> Result<ParsedMessage, ParseError> {
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    match *split.get(1).unwrap_or(&"") {
+        "00" => handle_position(&split),
+        "03" => handle_satellites(&split),
+        "04" => handle_time(&split),
+        id => Err(ParseError::UnsupportedMessageType(format!("$PUBX,{}", id))),
+    }
+}
+
+/// PUBX,00: Lat/Long position data
+fn handle_position(split: &[&str]) -> Result<ParsedMessage, ParseError> {
+    let now: DateTime<Utc> = Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).single().unwrap();
+
+    Ok(ParsedMessage::UbxPosition(UbxPositionData {
+        timestamp: parse_hhmmss_ss(split.get(2).unwrap_or(&""), now).ok(),
+        latitude: parse_latitude_ddmm_mmm(
+            split.get(3).unwrap_or(&""),
+            split.get(4).unwrap_or(&""),
+        )?,
+        longitude: parse_longitude_dddmm_mmm(
+            split.get(5).unwrap_or(&""),
+            split.get(6).unwrap_or(&""),
+        )?,
+        altitude: pick_number_field(split, 7)?,
+        nav_status: match pick_string_field(split, 8) {
+            Some(s) => Some(UbxNavStatus::new(&s)?),
+            None => None,
+        },
+        horizontal_accuracy: pick_number_field(split, 9)?,
+        vertical_accuracy: pick_number_field(split, 10)?,
+        sog_knots: pick_number_field::<f64>(split, 11)?.map(|kph| kph / 1.852),
+        cog: pick_number_field(split, 12)?,
+        vertical_velocity: pick_number_field(split, 13)?,
+        age_of_dgps: pick_number_field(split, 14)?,
+        hdop: pick_number_field(split, 15)?,
+        vdop: pick_number_field(split, 16)?,
+        tdop: pick_number_field(split, 17)?,
+        satellite_count: pick_number_field(split, 18)?,
+    }))
+}
+
+/// PUBX,03: Satellite status
+fn handle_satellites(split: &[&str]) -> Result<ParsedMessage, ParseError> {
+    // The count can't exceed the number of satellite blocks actually present
+    let count: usize = pick_number_field(split, 2)?.unwrap_or(0);
+    let count = min(count, split.len().saturating_sub(3) / 6);
+    let mut v = Vec::with_capacity(count);
+    for i in 0..count {
+        let base = 3 + i * 6;
+        if let Some(prn_number) = pick_number_field::<u8>(split, base)? {
+            v.push(gnss::GsvData {
+                source: ubx_nav_system(prn_number),
+                prn_number,
+                elevation: pick_number_field(split, base + 3)?,
+                azimuth: pick_number_field(split, base + 2)?,
+                snr: pick_number_field(split, base + 4)?,
+                signal_id: None,
+            });
+        }
+    }
+    Ok(ParsedMessage::Gsv(v))
+}
+
+/// PUBX,04: Time of day and clock information
+fn handle_time(split: &[&str]) -> Result<ParsedMessage, ParseError> {
+    Ok(ParsedMessage::Zda(gnss::ZdaData {
+        source: gnss::NavigationSystem::Proprietary,
+        timestamp_utc: parse_yymmdd_hhmmss(split.get(3).unwrap_or(&""), "000000")
+            .and_then(|date| parse_hhmmss_ss(split.get(2).unwrap_or(&""), date))
+            .ok(),
+        timezone_local: None,
+    }))
+}
+
+/// Resolve navigation system from u-blox satellite numbering.
+fn ubx_nav_system(svid: u8) -> gnss::NavigationSystem {
+    match svid {
+        1..=32 => gnss::NavigationSystem::Gps,
+        65..=96 => gnss::NavigationSystem::Glonass,
+        193..=202 => gnss::NavigationSystem::Qzss,
+        211..=246 => gnss::NavigationSystem::Galileo,
+        _ => gnss::NavigationSystem::Other,
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_pubx_position() {
+        match NmeaParser::new().parse_sentence(
+            "$PUBX,00,081350.00,4717.113210,N,00833.915187,E,546.589,G3,2.1,2.0,0.007,77.52,0.007,,0.92,1.19,0.77,9,0,0*5F",
+        ) {
+            Ok(ps) => match ps {
+                ParsedMessage::UbxPosition(pos) => {
+                    assert_eq!(
+                        pos.timestamp,
+                        Utc.with_ymd_and_hms(2000, 1, 1, 8, 13, 50).single()
+                    );
+                    assert::close(pos.latitude.unwrap_or(0.0), 47.285, 0.001);
+                    assert::close(pos.longitude.unwrap_or(0.0), 8.565, 0.001);
+                    assert_eq!(pos.altitude, Some(546.589));
+                    assert_eq!(pos.nav_status, Some(UbxNavStatus::Standalone3D));
+                    assert_eq!(pos.horizontal_accuracy, Some(2.1));
+                    assert_eq!(pos.vertical_accuracy, Some(2.0));
+                    assert::close(pos.sog_knots.unwrap_or(0.0), 0.0038, 0.0001);
+                    assert_eq!(pos.cog, Some(77.52));
+                    assert_eq!(pos.vertical_velocity, Some(0.007));
+                    assert_eq!(pos.age_of_dgps, None);
+                    assert_eq!(pos.hdop, Some(0.92));
+                    assert_eq!(pos.vdop, Some(1.19));
+                    assert_eq!(pos.tdop, Some(0.77));
+                    assert_eq!(pos.satellite_count, Some(9));
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_pubx_satellites() {
+        match NmeaParser::new()
+            .parse_sentence("$PUBX,03,3,23,-,,,45,010,08,U,067,31,42,025,66,e,195,33,46,026*02")
+        {
+            Ok(ps) => match ps {
+                ParsedMessage::Gsv(v) => {
+                    assert_eq!(v.len(), 3);
+                    assert_eq!(v[0].prn_number, 23);
+                    assert_eq!(v[0].elevation, None);
+                    assert_eq!(v[0].snr, Some(45.0));
+                    assert_eq!(v[1].source, gnss::NavigationSystem::Gps);
+                    assert_eq!(v[1].azimuth, Some(67.0));
+                    assert_eq!(v[1].elevation, Some(31.0));
+                    assert_eq!(v[1].snr, Some(42.0));
+                    assert_eq!(v[2].source, gnss::NavigationSystem::Glonass);
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+
+        // Satellite count exceeding the data given
+        assert_eq!(
+            NmeaParser::new().parse_sentence("$PUBX,03,99999999999"),
+            Ok(ParsedMessage::Gsv(Vec::new()))
+        );
+    }
+
+    #[test]
+    fn test_parse_pubx_time() {
+        match NmeaParser::new()
+            .parse_sentence("$PUBX,04,073731.00,091202,113851.00,1196,15D,1930035,-2660.664,43,*5D")
+        {
+            Ok(ps) => match ps {
+                ParsedMessage::Zda(zda) => {
+                    assert_eq!(zda.source, gnss::NavigationSystem::Proprietary);
+                    assert_eq!(
+                        zda.timestamp_utc,
+                        Utc.with_ymd_and_hms(2002, 12, 9, 7, 37, 31).single()
+                    );
+                    assert_eq!(zda.timezone_local, None);
+                }
+                _ => {
+                    panic!("Unexpected result: {:?}", ps);
+                }
+            },
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+
+        assert_eq!(
+            NmeaParser::new().parse_sentence("$PUBX,41,1,0007,0003,19200,0*25"),
+            Err(ParseError::UnsupportedMessageType(String::from("$PUBX,41")))
+        );
+    }
+}
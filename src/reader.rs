@@ -0,0 +1,276 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+//! Splitting of byte streams into sentences
+
+use super::*;
+
+/// Maximum length of a line in bytes. Longer lines are considered noise.
+const MAX_LINE_LENGTH: usize = 1024;
+
+/// Splits arbitrary byte chunks, e.g. from a serial port or a socket, into complete sentences
+/// ready for `NmeaParser::parse_sentence`. Noise between the sentences is discarded and the
+/// reader resynchronizes on the next `$` or `!` after a corrupted sentence.
+#[derive(Clone, Debug, Default)]
+pub struct SentenceReader {
+    /// Bytes of the current incomplete line
+    buffer: Vec<u8>,
+
+    /// Complete sentences waiting to be read
+    sentences: VecDeque<String>,
+
+    /// Number of bytes discarded as noise
+    discarded: usize,
+}
+
+impl SentenceReader {
+    /// Create an empty reader
+    pub fn new() -> SentenceReader {
+        SentenceReader::default()
+    }
+
+    /// Push a chunk of bytes to the reader. The chunk may contain any number of sentences and
+    /// partial sentences.
+    pub fn push(&mut self, chunk: &[u8]) {
+        for &b in chunk {
+            match b {
+                b'\r' | b'\n' => self.end_line(),
+                _ => {
+                    if self.buffer.len() >= MAX_LINE_LENGTH {
+                        self.discarded += self.buffer.len();
+                        self.buffer.clear();
+                    }
+                    self.buffer.push(b);
+                }
+            }
+        }
+    }
+
+    /// Return the next complete sentence, or `None` if more bytes are needed
+    pub fn next_sentence(&mut self) -> Option<String> {
+        self.sentences.pop_front()
+    }
+
+    /// Return the number of bytes discarded as noise
+    pub fn discarded_bytes(&self) -> usize {
+        self.discarded
+    }
+
+    /// Take the sentence from the end of the current line, if there is one.
+    fn end_line(&mut self) {
+        let line = core::mem::take(&mut self.buffer);
+        if line.is_empty() {
+            return;
+        }
+
+        // A sentence cut by corruption is followed by the next sentence on the same line, so the
+        // last delimiter starts the sentence. Delimiters can't appear inside sentences.
+        let start = match line.iter().rposition(|b| *b == b'$' || *b == b'!') {
+            Some(idx) => {
+                // Include the TAG block preceding the sentence
+                if idx > 0 && line[idx - 1] == b'\\' {
+                    line[..(idx - 1)]
+                        .iter()
+                        .rposition(|b| *b == b'\\')
+                        .unwrap_or(idx)
+                } else {
+                    idx
+                }
+            }
+            None => {
+                self.discarded += line.len();
+                return;
+            }
+        };
+        self.discarded += start;
+        match String::from_utf8(line[start..].to_vec()) {
+            Ok(sentence) => self.sentences.push_back(sentence),
+            Err(_) => self.discarded += line.len() - start,
+        }
+    }
+}
+
+impl Iterator for SentenceReader {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        self.next_sentence()
+    }
+}
+
+/// Iterator over the messages parsed from the lines of a reader, returned by
+/// `NmeaParser::parse_lines`
+#[cfg(feature = "std")]
+pub struct ParsedLines<'p, R> {
+    parser: &'p mut NmeaParser,
+    reader: R,
+    line: LineBuffer,
+}
+
+#[cfg(feature = "std")]
+impl<'p, R: std::io::BufRead> ParsedLines<'p, R> {
+    pub(crate) fn new(parser: &'p mut NmeaParser, reader: R) -> Self {
+        ParsedLines {
+            parser,
+            reader,
+            line: LineBuffer::default(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'p, R: std::io::BufRead> Iterator for ParsedLines<'p, R> {
+    type Item = Result<ParsedMessage, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let buf = match self.reader.fill_buf() {
+                Ok(buf) => buf,
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(e) => return Some(Err(ParseError::Io(e.to_string()))),
+            };
+
+            // End of input terminates also the last line
+            let complete = if buf.is_empty() {
+                if self.line.is_empty() {
+                    return None;
+                }
+                true
+            } else {
+                let (consumed, complete) = self.line.push(buf);
+                self.reader.consume(consumed);
+                complete
+            };
+            if complete {
+                let line = self.line.take();
+                if let Some(result) = parse_line(self.parser, &line) {
+                    return Some(result);
+                }
+            }
+        }
+    }
+}
+
+/// Line accumulated from the chunks of a reader. Lines longer than `MAX_LINE_LENGTH` are
+/// considered noise and dropped up to the next newline.
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub(crate) struct LineBuffer {
+    line: Vec<u8>,
+    overlong: bool,
+}
+
+#[cfg(feature = "std")]
+impl LineBuffer {
+    /// Append the bytes of the chunk up to the first newline. Returns the number of bytes
+    /// consumed and true if the line was completed by a newline.
+    pub(crate) fn push(&mut self, chunk: &[u8]) -> (usize, bool) {
+        let (bytes, consumed, complete) = match chunk.iter().position(|b| *b == b'\n') {
+            Some(idx) => (&chunk[..idx], idx + 1, true),
+            None => (chunk, chunk.len(), false),
+        };
+        if self.overlong || self.line.len() + bytes.len() > MAX_LINE_LENGTH {
+            self.line.clear();
+            self.overlong = !complete;
+        } else {
+            self.line.extend_from_slice(bytes);
+        }
+        (consumed, complete)
+    }
+
+    /// Return true if no bytes of the current line are buffered
+    pub(crate) fn is_empty(&self) -> bool {
+        self.line.is_empty()
+    }
+
+    /// Take the current line and start a new one
+    pub(crate) fn take(&mut self) -> Vec<u8> {
+        self.overlong = false;
+        core::mem::take(&mut self.line)
+    }
+}
+
+/// Parse a line read from a stream. Returns `None` for empty lines, incomplete multipart
+/// messages and filtered sentences.
+#[cfg(feature = "std")]
+pub(crate) fn parse_line(
+    parser: &mut NmeaParser,
+    line: &[u8],
+) -> Option<Result<ParsedMessage, ParseError>> {
+    let line = line.trim_ascii();
+    if line.is_empty() {
+        return None;
+    }
+    match parser.parse_bytes(line) {
+        Ok(ParsedMessage::Incomplete) | Ok(ParsedMessage::Skipped) => None,
+        result => Some(result),
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_sentence_reader() {
+        let mut r = SentenceReader::new();
+        r.push(b"\xff\x00noise\r\n$SDDPT,2.4,");
+        assert_eq!(r.next_sentence(), None);
+        r.push(b"0.5*54\r\n$GPGGA,1235");
+        r.push(b"$SDDPT,2.5,0.5*55\r\n\\s:2573345,c:1624104769*01\\!AIVDM,1,1,,A,15RTgt0PAso;90TKcjM8h6g208CQ,0*4A\n");
+        assert_eq!(r.next_sentence(), Some("$SDDPT,2.4,0.5*54".into()));
+        assert_eq!(r.next_sentence(), Some("$SDDPT,2.5,0.5*55".into()));
+        assert_eq!(
+            r.next_sentence(),
+            Some(
+                "\\s:2573345,c:1624104769*01\\!AIVDM,1,1,,A,15RTgt0PAso;90TKcjM8h6g208CQ,0*4A"
+                    .into()
+            )
+        );
+        assert_eq!(r.next_sentence(), None);
+        assert_eq!(r.discarded_bytes(), 18);
+
+        let mut p = NmeaParser::new();
+        r.push(b"$SDDPT,2.4,0.5*54\r\n");
+        for sentence in &mut r {
+            assert!(p.parse_sentence(&sentence).is_ok());
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_parse_lines() {
+        let input: &[u8] = b"$SDDPT,2.4,0.5*54\r\n\r\n\
+            !AIVDM,2,1,0,A,55?MbV02;H;s<HtKR20EHE:0@T4@Dn2222222216L961O5Gf0NSQEp6ClRp8,0*1D\r\n\
+            !AIVDM,2,2,0,A,88888888880,2*24\r\n\
+            $SDDPT,2.4,0.5*55\r\n";
+        let mut p = NmeaParser::new();
+        let results: Vec<_> = p.parse_lines(input).collect();
+        assert_eq!(results.len(), 3);
+        assert!(matches!(results[0], Ok(ParsedMessage::Dpt(_))));
+        assert!(matches!(results[1], Ok(ParsedMessage::VesselStaticData(_))));
+        assert!(results[2].is_err());
+
+        // Overlong lines are dropped as noise without buffering them
+        let mut input = vec![b'x'; 100 * MAX_LINE_LENGTH];
+        input.extend_from_slice(b"$SDDPT,2.4,0.5*54\r\n$SDDPT,2.4,0.5*54\r\n");
+        let results: Vec<_> = p.parse_lines(std::io::BufReader::new(&input[..])).collect();
+        assert_eq!(results.len(), 1);
+        assert!(matches!(results[0], Ok(ParsedMessage::Dpt(_))));
+    }
+}
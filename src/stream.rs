@@ -0,0 +1,145 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+//! Asynchronous parsing of `AsyncBufRead` sources
+
+use super::*;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::Stream;
+use tokio::io::AsyncBufRead;
+
+/// Stream of the messages parsed from the lines of an asynchronous reader, e.g. a TCP
+/// connection. Incomplete multipart messages, filtered sentences and empty lines are skipped,
+/// and I/O errors are yielded as `ParseError::Io`.
+pub struct ParsedMessageStream<R> {
+    parser: NmeaParser,
+    reader: R,
+    line: reader::LineBuffer,
+}
+
+impl<R: AsyncBufRead + Unpin> ParsedMessageStream<R> {
+    /// Create a stream parsing the given reader with a default parser
+    pub fn new(reader: R) -> Self {
+        ParsedMessageStream::with_parser(reader, NmeaParser::new())
+    }
+
+    /// Create a stream parsing the given reader with the given parser
+    pub fn with_parser(reader: R, parser: NmeaParser) -> Self {
+        ParsedMessageStream {
+            parser,
+            reader,
+            line: reader::LineBuffer::default(),
+        }
+    }
+
+    /// Return the parser of the stream, e.g. for reading the satellites in view
+    pub fn parser(&self) -> &NmeaParser {
+        &self.parser
+    }
+
+    /// Consume the stream and return the parser and the reader
+    pub fn into_inner(self) -> (NmeaParser, R) {
+        (self.parser, self.reader)
+    }
+}
+
+impl<R: AsyncBufRead + Unpin> Stream for ParsedMessageStream<R> {
+    type Item = Result<ParsedMessage, ParseError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            let buf = match Pin::new(&mut this.reader).poll_fill_buf(cx) {
+                Poll::Ready(Ok(buf)) => buf,
+                Poll::Ready(Err(e)) => {
+                    return Poll::Ready(Some(Err(ParseError::Io(e.to_string()))))
+                }
+                Poll::Pending => return Poll::Pending,
+            };
+
+            // End of input terminates also the last line
+            let complete = if buf.is_empty() {
+                if this.line.is_empty() {
+                    return Poll::Ready(None);
+                }
+                true
+            } else {
+                let (consumed, complete) = this.line.push(buf);
+                Pin::new(&mut this.reader).consume(consumed);
+                complete
+            };
+            if complete {
+                let line = this.line.take();
+                if let Some(result) = reader::parse_line(&mut this.parser, &line) {
+                    return Poll::Ready(Some(result));
+                }
+            }
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::task::Waker;
+    use std::sync::Arc;
+    use std::task::Wake;
+
+    /// Waker doing nothing, as the test readers never return `Poll::Pending`
+    struct NoopWaker;
+
+    impl Wake for NoopWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    #[test]
+    fn test_parsed_message_stream() {
+        let input: &[u8] = b"$SDDPT,2.4,0.5*54\r\n\
+            !AIVDM,2,1,0,A,55?MbV02;H;s<HtKR20EHE:0@T4@Dn2222222216L961O5Gf0NSQEp6ClRp8,0*1D\r\n\
+            !AIVDM,2,2,0,A,88888888880,2*24";
+        let mut stream = ParsedMessageStream::new(input);
+        let waker = Waker::from(Arc::new(NoopWaker));
+        let mut cx = Context::from_waker(&waker);
+        let mut next = || match Pin::new(&mut stream).poll_next(&mut cx) {
+            Poll::Ready(item) => item,
+            Poll::Pending => panic!("Unexpected pending"),
+        };
+        assert!(matches!(next(), Some(Ok(ParsedMessage::Dpt(_)))));
+        assert!(matches!(
+            next(),
+            Some(Ok(ParsedMessage::VesselStaticData(_)))
+        ));
+        assert_eq!(next(), None);
+    }
+
+    #[test]
+    fn test_parsed_message_stream_overlong_line() {
+        let mut input = vec![b'x'; 100 * 1024];
+        input.extend_from_slice(b"\n$SDDPT,2.4,0.5*54\n");
+        let mut stream = ParsedMessageStream::new(&input[..]);
+        let waker = Waker::from(Arc::new(NoopWaker));
+        let mut cx = Context::from_waker(&waker);
+        let mut next = || match Pin::new(&mut stream).poll_next(&mut cx) {
+            Poll::Ready(item) => item,
+            Poll::Pending => panic!("Unexpected pending"),
+        };
+        assert!(matches!(next(), Some(Ok(ParsedMessage::Dpt(_)))));
+        assert_eq!(next(), None);
+    }
+}
@@ -0,0 +1,233 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+//! NMEA 4.0 TAG blocks
+
+use super::*;
+
+/// NMEA 4.0 TAG block preceding a sentence, e.g. `\s:2573345,c:1624104769*01\` added by AIS
+/// networks and data loggers.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TagBlock {
+    /// Source station identifier (s:)
+    pub source: Option<String>,
+
+    /// Destination identifier (d:)
+    pub destination: Option<String>,
+
+    /// UNIX time of reception in seconds (c:)
+    pub unix_time: Option<i64>,
+
+    /// Relative time (r:)
+    pub relative_time: Option<i64>,
+
+    /// Line count (n:)
+    pub line_count: Option<u32>,
+
+    /// Free text (t:)
+    pub text: Option<String>,
+
+    /// Sentence grouping (g:)
+    pub group: Option<TagGroup>,
+}
+
+/// Sentence grouping of a TAG block telling which sentences belong to the same message
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TagGroup {
+    /// Number of the sentence within the group (1-based)
+    pub sentence_number: u8,
+
+    /// Total number of sentences in the group
+    pub sentence_count: u8,
+
+    /// Identifier of the group
+    pub group_id: u32,
+}
+
+impl TagBlock {
+    /// Return the UNIX time as `DateTime`. Millisecond values used by some AIS networks are
+    /// recognized by their magnitude.
+    pub fn timestamp(&self) -> Option<DateTime<Utc>> {
+        let t = self.unix_time?;
+        if t > 100_000_000_000 {
+            Utc.timestamp_millis_opt(t).single()
+        } else {
+            Utc.timestamp_opt(t, 0).single()
+        }
+    }
+
+    /// Parse the TAG block content between the backslashes, including the checksum.
+    fn parse(content: &str, verify_checksum: bool) -> Result<TagBlock, ParseError> {
+        let (fields, checksum_hex_given) = match content.rfind('*') {
+            Some(pos) => (&content[..pos], &content[(pos + 1)..]),
+            None => (content, ""),
+        };
+        if verify_checksum && !checksum_hex_given.is_empty() {
+            let checksum = fields.bytes().fold(0u8, |acc, b| acc ^ b);
+            let checksum_hex_calculated = format!("{:02X?}", checksum);
+            if !checksum_hex_calculated.eq_ignore_ascii_case(checksum_hex_given) {
+                return Err(ParseError::ChecksumMismatch {
+                    calculated: checksum_hex_calculated,
+                    given: checksum_hex_given.into(),
+                });
+            }
+        }
+
+        let mut tag_block = TagBlock::default();
+        for field in fields.split(',').filter(|f| !f.is_empty()) {
+            let (key, value) = field
+                .split_once(':')
+                .ok_or_else(|| ParseError::InvalidField {
+                    name: "TAG block field".into(),
+                    value: field.into(),
+                })?;
+            let invalid = || ParseError::InvalidField {
+                name: format!("TAG block field {}", key),
+                value: value.into(),
+            };
+            match key {
+                "s" => tag_block.source = Some(value.into()),
+                "d" => tag_block.destination = Some(value.into()),
+                "c" => tag_block.unix_time = Some(value.parse().map_err(|_| invalid())?),
+                "r" => tag_block.relative_time = Some(value.parse().map_err(|_| invalid())?),
+                "n" => tag_block.line_count = Some(value.parse().map_err(|_| invalid())?),
+                "t" => tag_block.text = Some(value.into()),
+                "g" => {
+                    let mut parts = value.split('-');
+                    match (
+                        parts.next().map(|p| p.parse::<u8>()),
+                        parts.next().map(|p| p.parse::<u8>()),
+                        parts.next().map(|p| p.parse::<u32>()),
+                    ) {
+                        (Some(Ok(number)), Some(Ok(count)), Some(Ok(group_id))) => {
+                            tag_block.group = Some(TagGroup {
+                                sentence_number: number,
+                                sentence_count: count,
+                                group_id,
+                            });
+                        }
+                        _ => return Err(invalid()),
+                    }
+                }
+                _ => {
+                    debug!("Unknown TAG block field: {}", field);
+                }
+            }
+        }
+        Ok(tag_block)
+    }
+}
+
+/// Split the TAG block from the beginning of the sentence, if there is one. Returns the TAG
+/// block and the rest of the sentence.
+pub(crate) fn split_tag_block(
+    sentence: &str,
+    verify_checksum: bool,
+) -> Result<(Option<TagBlock>, &str), ParseError> {
+    let trimmed = sentence.trim_start();
+    if let Some(rest) = trimmed.strip_prefix('\\') {
+        if let Some(end) = rest.find('\\') {
+            let tag_block = TagBlock::parse(&rest[..end], verify_checksum)?;
+            return Ok((Some(tag_block), &rest[(end + 1)..]));
+        }
+    }
+    Ok((None, sentence))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_tag_block() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence_with_tag_block(
+            "\\s:2573345,c:1624104769*01\\!AIVDM,1,1,,A,15RTgt0PAso;90TKcjM8h6g208CQ,0*4A",
+        ) {
+            Ok((tag_block, ps)) => {
+                let tag_block = tag_block.unwrap_or_default();
+                assert_eq!(tag_block.source, Some("2573345".into()));
+                assert_eq!(tag_block.unix_time, Some(1624104769));
+                assert_eq!(
+                    tag_block.timestamp(),
+                    Utc.with_ymd_and_hms(2021, 6, 19, 12, 12, 49).single()
+                );
+                assert_eq!(tag_block.group, None);
+                match ps {
+                    ParsedMessage::VesselDynamicData(vdd) => {
+                        assert_eq!(vdd.mmsi, 371798000);
+                    }
+                    _ => {
+                        panic!("Unexpected result: {:?}", ps);
+                    }
+                }
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+
+        // Corrupted TAG block
+        assert!(p
+            .parse_sentence(
+                "\\s:2573345,c:1624104769*02\\!AIVDM,1,1,,A,15RTgt0PAso;90TKcjM8h6g208CQ,0*4A"
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn test_tag_block_group() {
+        // Multi-sentence message without sequential message ID is grouped by the TAG blocks
+        let mut p = NmeaParser::new();
+        assert_eq!(
+            p.parse_sentence(
+                "\\g:1-2-4711,s:r003669945,c:1624104769*01\\!AIVDM,2,1,,A,55?MbV02;H;s<HtKR20EHE:0@T4@Dn2222222216L961O5Gf0NSQEp6ClRp8,0*2D"
+            ),
+            Ok(ParsedMessage::Incomplete)
+        );
+        match p.parse_sentence_with_tag_block("\\g:2-2-4711*5E\\!AIVDM,2,2,,A,88888888880,2*14") {
+            Ok((tag_block, ps)) => {
+                assert_eq!(
+                    tag_block.and_then(|t| t.group),
+                    Some(TagGroup {
+                        sentence_number: 2,
+                        sentence_count: 2,
+                        group_id: 4711
+                    })
+                );
+                match ps {
+                    ParsedMessage::VesselStaticData(vsd) => {
+                        assert_eq!(vsd.mmsi, 351759000);
+                        assert_eq!(vsd.destination, Some("NEW YORK".into()));
+                    }
+                    _ => {
+                        panic!("Unexpected result: {:?}", ps);
+                    }
+                }
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "OK");
+            }
+        }
+
+        // Sentence number out of range
+        assert!(p
+            .parse_sentence("\\g:257-2-4711*5C\\!AIVDM,2,2,,A,88888888880,2*14")
+            .is_err());
+    }
+}
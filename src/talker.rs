@@ -0,0 +1,389 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+//! Talker identifiers
+
+use super::*;
+use serde::Serialize;
+
+/// Talker identified by the two characters following the `$` or `!` delimiter (e.g. "II" in
+/// `$IIMWV`). Proprietary sentences (`$P...`) have no talker ID.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize)]
+pub enum Talker {
+    /// AG: Autopilot, general
+    AutopilotGeneral,
+
+    /// AP: Autopilot, magnetic
+    AutopilotMagnetic,
+
+    /// AB: AIS base station
+    AisBaseStation,
+
+    /// AD: AIS dependent base station
+    AisDependentBaseStation,
+
+    /// AI: AIS mobile station
+    AisMobileStation,
+
+    /// AN: AIS aid to navigation station
+    AisAidToNavigation,
+
+    /// AR: AIS receiving station
+    AisReceivingStation,
+
+    /// AS: AIS limited base station
+    AisLimitedBaseStation,
+
+    /// AT: AIS transmitting station
+    AisTransmittingStation,
+
+    /// AX: AIS repeater station
+    AisRepeaterStation,
+
+    /// BD, GB: BeiDou
+    Beidou,
+
+    /// BN: Bridge navigational watch alarm system
+    BridgeWatchAlarm,
+
+    /// CD: Digital selective calling (DSC)
+    DigitalSelectiveCalling,
+
+    /// CR: Data receiver
+    DataReceiver,
+
+    /// CS: Satellite communications
+    SatelliteCommunications,
+
+    /// CT: Radio-telephone (MF/HF)
+    RadioTelephoneMfHf,
+
+    /// CV: Radio-telephone (VHF)
+    RadioTelephoneVhf,
+
+    /// CX: Scanning receiver
+    ScanningReceiver,
+
+    /// DF: Direction finder
+    DirectionFinder,
+
+    /// EC: Electronic chart display and information system (ECDIS)
+    Ecdis,
+
+    /// EI: Electronic chart system (ECS)
+    ElectronicChartSystem,
+
+    /// EP: Emergency position indicating radio beacon (EPIRB)
+    Epirb,
+
+    /// ER: Engine room monitoring systems
+    EngineRoom,
+
+    /// GA: Galileo
+    Galileo,
+
+    /// GI: NavIC
+    Navic,
+
+    /// GL: GLONASS
+    Glonass,
+
+    /// GN: Combination of several satellite systems
+    GnssCombination,
+
+    /// GP: GPS
+    Gps,
+
+    /// HC: Heading sensor, compass, magnetic
+    MagneticCompass,
+
+    /// HE: Heading sensor, gyro, north seeking
+    GyroNorthSeeking,
+
+    /// HN: Heading sensor, gyro, non-north seeking
+    GyroNonNorthSeeking,
+
+    /// II: Integrated instrumentation
+    IntegratedInstrumentation,
+
+    /// IN: Integrated navigation
+    IntegratedNavigation,
+
+    /// QZ, GQ: QZSS
+    Qzss,
+
+    /// RA: Radar and/or radar plotting
+    Radar,
+
+    /// SD: Sounder, depth
+    DepthSounder,
+
+    /// SN: Electronic positioning system, other/general
+    ElectronicPositioning,
+
+    /// SS: Sounder, scanning
+    ScanningSounder,
+
+    /// TI: Turn rate indicator
+    TurnRateIndicator,
+
+    /// VD: Velocity sensor, Doppler
+    VelocityDoppler,
+
+    /// VM: Velocity sensor, speed log, water, magnetic
+    SpeedLogMagnetic,
+
+    /// VW: Velocity sensor, speed log, water, mechanical
+    SpeedLogMechanical,
+
+    /// VR: Voyage data recorder
+    VoyageDataRecorder,
+
+    /// WI: Weather instruments
+    WeatherInstruments,
+
+    /// YX: Transducer
+    Transducer,
+
+    /// ZA: Timekeeper, atomic clock
+    AtomicClock,
+
+    /// ZC: Timekeeper, chronometer
+    Chronometer,
+
+    /// ZQ: Timekeeper, quartz
+    QuartzClock,
+
+    /// ZV: Timekeeper, radio update
+    RadioUpdateClock,
+
+    /// P: Proprietary sentence
+    Proprietary,
+
+    /// Some other talker
+    Other,
+}
+
+impl Talker {
+    /// Return true if the talker is a satellite navigation receiver
+    pub fn is_gnss(&self) -> bool {
+        matches!(
+            self,
+            Talker::Beidou
+                | Talker::Galileo
+                | Talker::Navic
+                | Talker::Glonass
+                | Talker::GnssCombination
+                | Talker::Gps
+                | Talker::Qzss
+        )
+    }
+
+    /// Return true if the talker is an AIS station
+    pub fn is_ais(&self) -> bool {
+        matches!(
+            self,
+            Talker::AisBaseStation
+                | Talker::AisDependentBaseStation
+                | Talker::AisMobileStation
+                | Talker::AisAidToNavigation
+                | Talker::AisReceivingStation
+                | Talker::AisLimitedBaseStation
+                | Talker::AisTransmittingStation
+                | Talker::AisRepeaterStation
+        )
+    }
+
+    /// Identify the talker of the given sentence. Leading characters before the `$` or `!`
+    /// delimiter are ignored.
+    pub fn from_sentence(sentence: &str) -> Result<Talker, ParseError> {
+        let start = sentence.find(['$', '!']).ok_or_else(|| {
+            ParseError::InvalidSentence(format!("Invalid NMEA sentence: {}", sentence))
+        })?;
+        Talker::from_str(sentence.get(start + 1..).unwrap_or(""))
+    }
+}
+
+impl core::fmt::Display for Talker {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Talker::AutopilotGeneral => write!(f, "autopilot"),
+            Talker::AutopilotMagnetic => write!(f, "autopilot, magnetic"),
+            Talker::AisBaseStation => write!(f, "AIS base station"),
+            Talker::AisDependentBaseStation => write!(f, "AIS dependent base station"),
+            Talker::AisMobileStation => write!(f, "AIS mobile station"),
+            Talker::AisAidToNavigation => write!(f, "AIS aid to navigation station"),
+            Talker::AisReceivingStation => write!(f, "AIS receiving station"),
+            Talker::AisLimitedBaseStation => write!(f, "AIS limited base station"),
+            Talker::AisTransmittingStation => write!(f, "AIS transmitting station"),
+            Talker::AisRepeaterStation => write!(f, "AIS repeater station"),
+            Talker::Beidou => write!(f, "BeiDou"),
+            Talker::BridgeWatchAlarm => write!(f, "bridge navigational watch alarm system"),
+            Talker::DigitalSelectiveCalling => write!(f, "digital selective calling"),
+            Talker::DataReceiver => write!(f, "data receiver"),
+            Talker::SatelliteCommunications => write!(f, "satellite communications"),
+            Talker::RadioTelephoneMfHf => write!(f, "radio-telephone (MF/HF)"),
+            Talker::RadioTelephoneVhf => write!(f, "radio-telephone (VHF)"),
+            Talker::ScanningReceiver => write!(f, "scanning receiver"),
+            Talker::DirectionFinder => write!(f, "direction finder"),
+            Talker::Ecdis => write!(f, "ECDIS"),
+            Talker::ElectronicChartSystem => write!(f, "electronic chart system"),
+            Talker::Epirb => write!(f, "EPIRB"),
+            Talker::EngineRoom => write!(f, "engine room monitoring"),
+            Talker::Galileo => write!(f, "Galileo"),
+            Talker::Navic => write!(f, "NavIC"),
+            Talker::Glonass => write!(f, "GLONASS"),
+            Talker::GnssCombination => write!(f, "GNSS combination"),
+            Talker::Gps => write!(f, "GPS"),
+            Talker::Qzss => write!(f, "QZSS"),
+            Talker::MagneticCompass => write!(f, "magnetic compass"),
+            Talker::GyroNorthSeeking => write!(f, "gyro, north seeking"),
+            Talker::GyroNonNorthSeeking => write!(f, "gyro, non-north seeking"),
+            Talker::IntegratedInstrumentation => write!(f, "integrated instrumentation"),
+            Talker::IntegratedNavigation => write!(f, "integrated navigation"),
+            Talker::Radar => write!(f, "radar"),
+            Talker::DepthSounder => write!(f, "depth sounder"),
+            Talker::ElectronicPositioning => write!(f, "electronic positioning system"),
+            Talker::ScanningSounder => write!(f, "scanning sounder"),
+            Talker::TurnRateIndicator => write!(f, "turn rate indicator"),
+            Talker::VelocityDoppler => write!(f, "Doppler velocity sensor"),
+            Talker::SpeedLogMagnetic => write!(f, "speed log, magnetic"),
+            Talker::SpeedLogMechanical => write!(f, "speed log, mechanical"),
+            Talker::VoyageDataRecorder => write!(f, "voyage data recorder"),
+            Talker::WeatherInstruments => write!(f, "weather instruments"),
+            Talker::Transducer => write!(f, "transducer"),
+            Talker::AtomicClock => write!(f, "atomic clock"),
+            Talker::Chronometer => write!(f, "chronometer"),
+            Talker::QuartzClock => write!(f, "quartz clock"),
+            Talker::RadioUpdateClock => write!(f, "radio update clock"),
+            Talker::Proprietary => write!(f, "proprietary"),
+            Talker::Other => write!(f, "other"),
+        }
+    }
+}
+
+impl core::str::FromStr for Talker {
+    type Err = ParseError;
+
+    fn from_str(talker_id: &str) -> Result<Self, Self::Err> {
+        if talker_id.starts_with('P') {
+            return Ok(Self::Proprietary);
+        }
+        match talker_id.get(0..2) {
+            Some("AG") => Ok(Self::AutopilotGeneral),
+            Some("AP") => Ok(Self::AutopilotMagnetic),
+            Some("AB") => Ok(Self::AisBaseStation),
+            Some("AD") => Ok(Self::AisDependentBaseStation),
+            Some("AI") => Ok(Self::AisMobileStation),
+            Some("AN") => Ok(Self::AisAidToNavigation),
+            Some("AR") => Ok(Self::AisReceivingStation),
+            Some("AS") => Ok(Self::AisLimitedBaseStation),
+            Some("AT") => Ok(Self::AisTransmittingStation),
+            Some("AX") => Ok(Self::AisRepeaterStation),
+            Some("BD") => Ok(Self::Beidou),
+            Some("BN") => Ok(Self::BridgeWatchAlarm),
+            Some("CD") => Ok(Self::DigitalSelectiveCalling),
+            Some("CR") => Ok(Self::DataReceiver),
+            Some("CS") => Ok(Self::SatelliteCommunications),
+            Some("CT") => Ok(Self::RadioTelephoneMfHf),
+            Some("CV") => Ok(Self::RadioTelephoneVhf),
+            Some("CX") => Ok(Self::ScanningReceiver),
+            Some("DF") => Ok(Self::DirectionFinder),
+            Some("EC") => Ok(Self::Ecdis),
+            Some("EI") => Ok(Self::ElectronicChartSystem),
+            Some("EP") => Ok(Self::Epirb),
+            Some("ER") => Ok(Self::EngineRoom),
+            Some("GA") => Ok(Self::Galileo),
+            Some("GB") => Ok(Self::Beidou),
+            Some("GI") => Ok(Self::Navic),
+            Some("GL") => Ok(Self::Glonass),
+            Some("GN") => Ok(Self::GnssCombination),
+            Some("GP") => Ok(Self::Gps),
+            Some("GQ") => Ok(Self::Qzss),
+            Some("HC") => Ok(Self::MagneticCompass),
+            Some("HE") => Ok(Self::GyroNorthSeeking),
+            Some("HN") => Ok(Self::GyroNonNorthSeeking),
+            Some("II") => Ok(Self::IntegratedInstrumentation),
+            Some("IN") => Ok(Self::IntegratedNavigation),
+            Some("QZ") => Ok(Self::Qzss),
+            Some("RA") => Ok(Self::Radar),
+            Some("SD") => Ok(Self::DepthSounder),
+            Some("SN") => Ok(Self::ElectronicPositioning),
+            Some("SS") => Ok(Self::ScanningSounder),
+            Some("TI") => Ok(Self::TurnRateIndicator),
+            Some("VD") => Ok(Self::VelocityDoppler),
+            Some("VM") => Ok(Self::SpeedLogMagnetic),
+            Some("VW") => Ok(Self::SpeedLogMechanical),
+            Some("VR") => Ok(Self::VoyageDataRecorder),
+            Some("WI") => Ok(Self::WeatherInstruments),
+            Some("YX") => Ok(Self::Transducer),
+            Some("ZA") => Ok(Self::AtomicClock),
+            Some("ZC") => Ok(Self::Chronometer),
+            Some("ZQ") => Ok(Self::QuartzClock),
+            Some("ZV") => Ok(Self::RadioUpdateClock),
+            Some(_) => Ok(Self::Other),
+            None => Err(ParseError::InvalidSentence(
+                "Invalid talker identifier".to_string(),
+            )),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_talker() {
+        assert_eq!(
+            Talker::from_str("IIMWV"),
+            Ok(Talker::IntegratedInstrumentation)
+        );
+        assert_eq!(Talker::from_str("HE"), Ok(Talker::GyroNorthSeeking));
+        assert_eq!(Talker::from_str("PGRME"), Ok(Talker::Proprietary));
+        assert_eq!(Talker::from_str("GBGSV"), Ok(Talker::Beidou));
+        assert_eq!(Talker::from_str("GQGSV"), Ok(Talker::Qzss));
+        assert_eq!(Talker::from_str("XX"), Ok(Talker::Other));
+        assert!(Talker::from_str("X").is_err());
+        assert_eq!(
+            Talker::from_sentence("\\s:2573535,c:1671620143*0B\\!AIVDM,1,1,,A,,0*00"),
+            Ok(Talker::AisMobileStation)
+        );
+        assert!(Talker::Gps.is_gnss());
+        assert!(!Talker::Gps.is_ais());
+        assert!(Talker::AisBaseStation.is_ais());
+        assert_eq!(
+            Talker::WeatherInstruments.to_string(),
+            "weather instruments"
+        );
+    }
+
+    #[test]
+    fn test_parse_sentence_with_talker() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence_with_talker("$SDDPT,4.1,0.0*52") {
+            Ok((talker, ParsedMessage::Dpt(dpt))) => {
+                assert_eq!(talker, Talker::DepthSounder);
+                assert_eq!(dpt.depth_relative_to_transducer, Some(4.1));
+            }
+            ps => {
+                panic!("Unexpected result: {:?}", ps);
+            }
+        }
+    }
+}
@@ -17,7 +17,7 @@ use super::*;
 
 use chrono::Duration;
 
-const AIS_CHAR_BITS: usize = 6;
+pub(crate) const AIS_CHAR_BITS: usize = 6;
 
 /// Make a key for storing NMEA sentence fragments
 pub(crate) fn make_fragment_key(
@@ -37,7 +37,12 @@ pub(crate) fn make_fragment_key(
 pub(crate) fn parse_payload(payload: &str) -> Result<BitVec, String> {
     let mut bv = BitVec::<usize, LocalBits>::with_capacity(payload.len() * 6);
     for c in payload.chars() {
-        let mut ci = (c as u8) - 48;
+        let mut ci = match c {
+            '0'..='W' | '`'..='w' => (c as u8) - 48,
+            _ => {
+                return Err(format!("Invalid character in AIS payload: {}", c));
+            }
+        };
         if ci > 40 {
             ci -= 8;
         }
@@ -51,6 +56,32 @@ pub(crate) fn parse_payload(payload: &str) -> Result<BitVec, String> {
     Ok(bv)
 }
 
+/// Split gateway or router prefix (e.g. "UdPbC\0") from the beginning of a sentence. The
+/// sentence starts at the first TAG block or `$`/`!` delimiter followed by a sentence
+/// identifier. Returns the prefix and the sentence.
+pub(crate) fn split_prefix(line: &str) -> (&str, &str) {
+    let bytes = line.as_bytes();
+    let is_start = |i: usize| match bytes[i] {
+        b'\\' => {
+            matches!(bytes.get(i + 1), Some(b) if b.is_ascii_lowercase())
+                && bytes.get(i + 2) == Some(&b':')
+        }
+        b'$' | b'!' => {
+            let n = bytes[(i + 1)..]
+                .iter()
+                .take_while(|b| b.is_ascii_alphanumeric())
+                .count();
+            n >= 2 && matches!(bytes.get(i + 1 + n), Some(b',') | Some(b'*'))
+        }
+        _ => false,
+    };
+    let start = (0..bytes.len())
+        .find(|i| is_start(*i))
+        .or_else(|| line.find(['$', '!']))
+        .unwrap_or(0);
+    line.split_at(start)
+}
+
 /// Pick a numberic field from `BitVec`.
 pub(crate) fn pick_u64(bv: &BitVec, index: usize, len: usize) -> u64 {
     let mut res = 0;
@@ -83,10 +114,30 @@ pub(crate) fn pick_i64(bv: &BitVec, index: usize, len: usize) -> i64 {
     }
 }
 
+/// Pack `BitVec` into bytes, most significant bit first. The last byte is padded with zeros.
+pub(crate) fn pack_bytes(bv: &BitVec) -> Vec<u8> {
+    (0..bv.len().div_ceil(8))
+        .map(|i| pick_u64(bv, i * 8, 8) as u8)
+        .collect()
+}
+
 /// Pick a string from BitVec. Field `char_count` defines string length in characters.
 /// Characters consist of 6 bits.
 pub(crate) fn pick_string(bv: &BitVec, index: usize, char_count: usize) -> String {
     let mut res = String::with_capacity(char_count);
+    pick_string_into(bv, index, char_count, &mut res);
+    res
+}
+
+/// Pick a string from BitVec like `pick_string` but append it to the given buffer. Returns the
+/// byte range of the string within the buffer.
+pub(crate) fn pick_string_into(
+    bv: &BitVec,
+    index: usize,
+    char_count: usize,
+    res: &mut String,
+) -> core::ops::Range<usize> {
+    let start = res.len();
     for i in 0..char_count {
         // unwraps below won't panic as char_from::u32 will only ever receive values between
         // 32..=96, all of which are valid. Catch all branch is unreachable as we only request
@@ -100,8 +151,8 @@ pub(crate) fn pick_string(bv: &BitVec, index: usize, char_count: usize) -> Strin
     }
 
     let trimmed_len = res.trim_end().len();
-    res.truncate(trimmed_len);
-    res
+    res.truncate(trimmed_len.max(start));
+    start..res.len()
 }
 
 /// Pick ETA based on UTC month, day, hour and minute.
@@ -121,11 +172,22 @@ fn pick_eta_with_now(
     now: DateTime<Utc>,
 ) -> Result<Option<DateTime<Utc>>, ParseError> {
     // Pick ETA
-    let mut month = pick_u64(bv, index, 4) as u32;
-    let mut day = pick_u64(bv, index + 4, 5) as u32;
-    let mut hour = pick_u64(bv, index + 4 + 5, 5) as u32;
-    let mut minute = pick_u64(bv, index + 4 + 5 + 5, 6) as u32;
+    let month = pick_u64(bv, index, 4) as u32;
+    let day = pick_u64(bv, index + 4, 5) as u32;
+    let hour = pick_u64(bv, index + 4 + 5, 5) as u32;
+    let minute = pick_u64(bv, index + 4 + 5 + 5, 6) as u32;
+    resolve_eta(month, day, hour, minute, now)
+}
 
+/// Resolve ETA month, day, hour and minute into a date time relative to 'now'. The ETA is
+/// assumed to be in the current year unless it would be more than 180 days in the past.
+pub(crate) fn resolve_eta(
+    mut month: u32,
+    mut day: u32,
+    mut hour: u32,
+    mut minute: u32,
+    now: DateTime<Utc>,
+) -> Result<Option<DateTime<Utc>>, ParseError> {
     // Check special case for no value
     if month == 0 && day == 0 && hour == 24 && minute == 60 {
         return Ok(None);
@@ -180,13 +242,15 @@ fn pick_eta_with_now(
 pub(crate) fn pick_number_field<T: core::str::FromStr>(
     split: &[&str],
     num: usize,
-) -> Result<Option<T>, String> {
+) -> Result<Option<T>, ParseError> {
     split
         .get(num)
         .filter(|s| !s.is_empty())
         .map(|s| {
-            s.parse()
-                .map_err(|_| format!("Failed to parse field {}: {}", num, s))
+            s.parse().map_err(|_| ParseError::InvalidField {
+                name: format!("field {}", num),
+                value: s.to_string(),
+            })
         })
         .transpose()
 }
@@ -195,13 +259,15 @@ pub(crate) fn pick_number_field<T: core::str::FromStr>(
 pub(crate) fn pick_hex_field<T: num_traits::Num>(
     split: &[&str],
     num: usize,
-) -> Result<Option<T>, String> {
+) -> Result<Option<T>, ParseError> {
     split
         .get(num)
         .filter(|s| !s.is_empty())
         .map(|s| {
-            T::from_str_radix(s, 16)
-                .map_err(|_| format!("Failed to parse hex field {}: {}", num, s))
+            T::from_str_radix(s, 16).map_err(|_| ParseError::InvalidField {
+                name: format!("hex field {}", num),
+                value: s.to_string(),
+            })
         })
         .transpose()
 }
@@ -252,6 +318,17 @@ pub(crate) fn parse_hhmmss_ss(
     )
 }
 
+/// Parse a time span of format HHMMSS.SS and return it in seconds. Hour part isn't limited to
+/// 23 because the field is used for durations such as time-to-go.
+pub(crate) fn parse_hhmmss_ss_duration(hhmmss: &str) -> Result<f64, ParseError> {
+    let (hour, minute, second, nano) = parse_time_with_fractions(hhmmss)
+        .map_err(|_| format!("Invalid time span format: {}", hhmmss))?;
+    if minute > 59 || second > 59 {
+        return Err(format!("Invalid time span: {}", hhmmss).into());
+    }
+    Ok((hour * 3600 + minute * 60 + second) as f64 + nano as f64 / 1000000000.0)
+}
+
 /// Pick date by picking the given field numbers. Set time part to midnight.
 pub(crate) fn pick_date_with_fields(
     split: &[&str],
@@ -275,10 +352,13 @@ pub(crate) fn pick_timezone_with_fields(
     hour_field: usize,
     minute_field: usize,
 ) -> Result<FixedOffset, ParseError> {
-    let hour = split.get(hour_field).unwrap_or(&"").parse::<i32>()?;
+    let hour_str = split.get(hour_field).unwrap_or(&"");
+    let hour = hour_str.parse::<i32>()?;
     let minute = split.get(minute_field).unwrap_or(&"0").parse::<i32>()?;
+    // Minutes take the sign of the hours, also when the hours are "-00"
+    let sign = if hour_str.starts_with('-') { -1 } else { 1 };
 
-    if let Some(offset) = FixedOffset::east_opt(hour * 3600 + hour.signum() * minute * 60) {
+    if let Some(offset) = FixedOffset::east_opt(hour * 3600 + sign * minute * 60) {
         Ok(offset)
     } else {
         Err(ParseError::InvalidSentence(format!(
@@ -342,6 +422,13 @@ pub fn parse_valid_utc(
     sec: u32,
     nano: u32,
 ) -> Result<DateTime<Utc>, ParseError> {
+    // A leap second (23:59:60) is represented by chrono as second 59 with over a second of
+    // nanoseconds. Fractions of a second or more are left for chrono to reject.
+    let (sec, nano) = if sec == 60 && nano < 1_000_000_000 {
+        (59, nano + 1_000_000_000)
+    } else {
+        (sec, nano)
+    };
     let opt_utc = Utc
         .ymd_opt(year, month, day)
         .and_hms_nano_opt(hour, min, sec, nano);
@@ -791,6 +878,14 @@ mod test {
         assert_eq!(parse_hhmmss_ss("123456@", then).ok(), None);
     }
 
+    #[test]
+    fn test_parse_hhmmss_ss_duration() {
+        assert_eq!(parse_hhmmss_ss_duration("042359.17").ok(), Some(15839.17));
+        assert_eq!(parse_hhmmss_ss_duration("990000").ok(), Some(356400.0));
+        assert!(parse_hhmmss_ss_duration("006000").is_err());
+        assert!(parse_hhmmss_ss_duration("").is_err());
+    }
+
     #[test]
     fn test_pick_date_with_fields() {
         let s: Vec<&str> = "$GPZDA,072914.00,31,05,2018,+02,00".split(',').collect();
@@ -0,0 +1,158 @@
+/*
+Copyright 2024 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+//! Validation of sentences without parsing them into messages
+
+use super::*;
+
+/// Minimum number of data fields of the common sentence types, the address field excluded
+const MIN_FIELD_COUNTS: [(&str, usize); 17] = [
+    ("DBS", 6),
+    ("DPT", 2),
+    ("GGA", 14),
+    ("GLL", 6),
+    ("GNS", 12),
+    ("GSA", 17),
+    ("GST", 8),
+    ("GSV", 3),
+    ("HDT", 2),
+    ("MTW", 2),
+    ("MWV", 5),
+    ("RMC", 11),
+    ("VDM", 6),
+    ("VDO", 6),
+    ("VHW", 8),
+    ("VTG", 8),
+    ("ZDA", 6),
+];
+
+/// Check the given sentence without parsing it into a message. The framing, the checksum (if
+/// present) and the field count of the common sentence types are checked. The payload length of
+/// single-sentence AIS messages is checked against the message type. Returns the reason of the
+/// first failed check.
+pub fn validate_sentence(sentence: &str) -> Result<(), ParseError> {
+    let kind = peek_sentence_kind(sentence)?;
+    let sentence = peek::strip_framing(sentence).trim_end();
+
+    // Checksum
+    let body = match sentence.rfind('*') {
+        Some(pos) => {
+            let checksum_hex_given = sentence.get((pos + 1)..).unwrap_or_default();
+            let checksum = sentence[1..pos].bytes().fold(0u8, |acc, b| acc ^ b);
+            let checksum_hex_calculated = format!("{:02X?}", checksum);
+            if !checksum_hex_calculated.eq_ignore_ascii_case(checksum_hex_given) {
+                return Err(ParseError::ChecksumMismatch {
+                    calculated: checksum_hex_calculated,
+                    given: checksum_hex_given.into(),
+                });
+            }
+            &sentence[..pos]
+        }
+        None => sentence,
+    };
+
+    // Field count
+    let field_count = body.split(',').count() - 1;
+    if let Some((_, min_count)) = MIN_FIELD_COUNTS
+        .iter()
+        .find(|(sentence_type, _)| *sentence_type == kind.sentence_type)
+    {
+        if field_count < *min_count {
+            return Err(ParseError::CorruptedSentence(format!(
+                "{} has {} fields, at least {} expected",
+                kind.sentence_type, field_count, min_count
+            )));
+        }
+    }
+
+    // AIS payload
+    if kind.sentence_type == "VDM" || kind.sentence_type == "VDO" {
+        let mut fields = body.split(',').skip(1);
+        let fragment_count = fields.next().unwrap_or_default();
+        let payload = fields.nth(3).unwrap_or_default();
+        let fill_bits = fields.next().unwrap_or_default();
+        if let Some(c) = payload
+            .chars()
+            .find(|c| !matches!(c, '0'..='W' | '`'..='w'))
+        {
+            return Err(ParseError::InvalidSentence(format!(
+                "Invalid character in AIS payload: {}",
+                c
+            )));
+        }
+        let fill_bits = fill_bits
+            .parse::<usize>()
+            .ok()
+            .filter(|f| *f <= 5)
+            .ok_or_else(|| ParseError::InvalidField {
+                name: "fill bits".into(),
+                value: fill_bits.into(),
+            })?;
+        if fragment_count == "1" {
+            if let Some(message_type) = kind.ais_message_type {
+                let len = (payload.len() * 6).saturating_sub(fill_bits);
+                ais::check_payload_length(message_type as u64, len)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_validate_sentence() {
+        assert_eq!(
+            validate_sentence("!AIVDM,1,1,,A,15RTgt0PAso;90TKcjM8h6g208CQ,0*4A\r\n"),
+            Ok(())
+        );
+        assert_eq!(
+            validate_sentence("$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47"),
+            Ok(())
+        );
+        assert_eq!(
+            validate_sentence("!AIVDM,1,1,,A,15RTgt0PAso;90TKcjM8h6g208CQ,0*4B"),
+            Err(ParseError::ChecksumMismatch {
+                calculated: "4A".into(),
+                given: "4B".into()
+            })
+        );
+        assert_eq!(
+            validate_sentence("$GPGGA,123519,4807.038,N,01131.000,E,1,08"),
+            Err(ParseError::CorruptedSentence(
+                "GGA has 7 fields, at least 14 expected".into()
+            ))
+        );
+        assert_eq!(
+            validate_sentence("!AIVDM,1,1,,A,15RTgt0PAso;90TKcjM8h6g,0"),
+            Err(ParseError::TruncatedPayload(
+                "message type 1 has 138 bits, at least 168 expected".into()
+            ))
+        );
+        assert!(validate_sentence("!AIVDM,1,1,,A,15RTgt0PAso;90TKcjM8h6g208CQ,9").is_err());
+        assert_eq!(
+            validate_sentence(
+                "!AIVDM,2,1,0,A,55?MbV02;H;s<HtKR20EHE:0@T4@Dn2222222216L961O5Gf0NSQEp6ClRp8,0*1D"
+            ),
+            Ok(())
+        );
+        assert!(validate_sentence("garbage").is_err());
+    }
+}